@@ -16,7 +16,6 @@ pub fn run(
   environment: &mut Environment,
   with_stdlib: bool,
 ) {
-  let mut showed: bool = false;
 
   // Evaluate stdlib.
   if environment.stdlibs.len() > 0 && with_stdlib {
@@ -32,30 +31,44 @@ pub fn run(
           if let Some(error) = object.get_error() {
             println!(
               "{}{}",
-              if showed { "\n" } else { "" },
+              if environment.has_errors { "\n" } else { "" },
               error.string(std_file.clone())
             );
 
-            showed = true;
+            environment.has_errors = true;
           }
         }
       }
     }
   }
 
+  // Set the script arguments in the `process` module.
+  if let Some(mut process) = environment.store.get_object(&String::from("process")).and_then(|object| object.get_hashmap()) {
+    let arguments = environment.arguments.args.iter().map(|argument| StringO::new(argument.clone())).collect();
+
+    process.set_item(String::from("args"), Array::new(arguments));
+    environment.store.set_object(String::from("process"), Box::new(Objects::HASHMAP(process)));
+  }
+
   // Evaluate file statements.
   for statement in file.statements.iter() {
     // Evaluate the statement.
     if let Some(object) = evaluate_statement(statement, environment) {
       // Check if the object is an error.
       if let Some(error) = object.get_error() {
+        // Check if the script requested an exit code.
+        if let Some(exit_code) = error.get_exit_code() {
+          environment.exit_code = Some(exit_code);
+          return;
+        }
+
         println!(
           "{}{}",
-          if showed { "\n" } else { "" },
+          if environment.has_errors { "\n" } else { "" },
           error.string(file.clone())
         );
 
-        showed = true;
+        environment.has_errors = true;
       }
     }
   }
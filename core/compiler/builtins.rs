@@ -1,17 +1,68 @@
+mod command;
+mod fs;
+mod input;
+mod json;
+mod math;
+mod object;
+mod path;
 mod print;
+mod process;
+mod time;
 
 use sflyn_parser::tokens::Token;
 
+use crate::Environment;
+
+use std::env;
+
 use super::{
+  Array,
   BuiltIn,
+  BuiltInFn,
+  Bytes,
   Error,
+  HashMap,
+  Map,
+  Number,
   Objects,
+  RegExp,
+  Set,
+  StringO,
 };
 
+pub type NativeFn = fn(
+  token: Token,
+  this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  environment: &mut Environment,
+) -> Box<Objects>;
+
 pub fn get_builtin_for_identifier(identifier: Token) -> Box<Objects> {
   // Print
   if identifier.value == "print" {
     return BuiltIn::new_box(None, Some(print::print));
+  } else if identifier.value == "eprint" {
+    return BuiltIn::new_box(None, Some(print::eprint));
+  } else if identifier.value == "printf" {
+    return BuiltIn::new_box(None, Some(print::printf));
+  } else if identifier.value == "format" {
+    return BuiltIn::new_box(None, Some(print::format));
+  }
+
+  // Standard input
+  if identifier.value == "input" {
+    return BuiltIn::new_box(None, Some(input::input));
+  } else if identifier.value == "readLine" {
+    return BuiltIn::new_box(None, Some(input::read_line));
+  } else if identifier.value == "readAll" {
+    return BuiltIn::new_box(None, Some(input::read_all));
+  }
+
+  // External commands
+  if identifier.value == "exec" {
+    return BuiltIn::new_box(None, Some(command::exec));
+  } else if identifier.value == "spawn" {
+    return BuiltIn::new_box(None, Some(command::spawn));
   }
 
   // Default
@@ -20,3 +71,322 @@ pub fn get_builtin_for_identifier(identifier: Token) -> Box<Objects> {
     identifier.clone(),
   )
 }
+
+/// Get the Rust implementation of an exported `native` function of a standard module (`fs.sf`, `time.sf`).
+pub fn get_module_builtin(module: &str, name: &str) -> Option<BuiltInFn> {
+  match (module, name) {
+    // File system
+    ("fs", "readFile") => Some(fs::read_file),
+    ("fs", "writeFile") => Some(fs::write_file),
+    ("fs", "appendFile") => Some(fs::append_file),
+    ("fs", "exists") => Some(fs::exists),
+    ("fs", "readDir") => Some(fs::read_dir),
+    ("fs", "mkdir") => Some(fs::mkdir),
+    ("fs", "remove") => Some(fs::remove),
+    ("fs", "rename") => Some(fs::rename),
+    ("fs", "stat") => Some(fs::stat),
+
+    // Path
+    ("path", "join") => Some(path::join),
+    ("path", "dirname") => Some(path::dirname),
+    ("path", "basename") => Some(path::basename),
+    ("path", "extname") => Some(path::extname),
+    ("path", "resolve") => Some(path::resolve),
+    ("path", "relative") => Some(path::relative),
+    ("path", "normalize") => Some(path::normalize),
+
+    // Time
+    ("time", "now") => Some(time::now),
+    ("time", "instant") => Some(time::instant),
+    ("time", "elapsed") => Some(time::elapsed),
+    ("time", "duration") => Some(time::duration),
+    ("time", "date") => Some(time::date),
+    ("time", "fromTimestamp") => Some(time::from_timestamp),
+    ("time", "withOffset") => Some(time::with_offset),
+    ("time", "add") => Some(time::add),
+    ("time", "diff") => Some(time::diff),
+    ("time", "parse") => Some(time::parse),
+    ("time", "toISO") => Some(time::to_iso),
+    ("time", "formatDuration") => Some(time::format_duration),
+    ("time", "sleep") => Some(time::sleep),
+
+    _ => None,
+  }
+}
+
+/// Get the Rust implementation of a `native` prototype method.
+pub fn get_native(prototype: &str, name: &str) -> Option<NativeFn> {
+  match (prototype, name) {
+    // Array
+    ("Array", "push") => Some(Array::push),
+    ("Array", "pop") => Some(Array::pop),
+    ("Array", "shift") => Some(Array::shift),
+    ("Array", "unshift") => Some(Array::unshift),
+    ("Array", "slice") => Some(Array::slice),
+    ("Array", "splice") => Some(Array::splice),
+    ("Array", "concat") => Some(Array::concat),
+    ("Array", "indexOf") => Some(Array::index_of),
+    ("Array", "includes") => Some(Array::includes),
+    ("Array", "find") => Some(Array::find),
+    ("Array", "findIndex") => Some(Array::find_index),
+    ("Array", "map") => Some(Array::map),
+    ("Array", "filter") => Some(Array::filter),
+    ("Array", "reduce") => Some(Array::reduce),
+    ("Array", "forEach") => Some(Array::for_each),
+    ("Array", "some") => Some(Array::some),
+    ("Array", "every") => Some(Array::every),
+    ("Array", "join") => Some(Array::join),
+    ("Array", "reverse") => Some(Array::reverse),
+    ("Array", "sort") => Some(Array::sort),
+
+    // String
+    ("String", "trim") => Some(StringO::trim),
+    ("String", "trimStart") => Some(StringO::trim_start),
+    ("String", "trimEnd") => Some(StringO::trim_end),
+    ("String", "toUpperCase") => Some(StringO::to_upper_case),
+    ("String", "toLowerCase") => Some(StringO::to_lower_case),
+    ("String", "startsWith") => Some(StringO::starts_with),
+    ("String", "endsWith") => Some(StringO::ends_with),
+    ("String", "includes") => Some(StringO::includes),
+    ("String", "indexOf") => Some(StringO::index_of),
+    ("String", "replace") => Some(StringO::replace),
+    ("String", "replaceAll") => Some(StringO::replace_all),
+    ("String", "repeat") => Some(StringO::repeat),
+    ("String", "padStart") => Some(StringO::pad_start),
+    ("String", "padEnd") => Some(StringO::pad_end),
+    ("String", "slice") => Some(StringO::slice),
+    ("String", "substring") => Some(StringO::substring),
+    ("String", "charAt") => Some(StringO::char_at),
+    ("String", "codePointAt") => Some(StringO::code_point_at),
+    ("String", "chars") => Some(StringO::chars),
+    ("String", "split") => Some(StringO::split),
+
+    // HashMap
+    ("HashMap", "keys") => Some(HashMap::keys),
+    ("HashMap", "values") => Some(HashMap::values),
+    ("HashMap", "entries") => Some(HashMap::entries),
+    ("HashMap", "has") => Some(HashMap::has),
+    ("HashMap", "delete") => Some(HashMap::delete),
+    ("HashMap", "merge") => Some(HashMap::merge),
+    ("HashMap", "get") => Some(HashMap::get),
+
+    // Map
+    ("Map", "get") => Some(Map::get),
+    ("Map", "set") => Some(Map::set),
+    ("Map", "has") => Some(Map::has),
+    ("Map", "delete") => Some(Map::delete),
+    ("Map", "clear") => Some(Map::clear),
+    ("Map", "keys") => Some(Map::keys),
+    ("Map", "values") => Some(Map::values),
+    ("Map", "entries") => Some(Map::entries),
+
+    // Set
+    ("Set", "add") => Some(Set::add),
+    ("Set", "has") => Some(Set::has),
+    ("Set", "delete") => Some(Set::delete),
+    ("Set", "clear") => Some(Set::clear),
+    ("Set", "values") => Some(Set::values),
+
+    // RegExp
+    ("RegExp", "test") => Some(RegExp::test),
+    ("RegExp", "exec") => Some(RegExp::exec),
+    ("RegExp", "matchAll") => Some(RegExp::match_all),
+    ("RegExp", "replace") => Some(RegExp::replace),
+    ("RegExp", "split") => Some(RegExp::split),
+
+    // Bytes
+    ("Bytes", "toString") => Some(Bytes::to_string),
+    ("Bytes", "toBase64") => Some(Bytes::to_base64),
+    ("Bytes", "toHex") => Some(Bytes::to_hex),
+    ("Bytes", "toArray") => Some(Bytes::to_array),
+    ("Bytes", "slice") => Some(Bytes::slice),
+    ("Bytes", "concat") => Some(Bytes::concat),
+    ("Bytes", "sha256") => Some(Bytes::sha256),
+    ("Bytes", "sha1") => Some(Bytes::sha1),
+    ("Bytes", "md5") => Some(Bytes::md5),
+    ("Bytes", "crc32") => Some(Bytes::crc32),
+
+    // Number
+    ("Number", "toFixed") => Some(Number::to_fixed),
+    ("Number", "toPrecision") => Some(Number::to_precision),
+    ("Number", "toString") => Some(Number::to_string),
+    ("Number", "isInteger") => Some(Number::is_integer),
+    ("Number", "isNaN") => Some(Number::is_nan),
+
+    // Shared
+    (_, "toString") => Some(object::to_string),
+
+    _ => None,
+  }
+}
+
+/// Check if a `native` prototype method updates its receiver, the receiver is stored back in
+/// the variable after the call.
+pub fn is_mutating_native(prototype: &str, name: &str) -> bool {
+  matches!(
+    (prototype, name),
+    ("Array", "push" | "pop" | "shift" | "unshift" | "splice" | "reverse" | "sort") |
+      ("HashMap", "delete") |
+      ("Map", "set" | "delete" | "clear") |
+      ("Set", "add" | "delete" | "clear")
+  )
+}
+
+/// Get the Rust implementation of a `native` function of a standard library module.
+pub fn get_module_native(module: &str, name: &str) -> Option<NativeFn> {
+  match (module, name) {
+    // Number
+    ("Number", "parse") => Some(Number::parse),
+
+    // Map
+    ("Map", "new") => Some(Map::create),
+
+    // Set
+    ("Set", "new") => Some(Set::create),
+
+    // RegExp
+    ("RegExp", "new") => Some(RegExp::create),
+
+    // Bytes
+    ("Bytes", "fromString") => Some(Bytes::from_string),
+    ("Bytes", "fromBase64") => Some(Bytes::from_base64),
+    ("Bytes", "fromHex") => Some(Bytes::from_hex),
+    ("Bytes", "fromArray") => Some(Bytes::from_array),
+
+    // JSON
+    ("JSON", "parse") => Some(json::parse),
+    ("JSON", "stringify") => Some(json::stringify),
+
+    // Process
+    ("process", "env") => Some(process::get_env),
+    ("process", "cwd") => Some(process::cwd),
+    ("process", "exit") => Some(process::exit),
+
+    // Math
+    ("Math", "floor") => Some(math::floor),
+    ("Math", "ceil") => Some(math::ceil),
+    ("Math", "round") => Some(math::round),
+    ("Math", "abs") => Some(math::abs),
+    ("Math", "min") => Some(math::min),
+    ("Math", "max") => Some(math::max),
+    ("Math", "pow") => Some(math::pow),
+    ("Math", "sqrt") => Some(math::sqrt),
+    ("Math", "sin") => Some(math::sin),
+    ("Math", "cos") => Some(math::cos),
+    ("Math", "tan") => Some(math::tan),
+    ("Math", "asin") => Some(math::asin),
+    ("Math", "acos") => Some(math::acos),
+    ("Math", "atan") => Some(math::atan),
+    ("Math", "atan2") => Some(math::atan2),
+    ("Math", "random") => Some(math::random),
+    ("Math", "seed") => Some(math::seed),
+
+    _ => None,
+  }
+}
+
+/// Check the number of arguments of a native function.
+pub fn check_arguments(
+  token: &Token,
+  arguments: &[Box<Objects>],
+  min: usize,
+  max: usize,
+) -> Option<Box<Objects>> {
+  if arguments.len() < min || arguments.len() > max {
+    let expected = if min == max { min.to_string() } else { format!("{}` to `{}", min, max) };
+
+    return Some(Error::new(
+      format!("expect `{}` arguments, got `{}` instead.", expected, arguments.len()),
+      token.clone(),
+    ));
+  }
+
+  None
+}
+
+/// Check if the script can read a path with the `--allow-read` flag.
+pub fn check_read_permission(token: &Token, environment: &Environment, path: &str) -> Option<Box<Objects>> {
+  if environment.arguments.allow_read.allows(path) {
+    return None;
+  }
+
+  Some(Error::new(
+    format!("`{}` requires the `--allow-read` permission for `{}`.", token.value, path),
+    token.clone(),
+  ))
+}
+
+/// Check if the script can read the current directory with the `--allow-read` flag, the error
+/// does not show the directory.
+pub fn check_cwd_permission(token: &Token, environment: &Environment) -> Option<Box<Objects>> {
+  let directory = env::current_dir().map_or(String::from("/"), |directory| directory.display().to_string());
+
+  if environment.arguments.allow_read.allows(&directory) {
+    return None;
+  }
+
+  Some(Error::new(
+    format!("`{}` requires the `--allow-read` permission for the current directory.", token.value),
+    token.clone(),
+  ))
+}
+
+/// Check if the script can write a path with the `--allow-write` flag.
+pub fn check_write_permission(token: &Token, environment: &Environment, path: &str) -> Option<Box<Objects>> {
+  if environment.arguments.allow_write.allows(path) {
+    return None;
+  }
+
+  Some(Error::new(
+    format!("`{}` requires the `--allow-write` permission for `{}`.", token.value, path),
+    token.clone(),
+  ))
+}
+
+/// Check if the script can run external commands with the `--allow-run` flag.
+pub fn check_run_permission(token: &Token, environment: &Environment) -> Option<Box<Objects>> {
+  if environment.arguments.flag_allow_run {
+    return None;
+  }
+
+  Some(Error::new(
+    format!("`{}` requires the `--allow-run` permission.", token.value),
+    token.clone(),
+  ))
+}
+
+/// Check if the script can get the environment variables with the `--allow-env` flag.
+pub fn check_env_permission(token: &Token, environment: &Environment, name: &str) -> Option<Box<Objects>> {
+  if environment.arguments.flag_allow_env {
+    return None;
+  }
+
+  Some(Error::new(
+    format!("`{}` requires the `--allow-env` permission for `{}`.", token.value, name),
+    token.clone(),
+  ))
+}
+
+/// Get the position of an element index, the negative indexes start from the end (`-1` is the
+/// last element) and the indexes that are not integers or are before the start are `None`.
+pub fn element_index(index: &Objects, length: usize) -> Option<usize> {
+  let value = index.get_number()?.get_value();
+  let position = if value < 0.0 { length as f64 + value } else { value };
+
+  if value.fract() != 0.0 || position < 0.0 {
+    return None;
+  }
+
+  Some(position as usize)
+}
+
+/// Get an index from a relative position of a native method, the negative positions start at the end.
+pub fn relative_index(position: &Objects, length: usize) -> usize {
+  let position = position.get_number().map_or(0.0, |number| number.get_value()).trunc();
+
+  if position < 0.0 {
+    return (length as f64 + position).max(0.0) as usize;
+  }
+
+  (position as usize).min(length)
+}
@@ -0,0 +1,124 @@
+use crate::{
+  compiler::{
+    builtins::{
+      check_arguments,
+      check_run_permission,
+    },
+    Error,
+    HashItem,
+    HashMap,
+    Number,
+    Objects,
+    StringO,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
+use std::{
+  io::{
+    self,
+    Write,
+  },
+  process::{
+    Command,
+    ExitStatus,
+  },
+};
+
+/// Get the command of the arguments: the program name and the optional arguments array.
+fn get_command(
+  token: &Token,
+  arguments: &[Box<Objects>],
+  environment: &Environment,
+) -> Result<Command, Box<Objects>> {
+  if let Some(error) = check_arguments(token, arguments, 1, 2) {
+    return Err(error);
+  } else if let Some(error) = check_run_permission(token, environment) {
+    return Err(error);
+  }
+
+  let mut command = Command::new(arguments[0].string());
+
+  if let Some(args) = arguments.get(1) {
+    match args.get_array() {
+      Some(array) => {
+        command.args(array.get_elements().iter().map(|argument| argument.string()));
+      },
+      None => {
+        return Err(Error::new(
+          format!("`{}` is not an array of arguments.", args.string()),
+          token.clone(),
+        ));
+      },
+    }
+  }
+
+  Ok(command)
+}
+
+/// Get the exit code of a process, `-1` when a signal stopped it.
+fn get_code(status: ExitStatus) -> f64 {
+  status.code().map_or(-1.0, |code| code as f64)
+}
+
+fn new_result(code: f64, stdout: String, stderr: String) -> Box<Objects> {
+  HashMap::new(vec![
+    HashItem {
+      key: String::from("code"),
+      value: Number::new(code),
+    },
+    HashItem {
+      key: String::from("stdout"),
+      value: StringO::new(stdout),
+    },
+    HashItem {
+      key: String::from("stderr"),
+      value: StringO::new(stderr),
+    },
+  ])
+}
+
+/// Run a command and get its `{ code, stdout, stderr }` result, the commands that can not start
+/// have the `-1` code and the reason in `stderr`.
+pub fn exec(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  let mut command = match get_command(&token, &arguments, environment) {
+    Ok(command) => command,
+    Err(error) => {
+      return error;
+    },
+  };
+
+  match command.output() {
+    Ok(output) => new_result(
+      get_code(output.status),
+      String::from_utf8_lossy(&output.stdout).to_string(),
+      String::from_utf8_lossy(&output.stderr).to_string(),
+    ),
+    Err(error) => new_result(-1.0, String::new(), error.to_string()),
+  }
+}
+
+/// Run a command with the standard input and outputs of the script and get its exit code, the
+/// commands that can not start have the `-1` code and write the reason to `stderr`.
+pub fn spawn(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  let mut command = match get_command(&token, &arguments, environment) {
+    Ok(command) => command,
+    Err(error) => {
+      return error;
+    },
+  };
+
+  // Write the pending output of the script before the command output.
+  io::stdout().flush().unwrap();
+
+  match command.status() {
+    Ok(status) => Number::new(get_code(status)),
+    Err(error) => {
+      eprintln!("`{}` can not be started: {}.", arguments[0].string(), error);
+
+      Number::new(-1.0)
+    },
+  }
+}
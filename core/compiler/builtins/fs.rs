@@ -0,0 +1,253 @@
+use crate::{
+  compiler::{
+    Array,
+    Boolean,
+    builtins::{
+      check_arguments,
+      check_read_permission,
+      check_write_permission,
+    },
+    HashItem,
+    HashMap,
+    Null,
+    Number,
+    Objects,
+    StringO,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
+use std::{
+  fs,
+  io::{
+    self,
+    Write,
+  },
+  time::UNIX_EPOCH,
+};
+
+/// Get the `{ code, message }` hashmap of a failed operation, the scripts inspect it instead of
+/// stopping the program.
+fn fs_error(error: io::Error) -> Box<Objects> {
+  HashMap::new(vec![
+    HashItem {
+      key: String::from("code"),
+      value: StringO::new(format!("{:?}", error.kind())),
+    },
+    HashItem {
+      key: String::from("message"),
+      value: StringO::new(error.to_string()),
+    },
+  ])
+}
+
+/// Get `null` when the operation succeeds or the error hashmap when it fails.
+fn fs_result(result: io::Result<()>) -> Box<Objects> {
+  match result {
+    Ok(_) => Null::new(),
+    Err(error) => fs_error(error),
+  }
+}
+
+/// Check if the optional `recursive` argument is true.
+fn is_recursive(arguments: &[Box<Objects>]) -> bool {
+  arguments.get(1).is_some_and(|argument| argument.expect_boolean(true))
+}
+
+pub fn read_file(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  if let Some(error) = check_read_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  match fs::read_to_string(arguments[0].string()) {
+    Ok(content) => StringO::new(content),
+    Err(error) => fs_error(error),
+  }
+}
+
+pub fn write_file(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+    return error;
+  }
+
+  if let Some(error) = check_write_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  fs_result(fs::write(arguments[0].string(), arguments[1].string()))
+}
+
+pub fn append_file(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+    return error;
+  }
+
+  if let Some(error) = check_write_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  let file = fs::OpenOptions::new().create(true).append(true).open(arguments[0].string());
+
+  fs_result(file.and_then(|mut file| file.write_all(arguments[1].string().as_bytes())))
+}
+
+pub fn exists(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  if let Some(error) = check_read_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  Boolean::new(fs::metadata(arguments[0].string()).is_ok())
+}
+
+pub fn read_dir(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  if let Some(error) = check_read_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  let entries = match fs::read_dir(arguments[0].string()) {
+    Ok(entries) => entries,
+    Err(error) => {
+      return fs_error(error);
+    },
+  };
+
+  let mut names: Vec<String> = Vec::new();
+
+  for entry in entries {
+    match entry {
+      Ok(entry) => names.push(entry.file_name().to_string_lossy().to_string()),
+      Err(error) => {
+        return fs_error(error);
+      },
+    }
+  }
+
+  // The operating system does not sort the entries.
+  names.sort();
+
+  Array::new(names.into_iter().map(StringO::new).collect())
+}
+
+pub fn mkdir(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+    return error;
+  }
+
+  if let Some(error) = check_write_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  let path = arguments[0].string();
+
+  if is_recursive(&arguments) {
+    return fs_result(fs::create_dir_all(path));
+  }
+
+  fs_result(fs::create_dir(path))
+}
+
+pub fn remove(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+    return error;
+  }
+
+  if let Some(error) = check_write_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  let path = arguments[0].string();
+
+  let metadata = match fs::symlink_metadata(&path) {
+    Ok(metadata) => metadata,
+    Err(error) => {
+      return fs_error(error);
+    },
+  };
+
+  // Check if the path is a directory, only the empty directories are removed without `recursive`.
+  if metadata.is_dir() {
+    if is_recursive(&arguments) {
+      return fs_result(fs::remove_dir_all(path));
+    }
+
+    return fs_result(fs::remove_dir(path));
+  }
+
+  fs_result(fs::remove_file(path))
+}
+
+pub fn rename(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+    return error;
+  }
+
+  // The source is read and removed, the destination is written.
+  if let Some(error) = check_read_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  if let Some(error) = check_write_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  if let Some(error) = check_write_permission(&token, environment, &arguments[1].string()) {
+    return error;
+  }
+
+  fs_result(fs::rename(arguments[0].string(), arguments[1].string()))
+}
+
+pub fn stat(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  if let Some(error) = check_read_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  let metadata = match fs::metadata(arguments[0].string()) {
+    Ok(metadata) => metadata,
+    Err(error) => {
+      return fs_error(error);
+    },
+  };
+
+  // Get the last modification time in milliseconds since the Unix epoch.
+  let modified = metadata.modified().ok()
+    .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+    .map_or(0.0, |duration| duration.as_millis() as f64);
+
+  HashMap::new(vec![
+    HashItem {
+      key: String::from("size"),
+      value: Number::new(metadata.len() as f64),
+    },
+    HashItem {
+      key: String::from("isFile"),
+      value: Boolean::new(metadata.is_file()),
+    },
+    HashItem {
+      key: String::from("isDir"),
+      value: Boolean::new(metadata.is_dir()),
+    },
+    HashItem {
+      key: String::from("modified"),
+      value: Number::new(modified),
+    },
+  ])
+}
@@ -0,0 +1,85 @@
+use crate::{
+  compiler::{
+    builtins::check_arguments,
+    Error,
+    Null,
+    Objects,
+    StringO,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
+use std::io::{
+  self,
+  BufRead,
+  Read,
+  Write,
+};
+
+/// Read the next line of the standard input without the line break, the end of the input is `null`.
+fn next_line(token: Token) -> Box<Objects> {
+  let mut line = String::new();
+
+  match io::stdin().lock().read_line(&mut line) {
+    Ok(0) => Null::new(),
+    Ok(_) => {
+      if line.ends_with('\n') {
+        line.pop();
+
+        if line.ends_with('\r') {
+          line.pop();
+        }
+      }
+
+      StringO::new(line)
+    },
+    Err(error) => Error::new(
+      format!("can not read the standard input: {}.", error),
+      token,
+    ),
+  }
+}
+
+pub fn input(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+    return error;
+  }
+
+  // Show the prompt in the same line of the answer.
+  if let Some(prompt) = arguments.first() {
+    let stdout = io::stdout();
+    let mut handle = stdout.lock();
+
+    handle.write_all(prompt.string().as_bytes()).unwrap();
+    handle.flush().unwrap();
+  }
+
+  next_line(token)
+}
+
+pub fn read_line(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+    return error;
+  }
+
+  next_line(token)
+}
+
+pub fn read_all(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+    return error;
+  }
+
+  let mut content = String::new();
+
+  match io::stdin().lock().read_to_string(&mut content) {
+    Ok(0) => Null::new(),
+    Ok(_) => StringO::new(content),
+    Err(error) => Error::new(
+      format!("can not read the standard input: {}.", error),
+      token,
+    ),
+  }
+}
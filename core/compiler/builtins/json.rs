@@ -0,0 +1,530 @@
+use crate::{
+  compiler::{
+    Array,
+    Boolean,
+    builtins::check_arguments,
+    Error,
+    HashItem,
+    HashMap,
+    Null,
+    Number,
+    Objects,
+    StringO,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::{
+  item_from_value,
+  Token,
+};
+
+/// Decoder of a JSON text, it keeps the line and the column of the current character to report
+/// the errors.
+struct JsonParser {
+  chars: Vec<char>,
+  position: usize,
+  line: usize,
+  column: usize,
+  depth: usize,   // Arrays and objects that are not closed yet
+}
+
+/// Maximum nesting of the arrays and the objects of a JSON text, the same limit of `serde_json`.
+const MAX_DEPTH: usize = 128;
+
+impl JsonParser {
+  fn new(text: &str) -> JsonParser {
+    JsonParser {
+      chars: text.chars().collect(),
+      position: 0,
+      line: 1,
+      column: 1,
+      depth: 0,
+    }
+  }
+
+  fn error(&self, message: String) -> String {
+    format!("invalid JSON at line {}, column {}: {}.", self.line, self.column, message)
+  }
+
+  fn unexpected(&self) -> String {
+    match self.peek() {
+      Some(current) => self.error(format!("unexpected `{}`", current)),
+      None => self.error(String::from("unexpected end of the input")),
+    }
+  }
+
+  fn peek(&self) -> Option<char> {
+    self.chars.get(self.position).cloned()
+  }
+
+  fn next(&mut self) -> Option<char> {
+    let current = self.peek()?;
+
+    self.position += 1;
+
+    if current == '\n' {
+      self.line += 1;
+      self.column = 1;
+    } else {
+      self.column += 1;
+    }
+
+    Some(current)
+  }
+
+  fn skip_whitespace(&mut self) {
+    while let Some(' ' | '\t' | '\n' | '\r') = self.peek() {
+      self.next();
+    }
+  }
+
+  fn expect(&mut self, expected: char) -> Result<(), String> {
+    if self.peek() != Some(expected) {
+      return Err(self.unexpected());
+    }
+
+    self.next();
+
+    Ok(())
+  }
+
+  /// Parse the whole text, only the whitespaces can follow the value.
+  fn parse(&mut self) -> Result<Box<Objects>, String> {
+    let value = self.parse_value()?;
+
+    self.skip_whitespace();
+
+    if self.peek().is_some() {
+      return Err(self.unexpected());
+    }
+
+    Ok(value)
+  }
+
+  fn parse_value(&mut self) -> Result<Box<Objects>, String> {
+    self.skip_whitespace();
+
+    match self.peek() {
+      Some('{' | '[') => {
+        // Check if the value is nested too deep.
+        if self.depth >= MAX_DEPTH {
+          return Err(self.error(format!("the value is nested more than {} levels", MAX_DEPTH)));
+        }
+
+        self.depth += 1;
+
+        let value = if self.peek() == Some('{') { self.parse_object() } else { self.parse_array() };
+
+        self.depth -= 1;
+
+        value
+      },
+      Some('"') => Ok(StringO::new(self.parse_string()?)),
+      Some('t') => self.parse_keyword("true", Boolean::new(true)),
+      Some('f') => self.parse_keyword("false", Boolean::new(false)),
+      Some('n') => self.parse_keyword("null", Null::new()),
+      Some('-' | '0'..='9') => self.parse_number(),
+      _ => Err(self.unexpected()),
+    }
+  }
+
+  fn parse_keyword(&mut self, keyword: &str, value: Box<Objects>) -> Result<Box<Objects>, String> {
+    for expected in keyword.chars() {
+      self.expect(expected)?;
+    }
+
+    Ok(value)
+  }
+
+  fn parse_object(&mut self) -> Result<Box<Objects>, String> {
+    let mut hashmap: Vec<HashItem> = Vec::new();
+
+    self.expect('{')?;
+    self.skip_whitespace();
+
+    // Check if the object is empty.
+    if self.peek() == Some('}') {
+      self.next();
+
+      return Ok(HashMap::new(hashmap));
+    }
+
+    loop {
+      self.skip_whitespace();
+
+      if self.peek() != Some('"') {
+        return Err(self.unexpected());
+      }
+
+      let key = self.parse_string()?;
+
+      self.skip_whitespace();
+      self.expect(':')?;
+
+      let value = self.parse_value()?;
+
+      // The last duplicated key wins.
+      match hashmap.iter_mut().find(|item| item.key == key) {
+        Some(item) => {
+          item.value = value;
+        },
+        None => {
+          hashmap.push(HashItem { key, value });
+        },
+      }
+
+      self.skip_whitespace();
+
+      match self.peek() {
+        Some(',') => {
+          self.next();
+        },
+        Some('}') => {
+          self.next();
+
+          return Ok(HashMap::new(hashmap));
+        },
+        _ => {
+          return Err(self.unexpected());
+        },
+      }
+    }
+  }
+
+  fn parse_array(&mut self) -> Result<Box<Objects>, String> {
+    let mut elements: Vec<Box<Objects>> = Vec::new();
+
+    self.expect('[')?;
+    self.skip_whitespace();
+
+    // Check if the array is empty.
+    if self.peek() == Some(']') {
+      self.next();
+
+      return Ok(Array::new(elements));
+    }
+
+    loop {
+      elements.push(self.parse_value()?);
+
+      self.skip_whitespace();
+
+      match self.peek() {
+        Some(',') => {
+          self.next();
+        },
+        Some(']') => {
+          self.next();
+
+          return Ok(Array::new(elements));
+        },
+        _ => {
+          return Err(self.unexpected());
+        },
+      }
+    }
+  }
+
+  fn parse_string(&mut self) -> Result<String, String> {
+    let mut string = String::new();
+
+    self.expect('"')?;
+
+    loop {
+      match self.peek() {
+        Some('"') => {
+          self.next();
+
+          return Ok(string);
+        },
+        Some('\\') => {
+          self.next();
+
+          let escaped = match self.peek() {
+            Some('"') => '"',
+            Some('\\') => '\\',
+            Some('/') => '/',
+            Some('b') => '\u{8}',
+            Some('f') => '\u{c}',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            Some('t') => '\t',
+            Some('u') => {
+              self.next();
+              string.push(self.parse_unicode()?);
+              continue;
+            },
+            _ => {
+              return Err(self.error(String::from("invalid escape sequence")));
+            },
+          };
+
+          self.next();
+          string.push(escaped);
+        },
+        Some(current) if (current as u32) < 0x20 => {
+          return Err(self.error(String::from("control characters must be escaped")));
+        },
+        Some(current) => {
+          self.next();
+          string.push(current);
+        },
+        None => {
+          return Err(self.error(String::from("unterminated string")));
+        },
+      }
+    }
+  }
+
+  /// Parse the hexadecimal digits of a `\u` escape, the surrogate pairs are joined.
+  fn parse_unicode(&mut self) -> Result<char, String> {
+    let first = self.parse_hex()?;
+
+    // Check if is a high surrogate.
+    if (0xD800..0xDC00).contains(&first) {
+      if self.peek() != Some('\\') {
+        return Err(self.error(String::from("expect a low surrogate")));
+      }
+
+      self.next();
+      self.expect('u')?;
+
+      let second = self.parse_hex()?;
+
+      if !(0xDC00..0xE000).contains(&second) {
+        return Err(self.error(String::from("invalid low surrogate")));
+      }
+
+      let code = 0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00);
+
+      return char::from_u32(code).ok_or_else(|| self.error(String::from("invalid unicode escape")));
+    }
+
+    char::from_u32(first).ok_or_else(|| self.error(String::from("invalid unicode escape")))
+  }
+
+  fn parse_hex(&mut self) -> Result<u32, String> {
+    let mut code: u32 = 0;
+
+    for _ in 0..4 {
+      match self.peek().and_then(|current| current.to_digit(16)) {
+        Some(digit) => {
+          self.next();
+          code = code * 16 + digit;
+        },
+        None => {
+          return Err(self.error(String::from("expect 4 hexadecimal digits")));
+        },
+      }
+    }
+
+    Ok(code)
+  }
+
+  fn parse_number(&mut self) -> Result<Box<Objects>, String> {
+    let start = self.position;
+
+    if self.peek() == Some('-') {
+      self.next();
+    }
+
+    // The integer part can not have leading zeros.
+    match self.peek() {
+      Some('0') => {
+        self.next();
+      },
+      Some('1'..='9') => self.skip_digits(),
+      _ => {
+        return Err(self.unexpected());
+      },
+    }
+
+    if self.peek() == Some('.') {
+      self.next();
+
+      if !self.peek().is_some_and(|current| current.is_ascii_digit()) {
+        return Err(self.unexpected());
+      }
+
+      self.skip_digits();
+    }
+
+    if let Some('e' | 'E') = self.peek() {
+      self.next();
+
+      if let Some('+' | '-') = self.peek() {
+        self.next();
+      }
+
+      if !self.peek().is_some_and(|current| current.is_ascii_digit()) {
+        return Err(self.unexpected());
+      }
+
+      self.skip_digits();
+    }
+
+    let number: String = self.chars[start..self.position].iter().collect();
+
+    match number.parse::<f64>() {
+      Ok(number) => Ok(Number::new(number)),
+      Err(_) => Err(self.error(format!("`{}` is not a valid number", number))),
+    }
+  }
+
+  fn skip_digits(&mut self) {
+    while self.peek().is_some_and(|current| current.is_ascii_digit()) {
+      self.next();
+    }
+  }
+}
+
+/// Get the JSON string of a text with the special characters escaped.
+fn stringify_string(string: &str) -> String {
+  let mut result = String::from("\"");
+
+  for current in string.chars() {
+    match current {
+      '"' => result.push_str("\\\""),
+      '\\' => result.push_str("\\\\"),
+      '\n' => result.push_str("\\n"),
+      '\r' => result.push_str("\\r"),
+      '\t' => result.push_str("\\t"),
+      current if (current as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", current as u32)),
+      current => result.push(current),
+    }
+  }
+
+  result.push('"');
+
+  result
+}
+
+/// Get the JSON text of a value, the items are written in new lines when the indentation is not
+/// empty.
+fn stringify_value(value: &Objects, indent: &str, depth: usize) -> Result<String, String> {
+  // Get the separators of the items.
+  let (newline, inner, outer, colon) = if indent.is_empty() {
+    (String::new(), String::new(), String::new(), ":")
+  } else {
+    (String::from("\n"), indent.repeat(depth + 1), indent.repeat(depth), ": ")
+  };
+
+  if value.get_null().is_some() {
+    return Ok(String::from("null"));
+  } else if let Some(boolean) = value.get_boolean() {
+    return Ok(boolean.get_value().to_string());
+  } else if let Some(number) = value.get_number() {
+    // JSON does not have `NaN` and `Infinity`.
+    if !number.get_value().is_finite() {
+      return Ok(String::from("null"));
+    }
+
+    return Ok(value.string());
+  } else if let Some(string) = value.get_string() {
+    return Ok(stringify_string(&string.get_value()));
+  } else if let Some(array) = value.get_array() {
+    let elements = array.get_elements();
+
+    if elements.is_empty() {
+      return Ok(String::from("[]"));
+    }
+
+    let mut items: Vec<String> = Vec::new();
+
+    for element in elements.iter() {
+      items.push(format!("{}{}", inner, stringify_value(element, indent, depth + 1)?));
+    }
+
+    return Ok(format!("[{}{}{}{}]", newline, items.join(&format!(",{}", newline)), newline, outer));
+  } else if let Some(hashmap) = value.get_hashmap() {
+    let data = hashmap.get_data();
+
+    if data.is_empty() {
+      return Ok(String::from("{}"));
+    }
+
+    let mut items: Vec<String> = Vec::new();
+
+    for item in data.iter() {
+      items.push(format!(
+        "{}{}{}{}",
+        inner,
+        stringify_string(&item.key),
+        colon,
+        stringify_value(&item.value, indent, depth + 1)?,
+      ));
+    }
+
+    return Ok(format!("{{{}{}{}{}}}", newline, items.join(&format!(",{}", newline)), newline, outer));
+  }
+
+  // The body of the functions is not written in the error.
+  if value.get_anonymous_function().is_some() || value.get_builtin().is_some() {
+    return Err(String::from("the functions can not be converted to JSON."));
+  }
+
+  Err(format!("`{}` can not be converted to JSON.", value.string()))
+}
+
+pub fn parse(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  environment: &mut Environment,
+) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+    return error;
+  }
+
+  let value = match JsonParser::new(&arguments[0].string()).parse() {
+    Ok(value) => value,
+    Err(message) => {
+      return Error::new(message, token);
+    },
+  };
+
+  // Check if the value satisfies the data type of the second argument.
+  if let Some(data_type) = arguments.get(1) {
+    let data_type = match item_from_value(&data_type.string()) {
+      Some(data_type) => data_type,
+      None => {
+        return Error::new(
+          format!("`{}` is not a valid data type.", data_type.string()),
+          token,
+        );
+      },
+    };
+
+    if let Some(message) = value.get_type_error(&data_type, &environment.store, "") {
+      return Error::new(
+        format!("the JSON value does not satisfy the `{}` data type: {}.", data_type.value, message),
+        token,
+      );
+    }
+  }
+
+  value
+}
+
+pub fn stringify(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+    return error;
+  }
+
+  // Get the number of spaces of the indentation.
+  let indent = match arguments.get(1).and_then(|indent| indent.get_number()) {
+    Some(number) => " ".repeat(number.get_value().clamp(0.0, 10.0) as usize),
+    None => String::new(),
+  };
+
+  match stringify_value(&arguments[0], &indent, 0) {
+    Ok(text) => StringO::new(text),
+    Err(message) => Error::new(message, token),
+  }
+}
@@ -0,0 +1,244 @@
+use crate::{
+  compiler::{
+    builtins::check_arguments,
+    Error,
+    Null,
+    Number,
+    Objects,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
+use std::{
+  cell::Cell,
+  time::{
+    SystemTime,
+    UNIX_EPOCH,
+  },
+};
+
+thread_local! {
+  // State of the `Math->random()` generator, zero until the first use or a `Math->seed()` call.
+  static RANDOM_STATE: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Get the number arguments of a math function.
+fn get_numbers(token: &Token, arguments: &[Box<Objects>], count: usize) -> Result<Vec<f64>, Box<Objects>> {
+  if let Some(error) = check_arguments(token, arguments, count, count) {
+    return Err(error);
+  }
+
+  let mut numbers: Vec<f64> = Vec::new();
+
+  for argument in arguments.iter() {
+    match argument.get_number() {
+      Some(number) => numbers.push(number.get_value()),
+      None => {
+        return Err(Error::new(
+          format!("`{}` is not a number.", argument.string()),
+          token.clone(),
+        ));
+      },
+    }
+  }
+
+  Ok(numbers)
+}
+
+/// Apply a math function to the number arguments.
+fn apply(token: &Token, arguments: &[Box<Objects>], count: usize, fun: fn(&[f64]) -> f64) -> Box<Objects> {
+  match get_numbers(token, arguments, count) {
+    Ok(numbers) => Number::new(fun(&numbers)),
+    Err(error) => error,
+  }
+}
+
+pub fn floor(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].floor())
+}
+
+pub fn ceil(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].ceil())
+}
+
+// Round the halves up like `Math.round` in JavaScript (`-2.5` is `-2`).
+pub fn round(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| (numbers[0] + 0.5).floor())
+}
+
+pub fn abs(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].abs())
+}
+
+pub fn min(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 2, |numbers| numbers[0].min(numbers[1]))
+}
+
+pub fn max(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 2, |numbers| numbers[0].max(numbers[1]))
+}
+
+pub fn pow(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 2, |numbers| numbers[0].powf(numbers[1]))
+}
+
+pub fn sqrt(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].sqrt())
+}
+
+pub fn sin(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].sin())
+}
+
+pub fn cos(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].cos())
+}
+
+pub fn tan(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].tan())
+}
+
+pub fn asin(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].asin())
+}
+
+pub fn acos(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].acos())
+}
+
+pub fn atan(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 1, |numbers| numbers[0].atan())
+}
+
+pub fn atan2(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  apply(&token, &arguments, 2, |numbers| numbers[0].atan2(numbers[1]))
+}
+
+/// Get a number between `0` (included) and `1` (excluded) with a xorshift generator.
+pub fn random(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+    return error;
+  }
+
+  let value = RANDOM_STATE.with(|state| {
+    let mut value = state.get();
+
+    // Check if the generator is not seeded.
+    if value == 0 {
+      value = SystemTime::now().duration_since(UNIX_EPOCH).map_or(1, |time| time.as_nanos() as u64) | 1;
+    }
+
+    value ^= value << 13;
+    value ^= value >> 7;
+    value ^= value << 17;
+
+    state.set(value);
+
+    value
+  });
+
+  // Use the 53 high bits as the fraction of the number.
+  Number::new((value >> 11) as f64 / (1u64 << 53) as f64)
+}
+
+/// Seed the `Math->random()` generator to get a repeatable sequence.
+pub fn seed(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  match get_numbers(&token, &arguments, 1) {
+    Ok(numbers) => {
+      // Mix the seed to avoid the zero state.
+      let value = (numbers[0] as i64 as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+
+      RANDOM_STATE.with(|state| state.set(value));
+
+      Null::new()
+    },
+    Err(error) => error,
+  }
+}
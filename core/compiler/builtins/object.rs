@@ -0,0 +1,23 @@
+use crate::{
+  compiler::{
+    builtins::check_arguments,
+    Objects,
+    StringO,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
+pub fn to_string(
+  token: Token,
+  this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+    return error;
+  }
+
+  StringO::new(this.string())
+}
@@ -0,0 +1,103 @@
+use crate::{
+  compiler::{
+    builtins::{
+      check_arguments,
+      check_cwd_permission,
+    },
+    Error,
+    Objects,
+    StringO,
+  },
+  Environment,
+  utils,
+};
+
+use sflyn_parser::tokens::Token;
+
+/// Get the string arguments of a path function.
+fn get_strings(token: &Token, arguments: &[Box<Objects>]) -> Result<Vec<String>, Box<Objects>> {
+  let mut strings: Vec<String> = Vec::new();
+
+  for argument in arguments.iter() {
+    match argument.get_string() {
+      Some(string) => strings.push(string.get_value()),
+      None => {
+        return Err(Error::new(
+          format!("`{}` is not a string.", argument.string()),
+          token.clone(),
+        ));
+      },
+    }
+  }
+
+  Ok(strings)
+}
+
+/// Apply a path function to the string arguments.
+fn apply(
+  token: &Token,
+  arguments: &[Box<Objects>],
+  min: usize,
+  max: usize,
+  fun: fn(&[&str]) -> String,
+) -> Box<Objects> {
+  if let Some(error) = check_arguments(token, arguments, min, max) {
+    return error;
+  }
+
+  match get_strings(token, arguments) {
+    Ok(strings) => {
+      let strings: Vec<&str> = strings.iter().map(|string| string.as_str()).collect();
+
+      StringO::new(fun(&strings))
+    },
+    Err(error) => error,
+  }
+}
+
+pub fn join(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  apply(&token, &arguments, 0, usize::MAX, utils::join_paths)
+}
+
+pub fn dirname(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  apply(&token, &arguments, 1, 1, |paths| utils::dirname(paths[0]))
+}
+
+pub fn basename(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  apply(&token, &arguments, 1, 2, |paths| utils::basename(paths[0], paths.get(1).unwrap_or(&"")))
+}
+
+pub fn extname(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  apply(&token, &arguments, 1, 1, |paths| utils::extname(paths[0]))
+}
+
+/// Check if a path argument is an absolute path, the relative paths are resolved from the current
+/// directory.
+fn is_absolute(argument: &Objects) -> bool {
+  argument.get_string().is_some_and(|path| path.get_value().starts_with('/'))
+}
+
+pub fn resolve(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  // The paths start again from the last absolute path.
+  if !arguments.iter().any(|argument| is_absolute(argument)) {
+    if let Some(error) = check_cwd_permission(&token, environment) {
+      return error;
+    }
+  }
+
+  apply(&token, &arguments, 0, usize::MAX, utils::resolve_path)
+}
+
+pub fn relative(token: Token, arguments: Vec<Box<Objects>>, environment: &mut Environment) -> Box<Objects> {
+  if !arguments.iter().all(|argument| is_absolute(argument)) {
+    if let Some(error) = check_cwd_permission(&token, environment) {
+      return error;
+    }
+  }
+
+  apply(&token, &arguments, 2, 2, |paths| utils::relative_path(paths[0], paths[1]))
+}
+
+pub fn normalize(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  apply(&token, &arguments, 1, 1, |paths| utils::normalize_path(paths[0]))
+}
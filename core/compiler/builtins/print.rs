@@ -1,6 +1,11 @@
-use crate::compiler::{
-  Error,
-  Objects
+use crate::{
+  compiler::{
+    Error,
+    Null,
+    Objects,
+    StringO,
+  },
+  Environment,
 };
 
 use sflyn_parser::tokens::Token;
@@ -10,25 +15,248 @@ use std::io::{
   Write,
 };
 
-pub fn print(token: Token, arguments: Vec<Box<Objects>>) -> Box<Objects> {
-  if arguments.len() != 1 {
-    return Error::new(
-      format!("expect `1` argument, got `{}` instead.", arguments.len()),
-      token,
-    );
-  }
+/// Maximum width and precision of a placeholder.
+const MAX_PLACEHOLDER_SIZE: usize = 1024;
+
+/// Replace the escape sequences of the strings before writing them.
+fn unescape(string: String) -> String {
+  string
+    .replace("\\r", "\r")
+    .replace("\\n", "\n")
+    .replace("\\t", "\t")
+}
 
+/// Join the values with spaces, the strings are written without quotes.
+fn join_values(arguments: &[Box<Objects>]) -> String {
+  let values: Vec<String> = arguments.iter().map(|argument| argument.string()).collect();
+
+  values.join(" ")
+}
+
+fn write_stdout(string: String) {
   let stdout = io::stdout();
   let mut handle = stdout.lock();
 
-  let string =
-    arguments[0].clone().string()
-      .replace("\\r", "\r")
-      .replace("\\n", "\n")
-      .replace("\\t", "\t");
+  handle.write_all(unescape(string).as_bytes()).unwrap();
+  handle.flush().unwrap();
+}
+
+fn write_stderr(string: String) {
+  let stderr = io::stderr();
+  let mut handle = stderr.lock();
+
+  handle.write_all(unescape(string).as_bytes()).unwrap();
+}
+
+/// Pad a value to the width of a placeholder.
+fn pad(value: String, width: usize, fill: char, align: char) -> String {
+  let length = value.chars().count();
+
+  if length >= width {
+    return value;
+  }
+
+  let padding = width - length;
+
+  match align {
+    '<' => format!("{}{}", value, fill.to_string().repeat(padding)),
+    '^' => format!(
+      "{}{}{}",
+      fill.to_string().repeat(padding / 2),
+      value,
+      fill.to_string().repeat(padding - padding / 2),
+    ),
+    _ => format!("{}{}", fill.to_string().repeat(padding), value),
+  }
+}
+
+/// Format a value with a placeholder specifier: `[[fill]align][0][width][.precision]`, for example
+/// `{:>8.2}` or `{:*^10}`.
+fn format_value(value: &Objects, spec: &str) -> Result<String, String> {
+  let mut chars: Vec<char> = spec.chars().collect();
+  let mut fill = ' ';
+  let mut align: Option<char> = None;
+  let mut zero = false;
 
-  handle.write(string.as_bytes()).unwrap();
-  handle.write(b"\n").unwrap();
+  // Get the fill character and the alignment.
+  if chars.len() >= 2 && ['<', '>', '^'].contains(&chars[1]) {
+    fill = chars[0];
+    align = Some(chars[1]);
+    chars.drain(..2);
+  } else if !chars.is_empty() && ['<', '>', '^'].contains(&chars[0]) {
+    align = Some(chars[0]);
+    chars.remove(0);
+  }
+
+  // Check if the number is padded with zeros.
+  if chars.first() == Some(&'0') {
+    zero = true;
+    chars.remove(0);
+  }
+
+  let rest: String = chars.into_iter().collect();
+  let (width, precision) = match rest.split_once('.') {
+    Some((width, precision)) => (width, Some(precision)),
+    None => (rest.as_str(), None),
+  };
+
+  let invalid = || format!("`{{:{}}}` is not a valid placeholder.", spec);
+
+  let width: usize = if width.is_empty() {
+    0
+  } else {
+    width.parse().map_err(|_| invalid())?
+  };
+
+  let precision: Option<usize> = match precision {
+    Some(precision) => Some(precision.parse().map_err(|_| invalid())?),
+    None => None,
+  };
+
+  // Check if the width or the precision are too large.
+  if width > MAX_PLACEHOLDER_SIZE || precision.is_some_and(|precision| precision > MAX_PLACEHOLDER_SIZE) {
+    return Err(invalid());
+  }
+
+  // Check if the value is a number, the precision sets the decimals.
+  if let Some(number) = value.get_number() {
+    let number = number.get_value();
+    let string = match precision {
+      Some(precision) => format!("{:.*}", precision, number),
+      None => value.string(),
+    };
+
+    // The zeros are added after the sign.
+    if zero && align.is_none() {
+      let (sign, digits) = match string.strip_prefix('-') {
+        Some(digits) => ("-", digits.to_string()),
+        None => ("", string),
+      };
+
+      return Ok(format!("{}{}", sign, pad(digits, width.saturating_sub(sign.len()), '0', '>')));
+    }
+
+    return Ok(pad(string, width, fill, align.unwrap_or('>')));
+  }
+
+  let mut string = value.string();
+
+  // The precision truncates the other values.
+  if let Some(precision) = precision {
+    string = string.chars().take(precision).collect();
+  }
 
-  arguments[0].clone()
+  Ok(pad(string, width, fill, align.unwrap_or('<')))
+}
+
+/// Replace the `{}` placeholders of a template with the values, `{{` and `}}` are literal braces.
+fn format_template(template: &str, values: &[Box<Objects>]) -> Result<String, String> {
+  let mut result = String::new();
+  let mut chars = template.chars().peekable();
+  let mut index: usize = 0;
+
+  while let Some(current) = chars.next() {
+    if current == '}' {
+      // Check if is an escaped brace.
+      if chars.peek() == Some(&'}') {
+        chars.next();
+        result.push('}');
+        continue;
+      }
+
+      return Err(String::from("unexpected `}` in the template, use `}}` instead."));
+    }
+
+    if current != '{' {
+      result.push(current);
+      continue;
+    }
+
+    // Check if is an escaped brace.
+    if chars.peek() == Some(&'{') {
+      chars.next();
+      result.push('{');
+      continue;
+    }
+
+    let mut placeholder = String::new();
+    let mut closed = false;
+
+    for current in chars.by_ref() {
+      if current == '}' {
+        closed = true;
+        break;
+      }
+
+      placeholder.push(current);
+    }
+
+    if !closed {
+      return Err(String::from("the template has an unclosed `{`."));
+    }
+
+    let spec = match placeholder.strip_prefix(':') {
+      Some(spec) => spec,
+      None if placeholder.is_empty() => "",
+      None => {
+        return Err(format!("`{{{}}}` is not a valid placeholder.", placeholder));
+      },
+    };
+
+    match values.get(index) {
+      Some(value) => result.push_str(&format_value(value, spec)?),
+      None => {
+        return Err(format!("the template has more placeholders than the `{}` values.", values.len()));
+      },
+    }
+
+    index += 1;
+  }
+
+  if index < values.len() {
+    return Err(format!("the template has `{}` placeholders, got `{}` values.", index, values.len()));
+  }
+
+  Ok(result)
+}
+
+/// Format the template of the first argument with the other arguments.
+fn format_arguments(token: &Token, arguments: &[Box<Objects>]) -> Result<String, Box<Objects>> {
+  if arguments.is_empty() {
+    return Err(Error::new(String::from("expect the template argument."), token.clone()));
+  }
+
+  format_template(&arguments[0].string(), &arguments[1..])
+    .map_err(|message| Error::new(message, token.clone()))
+}
+
+pub fn print(_token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  write_stdout(format!("{}\n", join_values(&arguments)));
+
+  Null::new()
+}
+
+pub fn eprint(_token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  write_stderr(format!("{}\n", join_values(&arguments)));
+
+  Null::new()
+}
+
+/// Print a formatted template without the trailing new line.
+pub fn printf(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  match format_arguments(&token, &arguments) {
+    Ok(string) => {
+      write_stdout(string);
+
+      Null::new()
+    },
+    Err(error) => error,
+  }
+}
+
+pub fn format(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  match format_arguments(&token, &arguments) {
+    Ok(string) => StringO::new(string),
+    Err(error) => error,
+  }
 }
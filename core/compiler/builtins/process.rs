@@ -0,0 +1,84 @@
+use crate::{
+  compiler::{
+    builtins::{
+      check_arguments,
+      check_cwd_permission,
+      check_env_permission,
+    },
+    Error,
+    Null,
+    Objects,
+    StringO,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
+use std::env;
+
+pub fn get_env(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  environment: &mut Environment,
+) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  if let Some(error) = check_env_permission(&token, environment, &arguments[0].string()) {
+    return error;
+  }
+
+  match env::var(arguments[0].string()) {
+    Ok(value) => StringO::new(value),
+    Err(_) => Null::new(),
+  }
+}
+
+pub fn cwd(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  environment: &mut Environment,
+) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+    return error;
+  }
+
+  if let Some(error) = check_cwd_permission(&token, environment) {
+    return error;
+  }
+
+  match env::current_dir() {
+    Ok(path) => StringO::new(path.display().to_string()),
+    Err(error) => Error::new(
+      format!("can not get the current directory: {}.", error),
+      token,
+    ),
+  }
+}
+
+pub fn exit(
+  token: Token,
+  _this: &mut Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  _environment: &mut Environment,
+) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+    return error;
+  }
+
+  let code = arguments.first().and_then(|code| code.get_number()).map_or(0.0, |code| code.get_value());
+
+  // Check if the code is a valid exit code.
+  if code.fract() != 0.0 || !(0.0..=255.0).contains(&code) {
+    return Error::new(
+      format!("`{}` is not a valid exit code, expect an integer from `0` to `255`.", code),
+      token,
+    );
+  }
+
+  Error::new_exit(code as i32, token)
+}
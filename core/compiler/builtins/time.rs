@@ -0,0 +1,698 @@
+use crate::{
+  compiler::{
+    builtins::check_arguments,
+    Error,
+    HashItem,
+    HashMap,
+    Null,
+    Number,
+    Objects,
+    StringO,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
+use std::{
+  sync::OnceLock,
+  thread,
+  time::{
+    Duration,
+    Instant,
+    SystemTime,
+    UNIX_EPOCH,
+  },
+};
+
+const MILLISECONDS_PER_MINUTE: i64 = 60_000;
+const MILLISECONDS_PER_DAY: i64 = 86_400_000;
+
+// The dates are limited to 100,000,000 days around the Unix epoch (years -271821 to 275760).
+const MAX_TIMESTAMP: i64 = 8_640_000_000_000_000;
+const MIN_YEAR: i64 = -271_821;
+const MAX_YEAR: i64 = 275_760;
+
+// Start of the monotonic clock of `instant()`.
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// Get the days since the Unix epoch of a civil date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+  let year = if month <= 2 { year - 1 } else { year };
+  let era = year.div_euclid(400);
+  let year_of_era = year - era * 400;
+  let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+  let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+  era * 146_097 + day_of_era - 719_468
+}
+
+/// Get the civil date (year, month, day) of the days since the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+  let days = days + 719_468;
+  let era = days.div_euclid(146_097);
+  let day_of_era = days - era * 146_097;
+  let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+  let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+
+  (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }, month, day)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+  match month {
+    2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+/// Check the ranges of the date fields.
+fn check_fields(fields: &[i64; 7], offset: i64) -> Result<(), String> {
+  let [year, month, day, hour, minute, second, millisecond] = *fields;
+
+  if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+    return Err(format!("the year must be between {} and {}, got `{}`", MIN_YEAR, MAX_YEAR, year));
+  } else if !(1..=12).contains(&month) {
+    return Err(format!("the month must be between 1 and 12, got `{}`", month));
+  } else if day < 1 || day > days_in_month(year, month) {
+    return Err(format!("the day must be between 1 and {}, got `{}`", days_in_month(year, month), day));
+  } else if !(0..=23).contains(&hour) {
+    return Err(format!("the hour must be between 0 and 23, got `{}`", hour));
+  } else if !(0..=59).contains(&minute) {
+    return Err(format!("the minute must be between 0 and 59, got `{}`", minute));
+  } else if !(0..=59).contains(&second) {
+    return Err(format!("the second must be between 0 and 59, got `{}`", second));
+  } else if !(0..=999).contains(&millisecond) {
+    return Err(format!("the millisecond must be between 0 and 999, got `{}`", millisecond));
+  }
+
+  check_offset(offset as f64)
+}
+
+/// Check if the offset is less than a day in minutes.
+fn check_offset(offset: f64) -> Result<(), String> {
+  if offset.is_nan() || offset.abs() >= 1440.0 {
+    return Err(format!("the offset must be between -1439 and 1439 minutes, got `{}`", offset));
+  }
+
+  Ok(())
+}
+
+/// Check if the timestamp is in the range of the dates.
+fn check_timestamp(timestamp: i64) -> Result<i64, String> {
+  if !(-MAX_TIMESTAMP..=MAX_TIMESTAMP).contains(&timestamp) {
+    return Err(format!(
+      "the timestamp must be between {} and {} milliseconds, got `{}`",
+      -MAX_TIMESTAMP,
+      MAX_TIMESTAMP,
+      timestamp,
+    ));
+  }
+
+  Ok(timestamp)
+}
+
+/// Get the timestamp of a number in milliseconds.
+fn timestamp_from_number(milliseconds: f64) -> Result<i64, String> {
+  // Check if the number can be converted without saturating.
+  if !milliseconds.is_finite() || milliseconds.abs() > MAX_TIMESTAMP as f64 {
+    return Err(format!(
+      "the timestamp must be between {} and {} milliseconds, got `{}`",
+      -MAX_TIMESTAMP,
+      MAX_TIMESTAMP,
+      milliseconds,
+    ));
+  }
+
+  Ok(milliseconds.trunc() as i64)
+}
+
+/// Get the timestamp of the date fields in an offset, the fields must be checked first.
+fn timestamp_from_fields(fields: &[i64; 7], offset: i64) -> Result<i64, String> {
+  let [year, month, day, hour, minute, second, millisecond] = *fields;
+  let time = ((hour * 60 + minute) * 60 + second) * 1000 + millisecond;
+
+  days_from_civil(year, month, day)
+    .checked_mul(MILLISECONDS_PER_DAY)
+    .and_then(|timestamp| timestamp.checked_add(time - offset * MILLISECONDS_PER_MINUTE))
+    .ok_or_else(|| String::from("the date is out of range"))
+    .and_then(check_timestamp)
+}
+
+/// Get the `DateTime` hashmap of a timestamp in milliseconds and an offset in minutes.
+fn new_date(timestamp: i64, offset: i64) -> Box<Objects> {
+  let local = timestamp + offset * MILLISECONDS_PER_MINUTE;
+  let days = local.div_euclid(MILLISECONDS_PER_DAY);
+  let time = local.rem_euclid(MILLISECONDS_PER_DAY);
+  let (year, month, day) = civil_from_days(days);
+
+  let fields = [
+    ("timestamp", timestamp),
+    ("offset", offset),
+    ("year", year),
+    ("month", month),
+    ("day", day),
+    ("hour", time / 3_600_000),
+    ("minute", time / MILLISECONDS_PER_MINUTE % 60),
+    ("second", time / 1000 % 60),
+    ("millisecond", time % 1000),
+    // The weeks start on Sunday (0), the Unix epoch was a Thursday.
+    ("weekday", (days + 4).rem_euclid(7)),
+  ];
+
+  HashMap::new(
+    fields.iter()
+      .map(|(key, value)| HashItem { key: key.to_string(), value: Number::new(*value as f64) })
+      .collect()
+  )
+}
+
+fn new_duration(milliseconds: f64) -> Box<Objects> {
+  HashMap::new(vec![
+    HashItem {
+      key: String::from("milliseconds"),
+      value: Number::new(milliseconds),
+    },
+  ])
+}
+
+/// Get a number item of a hashmap argument.
+fn get_number_item(argument: &Objects, key: &str) -> Option<f64> {
+  argument.get_hashmap()?.get_item(key)?.get_number().map(|number| number.get_value())
+}
+
+/// Get the timestamp and the offset of a `DateTime` argument.
+fn get_date(token: &Token, argument: &Objects) -> Result<(i64, i64), Box<Objects>> {
+  match (get_number_item(argument, "timestamp"), get_number_item(argument, "offset")) {
+    (Some(timestamp), Some(offset)) if check_offset(offset).is_ok() => match timestamp_from_number(timestamp) {
+      Ok(timestamp) => Ok((timestamp, offset.trunc() as i64)),
+      Err(message) => Err(Error::new(format!("invalid date: {}.", message), token.clone())),
+    },
+    _ => Err(Error::new(
+      format!("`{}` is not a valid `DateTime` value.", argument.string()),
+      token.clone(),
+    )),
+  }
+}
+
+fn get_duration(token: &Token, argument: &Objects) -> Result<f64, Box<Objects>> {
+  get_number_item(argument, "milliseconds").ok_or_else(|| Error::new(
+    format!("`{}` is not a valid `Duration` value.", argument.string()),
+    token.clone(),
+  ))
+}
+
+/// Get a number argument, the missing optional arguments are zero.
+fn get_number(token: &Token, arguments: &[Box<Objects>], index: usize) -> Result<f64, Box<Objects>> {
+  match arguments.get(index) {
+    Some(argument) => argument.get_number().map(|number| number.get_value()).ok_or_else(|| Error::new(
+      format!("`{}` is not a number.", argument.string()),
+      token.clone(),
+    )),
+    None => Ok(0.0),
+  }
+}
+
+/// Get the optional offset argument in minutes.
+fn get_offset(token: &Token, arguments: &[Box<Objects>], index: usize) -> Result<i64, Box<Objects>> {
+  let offset = get_number(token, arguments, index)?;
+
+  check_offset(offset).map_err(|message| Error::new(format!("{}.", message), token.clone()))?;
+
+  Ok(offset.trunc() as i64)
+}
+
+/// Read the digits of a number with a fixed length.
+fn read_digits(chars: &[char], position: &mut usize, length: usize) -> Result<i64, String> {
+  let digits: String = chars.iter().skip(*position).take(length).collect();
+
+  if digits.chars().count() != length || !digits.chars().all(|digit| digit.is_ascii_digit()) {
+    return Err(format!("expect `{}` digits at position {}", length, *position + 1));
+  }
+
+  *position += length;
+
+  Ok(digits.parse().unwrap())
+}
+
+/// Expect a separator character.
+fn read_separator(chars: &[char], position: &mut usize, separators: &[char]) -> Result<(), String> {
+  match chars.get(*position) {
+    Some(current) if separators.contains(current) => {
+      *position += 1;
+      Ok(())
+    },
+    _ => Err(format!("expect `{}` at position {}", separators[0], *position + 1)),
+  }
+}
+
+/// Parse an ISO-8601 date (`2021-03-14`) or date time (`2021-03-14T15:09:26.535+02:00`), the date
+/// times without an offset are in UTC.
+fn parse_iso(text: &str) -> Result<(i64, i64), String> {
+  let chars: Vec<char> = text.chars().collect();
+  let mut position: usize = 0;
+  let mut fields: [i64; 7] = [0, 0, 0, 0, 0, 0, 0];
+  let mut offset: i64 = 0;
+
+  // Check if the year has the extended format (`+275760`, `-000001`).
+  fields[0] = match chars.first() {
+    Some(sign) if *sign == '+' || *sign == '-' => {
+      position += 1;
+
+      let year = read_digits(&chars, &mut position, 6)?;
+
+      if *sign == '-' && year == 0 {
+        return Err(String::from("the year `-000000` is not valid"));
+      }
+
+      if *sign == '-' { -year } else { year }
+    },
+    _ => read_digits(&chars, &mut position, 4)?,
+  };
+  read_separator(&chars, &mut position, &['-'])?;
+  fields[1] = read_digits(&chars, &mut position, 2)?;
+  read_separator(&chars, &mut position, &['-'])?;
+  fields[2] = read_digits(&chars, &mut position, 2)?;
+
+  // Check if the date has a time.
+  if position < chars.len() {
+    read_separator(&chars, &mut position, &['T', 't', ' '])?;
+    fields[3] = read_digits(&chars, &mut position, 2)?;
+    read_separator(&chars, &mut position, &[':'])?;
+    fields[4] = read_digits(&chars, &mut position, 2)?;
+
+    // Check if the time has seconds.
+    if chars.get(position) == Some(&':') {
+      position += 1;
+      fields[5] = read_digits(&chars, &mut position, 2)?;
+
+      // Check if the seconds have a fraction, only the milliseconds are kept.
+      if chars.get(position) == Some(&'.') || chars.get(position) == Some(&',') {
+        position += 1;
+
+        let fraction: String = chars[position..].iter().take_while(|digit| digit.is_ascii_digit()).collect();
+
+        if fraction.is_empty() {
+          return Err(format!("expect the fraction digits at position {}", position + 1));
+        }
+
+        position += fraction.len();
+        fields[6] = format!("{:0<3}", &fraction[..fraction.len().min(3)]).parse().unwrap();
+      }
+    }
+
+    // Get the offset.
+    match chars.get(position) {
+      Some('Z') | Some('z') => {
+        position += 1;
+      },
+      Some(sign) if *sign == '+' || *sign == '-' => {
+        let sign = if *sign == '-' { -1 } else { 1 };
+
+        position += 1;
+
+        let hours = read_digits(&chars, &mut position, 2)?;
+        let mut minutes = 0;
+
+        if position < chars.len() {
+          if chars[position] == ':' {
+            position += 1;
+          }
+
+          minutes = read_digits(&chars, &mut position, 2)?;
+        }
+
+        if minutes > 59 {
+          return Err(format!("the offset minutes must be between 0 and 59, got `{}`", minutes));
+        }
+
+        offset = sign * (hours * 60 + minutes);
+      },
+      _ => {},
+    }
+  }
+
+  if position < chars.len() {
+    return Err(format!("unexpected `{}` at position {}", chars[position], position + 1));
+  }
+
+  check_fields(&fields, offset)?;
+
+  Ok((timestamp_from_fields(&fields, offset)?, offset))
+}
+
+/// Get the ISO-8601 string of a date (`2021-03-14T15:09:26.535Z`).
+fn format_iso(timestamp: i64, offset: i64) -> String {
+  let local = timestamp + offset * MILLISECONDS_PER_MINUTE;
+  let time = local.rem_euclid(MILLISECONDS_PER_DAY);
+  let (year, month, day) = civil_from_days(local.div_euclid(MILLISECONDS_PER_DAY));
+
+  let zone = if offset == 0 {
+    String::from("Z")
+  } else {
+    format!("{}{:02}:{:02}", if offset < 0 { '-' } else { '+' }, offset.abs() / 60, offset.abs() % 60)
+  };
+
+  // The years out of `0000` to `9999` have the extended format with a sign and six digits.
+  let year = if (0..=9999).contains(&year) {
+    format!("{:04}", year)
+  } else {
+    format!("{}{:06}", if year < 0 { '-' } else { '+' }, year.abs())
+  };
+
+  format!(
+    "{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}{}",
+    year,
+    month,
+    day,
+    time / 3_600_000,
+    time / MILLISECONDS_PER_MINUTE % 60,
+    time / 1000 % 60,
+    time % 1000,
+    zone,
+  )
+}
+
+/// Get the ISO-8601 string of a duration (`PT1H30M`, `P2DT4H`).
+fn format_iso_duration(milliseconds: f64) -> String {
+  let sign = if milliseconds < 0.0 { "-" } else { "" };
+  let milliseconds = milliseconds.abs().round() as i64;
+
+  let days = milliseconds / MILLISECONDS_PER_DAY;
+  let hours = milliseconds / 3_600_000 % 24;
+  let minutes = milliseconds / MILLISECONDS_PER_MINUTE % 60;
+  let seconds = milliseconds % MILLISECONDS_PER_MINUTE;
+
+  let mut result = format!("{}P", sign);
+
+  if days > 0 {
+    result.push_str(&format!("{}D", days));
+  }
+
+  // The durations of whole days do not have a time.
+  if hours == 0 && minutes == 0 && seconds == 0 && days > 0 {
+    return result;
+  }
+
+  result.push('T');
+
+  if hours > 0 {
+    result.push_str(&format!("{}H", hours));
+  }
+
+  if minutes > 0 {
+    result.push_str(&format!("{}M", minutes));
+  }
+
+  if seconds > 0 || (hours == 0 && minutes == 0) {
+    if seconds % 1000 == 0 {
+      result.push_str(&format!("{}S", seconds / 1000));
+    } else {
+      let fraction = format!("{:03}", seconds % 1000);
+
+      result.push_str(&format!("{}.{}S", seconds / 1000, fraction.trim_end_matches('0')));
+    }
+  }
+
+  result
+}
+
+fn get_current_timestamp() -> i64 {
+  match SystemTime::now().duration_since(UNIX_EPOCH) {
+    Ok(duration) => duration.as_millis() as i64,
+    Err(error) => -(error.duration().as_millis() as i64),
+  }
+}
+
+pub fn now(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+    return error;
+  }
+
+  match get_offset(&token, &arguments, 0) {
+    Ok(offset) => new_date(get_current_timestamp(), offset),
+    Err(error) => error,
+  }
+}
+
+/// Get the milliseconds of a monotonic clock, only the difference between two instants is
+/// meaningful.
+pub fn instant(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+    return error;
+  }
+
+  Number::new(START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0)
+}
+
+pub fn elapsed(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  match get_number(&token, &arguments, 0) {
+    Ok(start) => new_duration(START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0 - start),
+    Err(error) => error,
+  }
+}
+
+/// Create a duration from a value and a unit: `ms`, `s`, `min`, `h` or `d`.
+pub fn duration(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+    return error;
+  }
+
+  let value = match get_number(&token, &arguments, 0) {
+    Ok(value) => value,
+    Err(error) => {
+      return error;
+    },
+  };
+
+  let unit = arguments.get(1).map_or(String::from("ms"), |unit| unit.string());
+
+  let factor = match unit.as_str() {
+    "ms" => 1.0,
+    "s" => 1000.0,
+    "min" => 60_000.0,
+    "h" => 3_600_000.0,
+    "d" => 86_400_000.0,
+    _ => {
+      return Error::new(
+        format!("`{}` is not a valid duration unit, expect `ms`, `s`, `min`, `h` or `d`.", unit),
+        token,
+      );
+    },
+  };
+
+  new_duration(value * factor)
+}
+
+/// Create a date from the fields in an offset, the month starts at 1.
+pub fn date(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 3, 8) {
+    return error;
+  }
+
+  let mut fields: [i64; 7] = [0, 0, 0, 0, 0, 0, 0];
+
+  for (index, field) in fields.iter_mut().enumerate() {
+    match get_number(&token, &arguments, index) {
+      // Check if the field can be converted without saturating.
+      Ok(value) if !value.is_finite() || value.abs() > i32::MAX as f64 => {
+        return Error::new(format!("invalid date: the field `{}` is out of range.", value), token);
+      },
+      Ok(value) => *field = value.trunc() as i64,
+      Err(error) => {
+        return error;
+      },
+    }
+  }
+
+  let offset = match get_offset(&token, &arguments, 7) {
+    Ok(offset) => offset,
+    Err(error) => {
+      return error;
+    },
+  };
+
+  match check_fields(&fields, offset).and_then(|_| timestamp_from_fields(&fields, offset)) {
+    Ok(timestamp) => new_date(timestamp, offset),
+    Err(message) => Error::new(format!("invalid date: {}.", message), token),
+  }
+}
+
+pub fn from_timestamp(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+    return error;
+  }
+
+  let timestamp = match get_number(&token, &arguments, 0) {
+    Ok(timestamp) => timestamp,
+    Err(error) => {
+      return error;
+    },
+  };
+
+  let timestamp = match timestamp_from_number(timestamp) {
+    Ok(timestamp) => timestamp,
+    Err(message) => {
+      return Error::new(format!("invalid date: {}.", message), token);
+    },
+  };
+
+  match get_offset(&token, &arguments, 1) {
+    Ok(offset) => new_date(timestamp, offset),
+    Err(error) => error,
+  }
+}
+
+/// Get the same instant of a date in another offset.
+pub fn with_offset(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+    return error;
+  }
+
+  let (timestamp, _) = match get_date(&token, &arguments[0]) {
+    Ok(date) => date,
+    Err(error) => {
+      return error;
+    },
+  };
+
+  match get_offset(&token, &arguments, 1) {
+    Ok(offset) => new_date(timestamp, offset),
+    Err(error) => error,
+  }
+}
+
+pub fn add(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+    return error;
+  }
+
+  let (timestamp, offset) = match get_date(&token, &arguments[0]) {
+    Ok(date) => date,
+    Err(error) => {
+      return error;
+    },
+  };
+
+  match get_duration(&token, &arguments[1]) {
+    Ok(milliseconds) => {
+      // Check if the duration can be converted without saturating, the result is checked after.
+      let timestamp = Some(milliseconds.round())
+        .filter(|milliseconds| milliseconds.abs() <= 2.0 * MAX_TIMESTAMP as f64)
+        .and_then(|milliseconds| timestamp.checked_add(milliseconds as i64))
+        .ok_or_else(|| String::from("the date is out of range"))
+        .and_then(check_timestamp);
+
+      match timestamp {
+        Ok(timestamp) => new_date(timestamp, offset),
+        Err(message) => Error::new(format!("invalid date: {}.", message), token),
+      }
+    },
+    Err(error) => error,
+  }
+}
+
+/// Get the duration from the first date to the second date.
+pub fn diff(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+    return error;
+  }
+
+  let dates = get_date(&token, &arguments[0])
+    .and_then(|start| get_date(&token, &arguments[1]).map(|end| (start.0, end.0)));
+
+  match dates {
+    Ok((start, end)) => new_duration((end - start) as f64),
+    Err(error) => error,
+  }
+}
+
+pub fn parse(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  let text = arguments[0].string();
+
+  match parse_iso(&text) {
+    Ok((timestamp, offset)) => new_date(timestamp, offset),
+    Err(message) => Error::new(format!("invalid ISO-8601 date `{}`: {}.", text, message), token),
+  }
+}
+
+pub fn to_iso(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  match get_date(&token, &arguments[0]) {
+    Ok((timestamp, offset)) => StringO::new(format_iso(timestamp, offset)),
+    Err(error) => error,
+  }
+}
+
+pub fn format_duration(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  match get_duration(&token, &arguments[0]) {
+    Ok(milliseconds) => StringO::new(format_iso_duration(milliseconds)),
+    Err(error) => error,
+  }
+}
+
+pub fn sleep(token: Token, arguments: Vec<Box<Objects>>, _environment: &mut Environment) -> Box<Objects> {
+  if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+    return error;
+  }
+
+  match get_number(&token, &arguments, 0) {
+    Ok(milliseconds) => {
+      // Check if the milliseconds are a valid duration.
+      let duration = if milliseconds.is_finite() {
+        Duration::try_from_secs_f64(milliseconds.max(0.0) / 1000.0).ok()
+      } else {
+        None
+      };
+
+      match duration {
+        Some(duration) => {
+          thread::sleep(duration);
+
+          Null::new()
+        },
+        None => Error::new(format!("`{}` is not a valid sleep duration.", arguments[0].string()), token),
+      }
+    },
+    Err(error) => error,
+  }
+}
+
+#[test]
+fn format_iso_extended_years() {
+  assert_eq!(format_iso(0, 0), "1970-01-01T00:00:00.000Z");
+  assert_eq!(format_iso(MAX_TIMESTAMP, 0), "+275760-09-13T00:00:00.000Z");
+  assert_eq!(format_iso(-MAX_TIMESTAMP, 0), "-271821-04-20T00:00:00.000Z");
+  assert_eq!(
+    parse_iso("-000005-01-01").map(|(timestamp, _)| format_iso(timestamp, 0)),
+    Ok(String::from("-000005-01-01T00:00:00.000Z")),
+  );
+}
+
+#[test]
+fn timestamps_out_of_range() {
+  assert_eq!(timestamp_from_number(8.64e15), Ok(MAX_TIMESTAMP));
+  assert!(timestamp_from_number(1e20).is_err());
+  assert!(timestamp_from_number(f64::NAN).is_err());
+  assert!(parse_iso("+275760-09-13T00:00:00.001Z").is_err());
+  assert!(timestamp_from_fields(&[MAX_YEAR, 12, 31, 0, 0, 0, 0], 0).is_err());
+}
@@ -4,20 +4,27 @@ mod hashmap;
 mod infix;
 mod prefix;
 
+pub use call::call_function;
+
 use crate::{
   compiler::{
     AnonymousFunction,
     Array,
     Boolean,
-    builtins::get_builtin_for_identifier,
+    BuiltIn,
+    builtins::{
+      element_index,
+      get_builtin_for_identifier,
+    },
     Error,
     Null,
     Number,
-    Object,
     Objects,
+    RegExp,
     StringO,
   },
   Environment,
+  utils::Regex,
 };
 
 use sflyn_parser::{
@@ -56,6 +63,11 @@ pub fn evaluate_expression(
 ) -> Box<Objects> {
   // Anonymous function
   if let Some(anonymous_function) = expression.get_anonymous_function() {
+    // Native functions are bound to their Rust implementation when called as methods.
+    if anonymous_function.is_native() {
+      return BuiltIn::new_box(None, None);
+    }
+
     AnonymousFunction::add_arguments_to_environment(
       anonymous_function.get_arguments(),
       environment,
@@ -112,40 +124,29 @@ pub fn evaluate_expression(
     }
 
     // Get string value.
-    if identifier_obj.get_string().is_some() && index_obj.get_number().is_some() {
-      let index: usize;
-      let string: String = identifier_obj.get_string().unwrap().get_value();
-      let value: String = index_obj.get_number().unwrap().string();
-
-      if value == "-1" {
-        index = string.len() - 1;
-      } else {
-        index = value.parse().unwrap();
-      }
-
-      if index >= string.len() {
-        return Null::new();
-      }
+    if let (Some(string), Some(_)) = (identifier_obj.get_string(), index_obj.get_number()) {
+      let chars: Vec<char> = string.get_value().chars().collect();
 
-      return StringO::new(string[index..index + 1].to_string());
+      return match element_index(&index_obj, chars.len()) {
+        Some(index) if index < chars.len() => StringO::new(chars[index].to_string()),
+        _ => Null::new(),
+      };
     }
     // Get array value.
-    else if identifier_obj.get_array().is_some() && index_obj.get_number().is_some() {
-      let index: usize;
-      let elements = identifier_obj.get_array().unwrap().get_elements();
-      let value = index_obj.get_number().unwrap().string();
-
-      if value == "-1" {
-        index = if elements.len() > 0 { elements.len() - 1 } else { 0 };
-      } else {
-        index = value.parse().unwrap();
-      }
-
-      if index >= elements.len() {
-        return Null::new();
-      }
+    else if let (Some(array), Some(_)) = (identifier_obj.get_array(), index_obj.get_number()) {
+      let elements = array.get_elements();
 
-      return elements[index].clone();
+      return match element_index(&index_obj, elements.len()) {
+        Some(index) if index < elements.len() => elements[index].clone(),
+        _ => Null::new(),
+      };
+    }
+    // Get hashmap value.
+    else if let Some(hashmap) = identifier_obj.get_hashmap() {
+      return match hashmap.get_item(&index_obj.string()) {
+        Some(object) => object,
+        None => Null::new(),
+      };
     }
   }
 
@@ -198,6 +199,14 @@ pub fn evaluate_expression(
     return prefix::evaluate(&prefix_exp, environment);
   }
 
+  // Regular Expression
+  if let Some(regexp) = expression.get_regexp() {
+    return match Regex::new(&regexp.get_pattern(), &regexp.get_flags()) {
+      Ok(regex) => RegExp::new(regex),
+      Err(message) => Error::new(message, regexp.get_token()),
+    };
+  }
+
   // String
   if let Some(string) = expression.get_string() {
     return StringO::new(string.get_value()[1..string.get_value().len() - 1].to_string());
@@ -1,8 +1,10 @@
 use crate::{
   compiler::{
+    Array,
     builtins::get_builtin_for_identifier,
     Error,
     evaluate_statement,
+    Null,
     Objects,
   },
   Environment,
@@ -13,6 +15,7 @@ use sflyn_parser::{
   Argument,
   Call,
   Expression,
+  tokens::Token,
 };
 
 use super::evaluate_expressions;
@@ -40,40 +43,67 @@ pub fn evaluate(
     return arguments[0].clone();
   }
 
+  call_function(
+    function_object,
+    arguments,
+    Some(call.get_token().value),
+    call.get_token(),
+    environment,
+  )
+}
+
+/// Call a function object with the evaluated arguments, the name is used to allow recursive calls.
+pub fn call_function(
+  function_object: Box<Objects>,
+  arguments: Vec<Box<Objects>>,
+  name: Option<String>,
+  token: Token,
+  environment: &mut Environment,
+) -> Box<Objects> {
   // Check if the function object is an anonymous function.
   if let Some(anonymous_function) = function_object.get_anonymous_function() {
-    let mut index: usize = 0;
-
     let mut function_environment = environment.clone();
 
     function_environment.store = Store::from_store(&anonymous_function.store);
 
-    // Add call arguments to the function environment.
-    for argument in arguments {
-      let function_argument: Argument = anonymous_function.arguments[index].get_argument().unwrap();
+    // Add the function to its own environment to allow recursive calls.
+    if let Some(name) = name {
+      function_environment.store.set_object(name, function_object.clone());
+    }
+
+    // Add call arguments to the function environment, the arguments that the function does not
+    // declare are ignored.
+    for (index, function_argument) in anonymous_function.arguments.iter().enumerate() {
+      let function_argument: Argument = function_argument.get_argument().unwrap();
 
-      function_environment.store.set_object(function_argument.get_token().value, argument);
+      // Check if the argument collects the remaining call arguments.
+      if function_argument.is_rest() {
+        let rest = arguments.get(index..).map_or(Vec::new(), |rest| rest.to_vec());
 
-      index += 1;
+        function_environment.store.set_object(function_argument.get_token().value, Array::new(rest));
+        break;
+      }
+
+      if let Some(argument) = arguments.get(index) {
+        function_environment.store.set_object(function_argument.get_token().value, argument.clone());
+      }
     }
 
     return match evaluate_statement(&anonymous_function.body, &mut function_environment) {
       Some(object) => object,
-      None => Error::new(
-        format!("unknown statement"),
-        anonymous_function.body.token(),
-      ),
+      // The function body does not return a value.
+      None => Null::new(),
     };
   }
   // Check if the function object is a builtin.
   else if let Some(builtin) = function_object.get_builtin() {
     if let Some(fun) = builtin.get_function() {
-      return (fun)(call.get_token(), arguments);
+      return (fun)(token, arguments, environment);
     }
   }
 
   Error::new(
-    format!("Unknown function: {}", call.get_token().value),
-    call.get_token(),
+    format!("Unknown function: {}", token.value),
+    token,
   )
 }
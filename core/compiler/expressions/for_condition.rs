@@ -44,7 +44,7 @@ fn cycle(
   }
 
   if let Some(boolean_obj) = second_obj_bool.get_boolean() {
-    if boolean_obj.get_value() == true {
+    if boolean_obj.get_value() {
       elements.push(third_obj);
 
       return cycle(key, second_expression, third_expression, environment, elements);
@@ -83,6 +83,17 @@ pub fn evaluate(
 
       environment.store.set_object(key.clone(), first_obj.clone());
 
+      // Check if the first value satisfies the condition (an empty standard input).
+      let condition = evaluate_expression(&for_condition.get_second(), environment);
+
+      if condition.get_error().is_some() {
+        return condition;
+      }
+
+      if !condition.expect_boolean(true) {
+        return ForIn::new(key, Vec::new());
+      }
+
       return cycle(
         key,
         &for_condition.get_second(),
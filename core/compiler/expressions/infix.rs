@@ -1,10 +1,18 @@
 use crate::{
   compiler::{
     Array,
+    builtins::{
+      element_index,
+      get_module_native,
+      get_native,
+      is_mutating_native,
+      NativeFn,
+    },
     Boolean,
     Error,
     ForIn,
     ForOf,
+    Null,
     Number,
     Objects,
     StringO,
@@ -14,16 +22,21 @@ use crate::{
 };
 
 use sflyn_parser::{
+  ArrayIndex,
+  Call,
   Expression,
+  Expressions,
   Infix,
   tokens::{
     Keywords,
     Signs,
-    Types,
   },
 };
 
-use super::evaluate_expression;
+use super::{
+  evaluate_expression,
+  evaluate_expressions,
+};
 
 pub fn evaluate(
   infix: &Infix,
@@ -48,7 +61,7 @@ pub fn evaluate(
   }
   // Check if the token is 'of'.
   else if infix.get_token().token.expect_keyword(&Keywords::OF) {
-    if infix.get_left().get_array().is_none() {
+    if infix.get_left().get_array().is_none() && infix.get_left().get_identifier().is_none() {
       return Error::new(
         String::from("is not a valid expression for an `of`."),
         infix.get_left().token(),
@@ -87,6 +100,38 @@ pub fn evaluate(
       for item in hashmap.get_data() {
         right_environment.store.set_object(item.key, item.value);
       }
+
+      // The missing optional keys are null.
+      if let Some(identifier) = infix.get_right().and_then(|right| right.get_identifier()) {
+        if hashmap.get_item(&identifier.get_value()).is_none() {
+          right_environment.store.set_object(identifier.get_value(), Null::new());
+        }
+      }
+
+      // Check if the method is a native function of a standard library module (`Math->floor()`).
+      if let (Some(module), Some(call)) = (
+        infix.get_left().get_identifier(),
+        infix.get_right().and_then(|right| right.get_call()),
+      ) {
+        // The functions of an imported module (`import * as fs from 'fs';`) already have their
+        // implementation.
+        let is_native = hashmap.get_item(&call.get_token().value)
+          .and_then(|item| item.get_builtin())
+          .is_some_and(|builtin| builtin.get_function().is_none());
+
+        if is_native {
+          let native = get_module_native(&module.get_value(), &call.get_token().value);
+
+          return evaluate_native(&module.get_value(), native, &call, left_object, infix, environment);
+        }
+      }
+
+      // The hashmap items have priority over the `HashMap` standard library.
+      if let Some(right_token) = infix.get_right().map(|right| right.token()) {
+        if hashmap.get_item(&right_token.value).is_none() {
+          name = "HashMap";
+        }
+      }
     } else if left_object.get_number().is_some() {
       name = "Number";
     } else if left_object.get_boolean().is_some() {
@@ -97,6 +142,14 @@ pub fn evaluate(
       name = "Null";
     } else if left_object.get_string().is_some() {
       name = "String";
+    } else if left_object.get_map().is_some() {
+      name = "Map";
+    } else if left_object.get_set().is_some() {
+      name = "Set";
+    } else if left_object.get_regexp().is_some() {
+      name = "RegExp";
+    } else if left_object.get_bytes().is_some() {
+      name = "Bytes";
     }
 
     if !name.is_empty() {
@@ -106,31 +159,16 @@ pub fn evaluate(
           for item in hashmap.get_data() {
             right_environment.store.set_object(item.key, item.value);
           }
-        }
-      }
-    }
-  }
 
-  if left_object.clone().is_some() &&
-    left_object.clone().unwrap().get_string().is_some() &&
-    infix.get_right().is_some() &&
-    infix.get_right().unwrap().token().value == "split" &&
-    infix.get_right().unwrap().get_call().is_some() {
-    let call_right = infix.get_right().unwrap().get_call().unwrap();
-    let arguments = call_right.get_arguments();
-
-    if arguments.len() == 1 {
-      let arg = arguments[0].clone();
-
-      if let Some(string) = arg.get_string() {
-        let split_value = string.get_value();
-        let elements: Vec<Box<Objects>> = left_object.unwrap()
-          .get_string().unwrap().get_value()
-          .split(&split_value[1..split_value.len() - 1])
-          .map(|x| StringO::new(x.to_string()))
-          .collect();
-
-        return Array::new(elements[1..elements.len() - 1].to_vec());
+          // Check if the method is a native function.
+          if let Some(call) = infix.get_right().and_then(|right| right.get_call()) {
+            if hashmap.get_item(&call.get_token().value).is_some_and(|item| item.get_builtin().is_some()) {
+              let native = get_native(name, &call.get_token().value);
+
+              return evaluate_native(name, native, &call, left_object, infix, environment);
+            }
+          }
+        }
       }
     }
   }
@@ -138,18 +176,20 @@ pub fn evaluate(
   if infix.is_type() {
     let right_type = infix.get_right_type().unwrap();
 
-    if let Some(data_type) = right_type.token.get_type() {
-      let left_object: Box<Objects> = left_object.unwrap();
+    return Boolean::new(left_object.unwrap().is_type(&right_type, &environment.store));
+  } else if infix.is_alias() {
+    let right_type = infix.get_right_type().unwrap();
+    let left_object: Box<Objects> = left_object.unwrap();
 
-      return Boolean::new(
-        (left_object.get_boolean().is_some() && data_type == Types::BOOLEAN) ||
-        (left_object.get_null().is_some() && data_type == Types::NULL) ||
-        (left_object.get_number().is_some() && data_type == Types::NUMBER) ||
-        (left_object.get_string().is_some() && data_type == Types::STRING)
+    // Check if the cast is valid.
+    if !left_object.is_type(&right_type, &environment.store) {
+      return Error::new(
+        format!("can not cast `{}` to `{}`.", left_object.string(), right_type.value),
+        infix.get_token(),
       );
     }
 
-    return Boolean::new(false);
+    return left_object;
   } else {
     // Evaluate right expression.
     let mut right_object: Box<Objects> = evaluate_expression(
@@ -168,25 +208,28 @@ pub fn evaluate(
     }
 
     // Parse method.
-    if infix.is_method() && left_object.is_some() {
-      let left_object = left_object.unwrap();
+    if let (true, Some(left_object)) = (infix.is_method(), left_object.as_ref()) {
       let right_token = infix.get_right().unwrap().token();
 
-      // Check if the method is 'toString()'.
-      if right_token.value == "toString" && (
-        left_object.get_string().is_some() ||
-        left_object.get_number().is_some() ||
-        left_object.get_boolean().is_some() ||
-        left_object.get_array().is_some()
-      ) {
-        return StringO::new(left_object.string());
+      // Check if the method is 'size' in a hashmap without a `size` item, a map or a set.
+      if right_token.value == "size" {
+        if let Some(hashmap_obj) = left_object.get_hashmap() {
+          if hashmap_obj.get_item("size").is_none() {
+            return Number::new(hashmap_obj.get_data().len() as f64);
+          }
+        } else if let Some(map_obj) = left_object.get_map() {
+          return Number::new(map_obj.get_size() as f64);
+        } else if let Some(set_obj) = left_object.get_set() {
+          return Number::new(set_obj.get_size() as f64);
+        }
       }
-      // Check if the method is 'length' in a string or an array.
-      else if right_token.value == "length" {
+
+      // Check if the method is 'length' in a string, an array or a bytes value.
+      if right_token.value == "length" {
         if let Some(string_obj) = left_object.get_string() {
           return Number::new(
             string_obj
-              .get_value().len()
+              .get_value().chars().count()
               .to_string().parse().unwrap()
           );
         } else if let Some(array_obj) = left_object.get_array() {
@@ -195,38 +238,8 @@ pub fn evaluate(
               .get_elements().len()
               .to_string().parse().unwrap()
           );
-        }
-      }
-      // Check if the method is 'push' in an array.
-      else if right_token.value == "push" && left_object.get_array().is_some() {
-        let mut array = left_object.get_array().unwrap();
-
-        if let Some(call) = infix.get_right().unwrap().get_call() {
-          let mut obj: Box<Objects> = evaluate_expression(
-            &call.get_arguments()[0].clone(),
-            environment,
-          );
-
-          if obj.get_error().is_some() {
-            return obj;
-          }
-
-          if let Some(return_o) = obj.get_return() {
-            obj = return_o.get_value();
-          }
-
-          array.add_element(&obj);
-
-          let array_box = Box::new(Objects::ARRAY(array));
-
-          if let Some(identifier) = infix.get_left().get_identifier() {
-            environment.store.replace_object(
-              &identifier.get_value(),
-              array_box.clone(),
-            );
-          }
-
-          return array_box;
+        } else if let Some(bytes_obj) = left_object.get_bytes() {
+          return Number::new(bytes_obj.get_data().len() as f64);
         }
       }
 
@@ -286,7 +299,7 @@ pub fn evaluate(
 
         // false boolean
         if let Some(boolean) = left_object.get_boolean() {
-          return_right = boolean.get_value() == false;
+          return_right = !boolean.get_value();
         }
 
         // Return the object.
@@ -317,16 +330,61 @@ pub fn evaluate(
             name, 
             right_object.get_array().unwrap().get_elements(),
           );
+        } else if let Some(hashmap) = right_object.get_hashmap() {
+          let mut name: String = String::new();
+
+          if let Some(identifier) = infix.get_left().get_identifier() {
+            name = identifier.get_value();
+          }
+
+          return ForIn::new(
+            name,
+            hashmap.get_data().iter().map(|item| StringO::new(item.key.clone())).collect(),
+          );
+        } else if let Some(map) = right_object.get_map() {
+          let mut name: String = String::new();
+
+          if let Some(identifier) = infix.get_left().get_identifier() {
+            name = identifier.get_value();
+          }
+
+          return ForIn::new(
+            name,
+            map.get_entries().into_iter().map(|(key, _)| key).collect(),
+          );
+        } else if let Some(set) = right_object.get_set() {
+          let mut name: String = String::new();
+
+          if let Some(identifier) = infix.get_left().get_identifier() {
+            name = identifier.get_value();
+          }
+
+          return ForIn::new(name, set.get_items());
         }
 
         return Error::new(
-          String::from("expect an array expression."),
+          String::from("expect an array, hashmap, map or set expression."),
           infix.get_right().unwrap().token(),
         );
       }
       // Check if the token is 'of'.
       else if infix.get_token().token.expect_keyword(&Keywords::OF) {
-        if let Some(hashmap) = right_object.get_hashmap() {
+        // Iterate the items of a set.
+        if let (Some(set), Some(identifier)) = (right_object.get_set(), infix.get_left().get_identifier()) {
+          return ForIn::new(identifier.get_value(), set.get_items());
+        }
+
+        // Get the entries of a hashmap or a map, the hashmap keys are strings unless they were set
+        // with a number index.
+        let entries = match (right_object.get_hashmap(), right_object.get_map()) {
+          (Some(hashmap), _) => Some(
+            hashmap.get_data().into_iter().map(|item| (hashmap.get_key(&item.key), item.value)).collect(),
+          ),
+          (_, Some(map)) => Some(map.get_entries()),
+          _ => None,
+        };
+
+        if let Some(entries) = entries {
           if let Some(left_array) = infix.get_left().get_array() {
             if left_array.get_data().len() != 2 {
               return Error::new(
@@ -349,12 +407,12 @@ pub fn evaluate(
               );
             }
 
-            return ForOf::new(names, hashmap.get_data());
+            return ForOf::new(names, entries);
           }
         }
 
         return Error::new(
-          String::from("expect an hashmap expression."),
+          String::from("expect an hashmap, map or set expression."),
           infix.get_right().unwrap().token(),
         );
       }
@@ -373,16 +431,22 @@ pub fn evaluate(
           if let Some(env_obj) = environment.store.get_object(&array_index.get_token().value) {
             if let Some(array_obj) = env_obj.get_array() {
               let mut array_obj: Array = array_obj.clone();
-              let mut index: usize = 0;
+              let index_obj = evaluate_expression(&array_index.get_index(), environment);
 
-              if let Some(number) = array_index.get_index().get_number() {
-                index = number.string().parse().unwrap();
-              } else if let Some(prefix) = array_index.get_index().get_prefix() {
-                if prefix.string() == "-1" {
-                  index = array_obj.get_elements().len() - 1;
-                }
+              if index_obj.get_error().is_some() {
+                return index_obj;
               }
 
+              let index = match element_index(&index_obj, array_obj.get_elements().len()) {
+                Some(index) => index,
+                None => {
+                  return Error::new(
+                    format!("`{}` is not a valid index.", index_obj.string()),
+                    array_index.get_index().token(),
+                  );
+                },
+              };
+
               array_obj.replace_element(index, &right_object);
 
               let new_array = Box::new(Objects::ARRAY(array_obj));
@@ -393,6 +457,21 @@ pub fn evaluate(
               );
 
               return new_array;
+            } else if let Some(mut hashmap_obj) = env_obj.get_hashmap() {
+              let index_obj = evaluate_expression(&array_index.get_index(), environment);
+
+              if index_obj.get_error().is_some() {
+                return index_obj;
+              }
+
+              hashmap_obj.set_index_item(&index_obj, right_object.clone());
+
+              environment.store.replace_object(
+                &array_index.get_token().value,
+                Box::new(Objects::HASHMAP(hashmap_obj)),
+              );
+
+              return right_object;
             }
           }
         }
@@ -457,7 +536,7 @@ pub fn evaluate(
           );
 
           if let Some(identifier) = infix.get_left().get_identifier() {
-            environment.store.set_object(identifier.get_value(), new_object.clone());
+            environment.store.replace_object(&identifier.get_value(), new_object.clone());
             return new_object;
           } else if let Some(array_index) = infix.get_left().get_array_index() {
             if let Some(env_obj) = environment.store.get_object(&array_index.get_token().value) {
@@ -493,3 +572,138 @@ pub fn evaluate(
 
   error
 }
+
+fn evaluate_native(
+  prototype: &str,
+  native: Option<NativeFn>,
+  call: &Call,
+  mut this: Box<Objects>,
+  infix: &Infix,
+  environment: &mut Environment,
+) -> Box<Objects> {
+  let native = match native {
+    Some(native) => native,
+    None => {
+      return Error::new(
+        format!("`{}.{}` does not have a native implementation.", prototype, call.get_token().value),
+        call.get_token(),
+      );
+    },
+  };
+
+  // Compile arguments.
+  let arguments = evaluate_expressions(call.get_arguments(), environment);
+
+  // Check if the first argument is an error.
+  if arguments.len() == 1 && arguments[0].get_error().is_some() {
+    return arguments[0].clone();
+  }
+
+  let object = (native)(call.get_token(), &mut this, arguments, environment);
+
+  // Check if the native function updates the receiver.
+  if is_mutating_native(prototype, &call.get_token().value) {
+    if let Some(error) = update_receiver(&infix.get_left(), this, environment) {
+      return error;
+    }
+  }
+
+  object
+}
+
+/// Store the updated receiver of a native function in its variable, the receiver can be an item of
+/// a hashmap (`user->tags`) or an element of an array or a hashmap (`list[0]`), the other receivers
+/// are temporary values.
+fn update_receiver(
+  receiver: &Expressions,
+  object: Box<Objects>,
+  environment: &mut Environment,
+) -> Option<Box<Objects>> {
+  // Check if the receiver is a variable.
+  if let Some(identifier) = receiver.get_identifier() {
+    environment.store.replace_object(&identifier.get_value(), object);
+
+    return None;
+  }
+
+  // Check if the receiver is an element of a variable.
+  if let Some(array_index) = receiver.get_array_index() {
+    let env_obj = environment.store.get_object(&array_index.get_token().value)?;
+    let new_object = update_element(&array_index, env_obj, object, environment);
+
+    if new_object.get_error().is_some() {
+      return Some(new_object);
+    }
+
+    environment.store.replace_object(&array_index.get_token().value, new_object);
+
+    return None;
+  }
+
+  // Check if the receiver is an item of a hashmap or an element of the item (`team->rounds[0]`).
+  if let Some(infix) = receiver.get_infix().filter(|infix| infix.is_method()) {
+    let right = infix.get_right()?;
+    let left_object = evaluate_expression(&infix.get_left(), environment);
+
+    if left_object.get_error().is_some() {
+      return Some(left_object);
+    }
+
+    let mut hashmap_obj = left_object.get_hashmap()?;
+
+    if let Some(identifier) = right.get_identifier() {
+      hashmap_obj.set_item(identifier.get_value(), object);
+    } else if let Some(array_index) = right.get_array_index() {
+      let item = hashmap_obj.get_item(&array_index.get_token().value)?;
+      let new_item = update_element(&array_index, item, object, environment);
+
+      if new_item.get_error().is_some() {
+        return Some(new_item);
+      }
+
+      hashmap_obj.set_item(array_index.get_token().value, new_item);
+    } else {
+      return None;
+    }
+
+    return update_receiver(&infix.get_left(), Box::new(Objects::HASHMAP(hashmap_obj)), environment);
+  }
+
+  None
+}
+
+/// Replace an element of an array or a hashmap, get the updated array or hashmap.
+fn update_element(
+  array_index: &ArrayIndex,
+  container: Box<Objects>,
+  object: Box<Objects>,
+  environment: &mut Environment,
+) -> Box<Objects> {
+  let index_obj = evaluate_expression(&array_index.get_index(), environment);
+
+  if index_obj.get_error().is_some() {
+    return index_obj;
+  }
+
+  if let Some(mut array_obj) = container.get_array() {
+    let index = match element_index(&index_obj, array_obj.get_elements().len()) {
+      Some(index) => index,
+      None => {
+        return Error::new(
+          format!("`{}` is not a valid index.", index_obj.string()),
+          array_index.get_index().token(),
+        );
+      },
+    };
+
+    array_obj.replace_element(index, &object);
+
+    return Box::new(Objects::ARRAY(array_obj));
+  } else if let Some(mut hashmap_obj) = container.get_hashmap() {
+    hashmap_obj.set_index_item(&index_obj, object);
+
+    return Box::new(Objects::HASHMAP(hashmap_obj));
+  }
+
+  container
+}
@@ -2,28 +2,36 @@ mod anonymous_function;
 mod array;
 mod boolean;
 mod builtin;
+mod bytes;
 mod continue_break;
 mod error;
 mod for_in;
 mod for_of;
 mod hashmap;
+mod map;
 mod null;
 mod number;
 mod object;
+mod regexp;
 mod return_o;
+mod set;
 mod string_o;
 
 pub use anonymous_function::*;
 pub use array::*;
 pub use boolean::*;
 pub use builtin::*;
+pub use bytes::*;
 pub use continue_break::*;
 pub use error::*;
 pub use for_in::*;
 pub use for_of::*;
 pub use hashmap::*;
+pub use map::*;
 pub use null::*;
 pub use number::*;
 pub use object::*;
+pub use regexp::*;
 pub use return_o::*;
+pub use set::*;
 pub use string_o::*;
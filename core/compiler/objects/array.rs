@@ -1,7 +1,23 @@
+use crate::{
+  compiler::{
+    builtins::{
+      check_arguments,
+      relative_index,
+    },
+    call_function,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
 use super::{
+  Boolean,
   Null,
+  Number,
   Object,
   Objects,
+  StringO,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -46,3 +62,545 @@ impl Array {
     }
   }
 }
+
+/// Sort the elements with a stable merge sort, `is_after` checks if the first element goes after
+/// the second one. Any comparison result is accepted and the first error stops the sort.
+fn merge_sort<T, E>(
+  elements: Vec<T>,
+  mut is_after: impl FnMut(&T, &T) -> Result<bool, E>,
+) -> Result<Vec<T>, E> {
+  let mut runs: Vec<Vec<T>> = elements.into_iter().map(|element| vec![element]).collect();
+
+  while runs.len() > 1 {
+    let mut merged_runs: Vec<Vec<T>> = Vec::with_capacity(runs.len() / 2 + 1);
+    let mut iterator = runs.into_iter();
+
+    while let Some(left) = iterator.next() {
+      let right = match iterator.next() {
+        Some(right) => right,
+        None => {
+          merged_runs.push(left);
+          break;
+        },
+      };
+
+      let mut merged: Vec<T> = Vec::with_capacity(left.len() + right.len());
+      let mut left = left.into_iter().peekable();
+      let mut right = right.into_iter().peekable();
+
+      while let (Some(one), Some(two)) = (left.peek(), right.peek()) {
+        // The left element goes first when both are equal to keep the sort stable.
+        if is_after(one, two)? {
+          merged.extend(right.next());
+        } else {
+          merged.extend(left.next());
+        }
+      }
+
+      merged.extend(left);
+      merged.extend(right);
+      merged_runs.push(merged);
+    }
+
+    runs = merged_runs;
+  }
+
+  Ok(runs.pop().unwrap_or_default())
+}
+
+/// Call a callback of an array method and unwrap its return value.
+fn call_callback(
+  token: &Token,
+  callback: &Objects,
+  arguments: Vec<Box<Objects>>,
+  environment: &mut Environment,
+) -> Box<Objects> {
+  let object = call_function(Box::new(callback.clone()), arguments, None, token.clone(), environment);
+
+  // Check if the object is a return.
+  if let Some(return_o) = object.get_return() {
+    return return_o.get_value();
+  }
+
+  object
+}
+
+// Native methods of the `Array` standard library.
+impl Array {
+  pub fn push(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let mut array = this.get_array().unwrap();
+
+    array.add_element(&arguments[0]);
+
+    let length = array.elements.len() as f64;
+
+    // Update the receiver with the new element.
+    **this = Objects::ARRAY(array);
+
+    Number::new(length)
+  }
+
+  pub fn pop(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    let mut array = this.get_array().unwrap();
+    let element = array.elements.pop().unwrap_or_else(Null::new);
+
+    **this = Objects::ARRAY(array);
+
+    element
+  }
+
+  pub fn shift(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    let mut array = this.get_array().unwrap();
+
+    // Check if the array is empty.
+    if array.elements.is_empty() {
+      return Null::new();
+    }
+
+    let element = array.elements.remove(0);
+
+    **this = Objects::ARRAY(array);
+
+    element
+  }
+
+  pub fn unshift(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let mut array = this.get_array().unwrap();
+
+    array.elements.insert(0, arguments[0].clone());
+
+    let length = array.elements.len() as f64;
+
+    **this = Objects::ARRAY(array);
+
+    Number::new(length)
+  }
+
+  pub fn slice(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 2) {
+      return error;
+    }
+
+    let elements = this.get_array().unwrap().elements;
+    let start = arguments.first().map_or(0, |start| relative_index(start, elements.len()));
+    let end = arguments.get(1).map_or(elements.len(), |end| relative_index(end, elements.len()));
+
+    // Check if the range is empty.
+    if start >= end {
+      return Array::new(Vec::new());
+    }
+
+    Array::new(elements[start..end].to_vec())
+  }
+
+  pub fn splice(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 3) {
+      return error;
+    }
+
+    let mut array = this.get_array().unwrap();
+    let start = relative_index(&arguments[0], array.elements.len());
+    let count = arguments.get(1).map_or(array.elements.len() - start, |count| {
+      (count.get_number().map_or(0.0, |number| number.get_value()).max(0.0) as usize).min(array.elements.len() - start)
+    });
+    let items = arguments.get(2).and_then(|items| items.get_array()).map_or(Vec::new(), |items| items.elements);
+
+    let removed: Vec<Box<Objects>> = array.elements.splice(start..start + count, items).collect();
+
+    **this = Objects::ARRAY(array);
+
+    Array::new(removed)
+  }
+
+  pub fn concat(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let mut elements = this.get_array().unwrap().elements;
+
+    if let Some(other) = arguments[0].get_array() {
+      elements.extend(other.elements);
+    }
+
+    Array::new(elements)
+  }
+
+  pub fn index_of(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+      return error;
+    }
+
+    let elements = this.get_array().unwrap().elements;
+    let start = arguments.get(1).map_or(0, |start| relative_index(start, elements.len()));
+
+    for (index, element) in elements.iter().enumerate().skip(start) {
+      if element == &arguments[0] {
+        return Number::new(index as f64);
+      }
+    }
+
+    Number::new(-1.0)
+  }
+
+  pub fn includes(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    Boolean::new(this.get_array().unwrap().elements.contains(&arguments[0]))
+  }
+
+  pub fn find(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    for (index, element) in this.get_array().unwrap().elements.into_iter().enumerate() {
+      let object = call_callback(&token, &arguments[0], vec![element.clone(), Number::new(index as f64)], environment);
+
+      // Check if the callback returns an error.
+      if object.get_error().is_some() {
+        return object;
+      }
+
+      if Boolean::is_truthy(object) {
+        return element;
+      }
+    }
+
+    Null::new()
+  }
+
+  pub fn find_index(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    for (index, element) in this.get_array().unwrap().elements.into_iter().enumerate() {
+      let object = call_callback(&token, &arguments[0], vec![element, Number::new(index as f64)], environment);
+
+      if object.get_error().is_some() {
+        return object;
+      }
+
+      if Boolean::is_truthy(object) {
+        return Number::new(index as f64);
+      }
+    }
+
+    Number::new(-1.0)
+  }
+
+  pub fn map(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let mut elements: Vec<Box<Objects>> = Vec::new();
+
+    for (index, element) in this.get_array().unwrap().elements.into_iter().enumerate() {
+      let object = call_callback(&token, &arguments[0], vec![element, Number::new(index as f64)], environment);
+
+      if object.get_error().is_some() {
+        return object;
+      }
+
+      elements.push(object);
+    }
+
+    Array::new(elements)
+  }
+
+  pub fn filter(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let mut elements: Vec<Box<Objects>> = Vec::new();
+
+    for (index, element) in this.get_array().unwrap().elements.into_iter().enumerate() {
+      let object = call_callback(&token, &arguments[0], vec![element.clone(), Number::new(index as f64)], environment);
+
+      if object.get_error().is_some() {
+        return object;
+      }
+
+      if Boolean::is_truthy(object) {
+        elements.push(element);
+      }
+    }
+
+    Array::new(elements)
+  }
+
+  pub fn reduce(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+      return error;
+    }
+
+    let mut accumulator = arguments[1].clone();
+
+    for (index, element) in this.get_array().unwrap().elements.into_iter().enumerate() {
+      accumulator = call_callback(
+        &token,
+        &arguments[0],
+        vec![accumulator, element, Number::new(index as f64)],
+        environment,
+      );
+
+      if accumulator.get_error().is_some() {
+        return accumulator;
+      }
+    }
+
+    accumulator
+  }
+
+  pub fn for_each(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    for (index, element) in this.get_array().unwrap().elements.into_iter().enumerate() {
+      let object = call_callback(&token, &arguments[0], vec![element, Number::new(index as f64)], environment);
+
+      if object.get_error().is_some() {
+        return object;
+      }
+    }
+
+    Null::new()
+  }
+
+  pub fn some(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    environment: &mut Environment,
+  ) -> Box<Objects> {
+    let index = Array::find_index(token, this, arguments, environment);
+
+    // Check if the callback returns an error.
+    if index.get_error().is_some() {
+      return index;
+    }
+
+    Boolean::new(index != Number::new(-1.0))
+  }
+
+  pub fn every(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    for (index, element) in this.get_array().unwrap().elements.into_iter().enumerate() {
+      let object = call_callback(&token, &arguments[0], vec![element, Number::new(index as f64)], environment);
+
+      if object.get_error().is_some() {
+        return object;
+      }
+
+      if !Boolean::is_truthy(object) {
+        return Boolean::new(false);
+      }
+    }
+
+    Boolean::new(true)
+  }
+
+  pub fn join(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+      return error;
+    }
+
+    let separator = arguments.first().map_or(String::from(","), |separator| separator.string());
+    let elements: Vec<String> = this.get_array().unwrap().elements.iter().map(|element| element.string()).collect();
+
+    StringO::new(elements.join(&separator))
+  }
+
+  pub fn reverse(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    let mut array = this.get_array().unwrap();
+
+    array.elements.reverse();
+
+    **this = Objects::ARRAY(array);
+
+    this.clone()
+  }
+
+  /// Sort the array with a stable sort, without a comparator the numbers are sorted by value and
+  /// the rest of elements by their string value.
+  pub fn sort(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+      return error;
+    }
+
+    let mut array = this.get_array().unwrap();
+
+    // The script comparators do not need to be a total order, `sort_by` can not be used with them.
+    let sorted = merge_sort(array.elements, |one, two| {
+      if let Some(compare) = arguments.first() {
+        let object = call_callback(&token, compare, vec![one.clone(), two.clone()], environment);
+
+        if object.get_error().is_some() {
+          return Err(object);
+        }
+
+        return Ok(object.get_number().is_some_and(|number| number.get_value() > 0.0));
+      }
+
+      Ok(match (one.get_number(), two.get_number()) {
+        (Some(one), Some(two)) => one.get_value() > two.get_value(),
+        _ => one.string() > two.string(),
+      })
+    });
+
+    array.elements = match sorted {
+      Ok(elements) => elements,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    **this = Objects::ARRAY(array);
+
+    this.clone()
+  }
+}
+
+#[test]
+fn merge_sort_is_stable() {
+  let pairs = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+  let sorted: Result<Vec<(i32, char)>, ()> = merge_sort(pairs, |one, two| Ok(one.0 > two.0));
+
+  assert_eq!(sorted, Ok(vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]));
+}
+
+#[test]
+fn merge_sort_with_inconsistent_comparisons() {
+  let mut calls = 0;
+  let sorted: Result<Vec<i32>, ()> = merge_sort((0..31).collect(), |_, _| {
+    calls += 1;
+    Ok(calls % 2 == 0)
+  });
+
+  assert_eq!(sorted.map(|elements| elements.len()), Ok(31));
+  assert_eq!(merge_sort(vec![3, 1, 2], |_, _| Err("error")), Err("error"));
+}
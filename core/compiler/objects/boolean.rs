@@ -18,9 +18,7 @@ impl Object for Boolean {
 
 impl Hashable for Boolean {
   fn get_hashkey(&self) -> HashKey {
-    HashKey {
-      value: if self.value { 1.0 } else { 0.0 },
-    }
+    HashKey::BOOLEAN(self.value)
   }
 }
 
@@ -2,14 +2,17 @@ use std::fmt;
 
 use sflyn_parser::tokens::Token;
 
+use crate::Environment;
+
 use super::{
   Object,
   Objects,
 };
 
-type BuiltInFn = fn(
+pub type BuiltInFn = fn(
   token: Token,
   arguments: Vec<Box<Objects>>,
+  environment: &mut Environment,
 ) -> Box<Objects>;
 
 #[derive(Clone)]
@@ -0,0 +1,327 @@
+use crate::{
+  compiler::builtins::{
+    check_arguments,
+    relative_index,
+  },
+  Environment,
+  utils::{
+    crc32,
+    decode_base64,
+    decode_hex,
+    encode_base64,
+    encode_hex,
+    md5,
+    sha1,
+    sha256,
+  },
+};
+
+use sflyn_parser::tokens::Token;
+
+use super::{
+  Array,
+  Error,
+  Number,
+  Object,
+  Objects,
+  StringO,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bytes {
+  data: Vec<u8>,
+}
+
+impl Object for Bytes {
+  fn string(&self) -> String {
+    let bytes: Vec<String> = self.data.iter().map(|byte| format!("{:02x}", byte)).collect();
+
+    if bytes.is_empty() {
+      return String::from("<bytes>");
+    }
+
+    format!("<bytes {}>", bytes.join(" "))
+  }
+}
+
+impl Bytes {
+  pub fn new(data: Vec<u8>) -> Box<Objects> {
+    Box::new(Objects::BYTES(Bytes { data }))
+  }
+
+  pub fn get_data(&self) -> Vec<u8> {
+    self.data.clone()
+  }
+}
+
+/// Get the text argument of a decoder, the rest of values are errors.
+fn get_text(token: &Token, argument: &Objects) -> Result<String, Box<Objects>> {
+  match argument.get_string() {
+    Some(string) => Ok(string.get_value()),
+    None => Err(Error::new(
+      format!("`{}` is not a string.", argument.string()),
+      token.clone(),
+    )),
+  }
+}
+
+// Native functions of the `Bytes` standard library.
+impl Bytes {
+  pub fn from_string(
+    token: Token,
+    _this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    match get_text(&token, &arguments[0]) {
+      Ok(text) => Bytes::new(text.into_bytes()),
+      Err(error) => error,
+    }
+  }
+
+  pub fn from_base64(
+    token: Token,
+    _this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let text = match get_text(&token, &arguments[0]) {
+      Ok(text) => text,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    match decode_base64(&text) {
+      Ok(data) => Bytes::new(data),
+      Err(message) => Error::new(format!("invalid base64 text: {}.", message), token),
+    }
+  }
+
+  pub fn from_hex(
+    token: Token,
+    _this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let text = match get_text(&token, &arguments[0]) {
+      Ok(text) => text,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    match decode_hex(&text) {
+      Ok(data) => Bytes::new(data),
+      Err(message) => Error::new(format!("invalid hexadecimal text: {}.", message), token),
+    }
+  }
+
+  pub fn from_array(
+    token: Token,
+    _this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let elements = match arguments[0].get_array() {
+      Some(array) => array.get_elements(),
+      None => {
+        return Error::new(
+          format!("`{}` is not an array.", arguments[0].string()),
+          token,
+        );
+      },
+    };
+
+    let mut data: Vec<u8> = Vec::new();
+
+    for element in elements.iter() {
+      // Check if the element is an integer between 0 and 255.
+      match element.get_number().map(|number| number.get_value()) {
+        Some(value) if value.fract() == 0.0 && (0.0..=255.0).contains(&value) => data.push(value as u8),
+        _ => {
+          return Error::new(
+            format!("`{}` is not a byte, the values must be integers from 0 to 255.", element.string()),
+            token,
+          );
+        },
+      }
+    }
+
+    Bytes::new(data)
+  }
+
+  pub fn to_string(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    match String::from_utf8(this.get_bytes().unwrap().data) {
+      Ok(text) => StringO::new(text),
+      Err(error) => Error::new(format!("the bytes are not a valid UTF-8 text: {}.", error), token),
+    }
+  }
+
+  pub fn to_base64(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+      return error;
+    }
+
+    let url_safe = arguments.first().and_then(|url_safe| url_safe.get_boolean()).is_some_and(|url_safe| url_safe.get_value());
+
+    StringO::new(encode_base64(&this.get_bytes().unwrap().data, url_safe))
+  }
+
+  pub fn to_hex(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    StringO::new(encode_hex(&this.get_bytes().unwrap().data))
+  }
+
+  pub fn to_array(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    Array::new(this.get_bytes().unwrap().data.iter().map(|byte| Number::new(*byte as f64)).collect())
+  }
+
+  pub fn slice(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 2) {
+      return error;
+    }
+
+    let data = this.get_bytes().unwrap().data;
+    let start = arguments.first().map_or(0, |start| relative_index(start, data.len()));
+    let end = arguments.get(1).map_or(data.len(), |end| relative_index(end, data.len()));
+
+    // Check if the range is empty.
+    if start >= end {
+      return Bytes::new(Vec::new());
+    }
+
+    Bytes::new(data[start..end].to_vec())
+  }
+
+  pub fn concat(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let other = match arguments[0].get_bytes() {
+      Some(other) => other,
+      None => {
+        return Error::new(
+          format!("`{}` is not a bytes value.", arguments[0].string()),
+          token,
+        );
+      },
+    };
+
+    let mut data = this.get_bytes().unwrap().data;
+
+    data.extend(other.data);
+
+    Bytes::new(data)
+  }
+
+  pub fn sha256(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    Bytes::new(sha256(&this.get_bytes().unwrap().data))
+  }
+
+  pub fn sha1(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    Bytes::new(sha1(&this.get_bytes().unwrap().data))
+  }
+
+  pub fn md5(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    Bytes::new(md5(&this.get_bytes().unwrap().data))
+  }
+
+  pub fn crc32(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    Number::new(crc32(&this.get_bytes().unwrap().data) as f64)
+  }
+}
@@ -11,11 +11,22 @@ use super::Objects;
 pub struct Error {
   message: String,
   token: Token,
+  exit_code: Option<i32>,  // Exit code requested with `process->exit()`
 }
 
 impl Error {
   pub fn new(message: String, token: Token) -> Box<Objects> {
-    Box::new(Objects::ERROR(Error { message, token }))
+    Box::new(Objects::ERROR(Error { message, token, exit_code: None }))
+  }
+
+  /// Create an error that stops the program without a message, it is propagated like the rest of
+  /// the errors until the end of the program.
+  pub fn new_exit(exit_code: i32, token: Token) -> Box<Objects> {
+    Box::new(Objects::ERROR(Error { message: String::new(), token, exit_code: Some(exit_code) }))
+  }
+
+  pub fn get_exit_code(&self) -> Option<i32> {
+    self.exit_code
   }
 
   pub fn string(&self, file: File) -> String {
@@ -1,5 +1,4 @@
 use super::{
-  HashItem,
   Object,
   Objects,
 };
@@ -7,18 +6,18 @@ use super::{
 #[derive(Debug, Clone, PartialEq)]
 pub struct ForOf {
   names: Vec<String>,
-  data: Vec<HashItem>,
+  data: Vec<(Box<Objects>, Box<Objects>)>,  // Key + Value
 }
 
 impl Object for ForOf {
   fn string(&self) -> String {
     let mut data: Vec<String> = Vec::new();
 
-    for item in self.data.iter() {
+    for (key, value) in self.data.iter() {
       data.push(format!(
         "{}: {}",
-        item.key,
-        item.value.clone().string(),
+        key.string(),
+        value.string(),
       ));
     }
 
@@ -30,7 +29,7 @@ impl Object for ForOf {
 }
 
 impl ForOf {
-  pub fn new(names: Vec<String>, data: Vec<HashItem>) -> Box<Objects> {
+  pub fn new(names: Vec<String>, data: Vec<(Box<Objects>, Box<Objects>)>) -> Box<Objects> {
     Box::new(Objects::FOROF(Self { names, data }))
   }
 
@@ -38,7 +37,7 @@ impl ForOf {
     self.names.clone()
   }
 
-  pub fn get_data(&self) -> Vec<HashItem> {
+  pub fn get_data(&self) -> Vec<(Box<Objects>, Box<Objects>)> {
     self.data.clone()
   }
 }
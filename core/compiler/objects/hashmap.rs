@@ -1,6 +1,19 @@
+use crate::{
+  compiler::builtins::check_arguments,
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
 use super::{
+  Array,
+  Boolean,
+  Error,
+  Null,
+  Number,
   Object,
   Objects,
+  StringO,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -12,6 +25,7 @@ pub struct HashItem {
 #[derive(Debug, Clone, PartialEq)]
 pub struct HashMap {
   data: Vec<HashItem>,
+  number_keys: Vec<String>,
 }
 
 impl Object for HashMap {
@@ -35,10 +49,182 @@ impl Object for HashMap {
 
 impl HashMap {
   pub fn new(data: Vec<HashItem>) -> Box<Objects> {
-    Box::new(Objects::HASHMAP(HashMap { data }))
+    Box::new(Objects::HASHMAP(HashMap { data, number_keys: Vec::new() }))
   }
 
   pub fn get_data(&self) -> Vec<HashItem> {
     self.data.clone()
   }
+
+  pub fn get_item(&self, key: &str) -> Option<Box<Objects>> {
+    self.data.iter().find(|item| item.key == key).map(|item| item.value.clone())
+  }
+
+  /// Get the key of an item as it was set, the keys set with a number index (`scores[1]`) are
+  /// numbers.
+  pub fn get_key(&self, key: &str) -> Box<Objects> {
+    if self.number_keys.iter().any(|number_key| number_key == key) {
+      if let Ok(value) = key.parse::<f64>() {
+        return Number::new(value);
+      }
+    }
+
+    StringO::new(key.to_string())
+  }
+
+  pub fn set_index_item(&mut self, key: &Objects, value: Box<Objects>) {
+    let name = key.string();
+
+    self.number_keys.retain(|number_key| number_key != &name);
+
+    // Check if the key is a number.
+    if key.get_number().is_some() {
+      self.number_keys.push(name.clone());
+    }
+
+    self.set_item(name, value);
+  }
+
+  pub fn set_item(&mut self, key: String, value: Box<Objects>) {
+    match self.data.iter_mut().find(|item| item.key == key) {
+      Some(item) => {
+        item.value = value;
+      },
+      None => {
+        self.data.push(HashItem { key, value });
+      },
+    }
+  }
+}
+
+// Native methods of the `HashMap` standard library.
+impl HashMap {
+  pub fn keys(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    let data = this.get_hashmap().unwrap().data;
+
+    Array::new(data.into_iter().map(|item| StringO::new(item.key)).collect())
+  }
+
+  pub fn values(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    let data = this.get_hashmap().unwrap().data;
+
+    Array::new(data.into_iter().map(|item| item.value).collect())
+  }
+
+  pub fn entries(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    let data = this.get_hashmap().unwrap().data;
+
+    Array::new(data.into_iter().map(|item| Array::new(vec![StringO::new(item.key), item.value])).collect())
+  }
+
+  pub fn has(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    Boolean::new(this.get_hashmap().unwrap().get_item(&arguments[0].string()).is_some())
+  }
+
+  pub fn delete(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let mut hashmap = this.get_hashmap().unwrap();
+    let key = arguments[0].string();
+    let length = hashmap.data.len();
+
+    hashmap.data.retain(|item| item.key != key);
+    hashmap.number_keys.retain(|number_key| number_key != &key);
+
+    let deleted = hashmap.data.len() != length;
+
+    // Update the receiver without the item.
+    **this = Objects::HASHMAP(hashmap);
+
+    Boolean::new(deleted)
+  }
+
+  pub fn merge(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let mut hashmap = this.get_hashmap().unwrap();
+
+    // Check if the argument is a hashmap.
+    match arguments[0].get_hashmap() {
+      Some(other) => {
+        for item in other.data.iter() {
+          hashmap.set_index_item(&other.get_key(&item.key), item.value.clone());
+        }
+      },
+      None => {
+        return Error::new(
+          format!("`{}` is not a hashmap.", arguments[0].string()),
+          token,
+        );
+      },
+    }
+
+    Box::new(Objects::HASHMAP(hashmap))
+  }
+
+  pub fn get(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+      return error;
+    }
+
+    match this.get_hashmap().unwrap().get_item(&arguments[0].string()) {
+      Some(value) => value,
+      None => arguments.get(1).cloned().unwrap_or_else(Null::new),
+    }
+  }
 }
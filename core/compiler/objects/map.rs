@@ -0,0 +1,344 @@
+use crate::{
+  compiler::builtins::check_arguments,
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
+use super::{
+  Array,
+  Boolean,
+  Error,
+  HashKey,
+  Null,
+  Object,
+  Objects,
+};
+
+use std::collections::HashMap as HashMapSTD;
+
+#[derive(Debug, Clone, Default)]
+pub struct Map {
+  entries: Vec<Option<(Box<Objects>, Box<Objects>)>>,  // Insertion order, the deleted entries are `None`
+  indexes: HashMapSTD<HashKey, usize>,                 // Entry key + Entry position
+}
+
+impl PartialEq for Map {
+  fn eq(&self, other: &Self) -> bool {
+    self.get_entries() == other.get_entries()
+  }
+}
+
+impl Object for Map {
+  fn string(&self) -> String {
+    let entries: Vec<String> = self.get_entries().iter()
+      .map(|(key, value)| format!("{} => {}", key.string(), value.string()))
+      .collect();
+
+    if entries.is_empty() {
+      return String::from("Map {}");
+    }
+
+    format!("Map {{ {} }}", entries.join(", "))
+  }
+}
+
+impl Map {
+  pub fn new() -> Box<Objects> {
+    Box::new(Objects::MAP(Map::default()))
+  }
+
+  pub fn get_entries(&self) -> Vec<(Box<Objects>, Box<Objects>)> {
+    self.entries.iter().flatten().cloned().collect()
+  }
+
+  pub fn get_size(&self) -> usize {
+    self.indexes.len()
+  }
+
+  pub fn get_value(&self, key: &HashKey) -> Option<Box<Objects>> {
+    self.indexes.get(key).and_then(|index| self.entries[*index].clone()).map(|(_, value)| value)
+  }
+
+  pub fn has_key(&self, key: &HashKey) -> bool {
+    self.indexes.contains_key(key)
+  }
+
+  /// Set the value of a key, a new key is added at the end of the insertion order.
+  pub fn insert(&mut self, key: HashKey, key_object: Box<Objects>, value: Box<Objects>) {
+    match self.indexes.get(&key) {
+      Some(index) => {
+        self.entries[*index] = Some((key_object, value));
+      },
+      None => {
+        self.indexes.insert(key, self.entries.len());
+        self.entries.push(Some((key_object, value)));
+      },
+    }
+  }
+
+  pub fn remove(&mut self, key: &HashKey) -> bool {
+    match self.indexes.remove(key) {
+      Some(index) => {
+        self.entries[index] = None;
+
+        // Compact the entries when the most of them are deleted.
+        if self.entries.len() > self.indexes.len() * 2 {
+          let entries = self.get_entries();
+
+          self.entries.clear();
+          self.indexes.clear();
+
+          for (key_object, value) in entries.into_iter() {
+            if let Some(key) = key_object.get_hashkey() {
+              self.insert(key, key_object, value);
+            }
+          }
+        }
+
+        true
+      },
+      None => false,
+    }
+  }
+}
+
+/// Get the structural key of an object used as a key of a map or an item of a set.
+pub fn get_collection_key(token: &Token, object: &Objects) -> Result<HashKey, Box<Objects>> {
+  match object.get_hashkey() {
+    Some(key) => Ok(key),
+    None => Err(Error::new(
+      format!("`{}` can not be used as a key, expect a number, a string, a boolean or a tuple of them.", object.string()),
+      token.clone(),
+    )),
+  }
+}
+
+/// Borrow the map of a native method receiver, the methods update it in place.
+fn get_receiver<'a>(token: &Token, this: &'a mut Box<Objects>) -> Result<&'a mut Map, Box<Objects>> {
+  match &mut **this {
+    Objects::MAP(map) => Ok(map),
+    object => Err(Error::new(format!("`{}` is not a map.", object.string()), token.clone())),
+  }
+}
+
+// Native methods of the `Map` standard library.
+impl Map {
+  pub fn create(
+    token: Token,
+    _this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+      return error;
+    }
+
+    // Check if the constructor does not have the initial entries.
+    if arguments.is_empty() {
+      return Map::new();
+    }
+
+    let entries = match arguments[0].get_array() {
+      Some(array) => array.get_elements(),
+      None => {
+        return Error::new(
+          format!("`{}` is not an array of entries.", arguments[0].string()),
+          token,
+        );
+      },
+    };
+
+    let mut map = Map::default();
+
+    for entry in entries.iter() {
+      // Check if the entry is a `[key, value]` pair.
+      let pair = match entry.get_array() {
+        Some(pair) if pair.get_elements().len() == 2 => pair.get_elements(),
+        _ => {
+          return Error::new(
+            format!("`{}` is not a `[key, value]` entry.", entry.string()),
+            token,
+          );
+        },
+      };
+
+      match get_collection_key(&token, &pair[0]) {
+        Ok(key) => map.insert(key, pair[0].clone(), pair[1].clone()),
+        Err(error) => {
+          return error;
+        },
+      }
+    }
+
+    Box::new(Objects::MAP(map))
+  }
+
+  pub fn get(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let map = match get_receiver(&token, this) {
+      Ok(map) => map,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    match get_collection_key(&token, &arguments[0]) {
+      Ok(key) => map.get_value(&key).unwrap_or_else(Null::new),
+      Err(error) => error,
+    }
+  }
+
+  pub fn set(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+      return error;
+    }
+
+    let map = match get_receiver(&token, this) {
+      Ok(map) => map,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    match get_collection_key(&token, &arguments[0]) {
+      Ok(key) => map.insert(key, arguments[0].clone(), arguments[1].clone()),
+      Err(error) => {
+        return error;
+      },
+    }
+
+    Null::new()
+  }
+
+  pub fn has(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let map = match get_receiver(&token, this) {
+      Ok(map) => map,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    match get_collection_key(&token, &arguments[0]) {
+      Ok(key) => Boolean::new(map.has_key(&key)),
+      Err(error) => error,
+    }
+  }
+
+  pub fn delete(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let map = match get_receiver(&token, this) {
+      Ok(map) => map,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    match get_collection_key(&token, &arguments[0]) {
+      Ok(key) => Boolean::new(map.remove(&key)),
+      Err(error) => error,
+    }
+  }
+
+  pub fn clear(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    match get_receiver(&token, this) {
+      Ok(map) => {
+        *map = Map::default();
+
+        Null::new()
+      },
+      Err(error) => error,
+    }
+  }
+
+  pub fn keys(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    match get_receiver(&token, this) {
+      Ok(map) => Array::new(map.get_entries().into_iter().map(|(key, _)| key).collect()),
+      Err(error) => error,
+    }
+  }
+
+  pub fn values(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    match get_receiver(&token, this) {
+      Ok(map) => Array::new(map.get_entries().into_iter().map(|(_, value)| value).collect()),
+      Err(error) => error,
+    }
+  }
+
+  pub fn entries(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    match get_receiver(&token, this) {
+      Ok(map) => Array::new(
+        map.get_entries().into_iter()
+          .map(|(key, value)| Array::new(vec![key, value]))
+          .collect()
+      ),
+      Err(error) => error,
+    }
+  }
+}
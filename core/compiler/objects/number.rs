@@ -1,8 +1,19 @@
+use crate::{
+  compiler::builtins::check_arguments,
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
 use super::{
+  Boolean,
+  Error,
   Hashable,
   HashKey,
+  Null,
   Object,
   Objects,
+  StringO,
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -12,15 +23,29 @@ pub struct Number {
 
 impl Object for Number {
   fn string(&self) -> String {
+    // Check if the number is not finite.
+    if self.value.is_nan() {
+      return String::from("NaN");
+    } else if self.value.is_infinite() {
+      return String::from(if self.value > 0.0 { "Infinity" } else { "-Infinity" });
+    }
+
     self.value.to_string()
   }
 }
 
 impl Hashable for Number {
   fn get_hashkey(&self) -> HashKey {
-    HashKey {
-      value: self.value,
-    }
+    // The zeros are the same key and all the NaN values are the same key.
+    let value = if self.value == 0.0 {
+      0.0
+    } else if self.value.is_nan() {
+      f64::NAN
+    } else {
+      self.value
+    };
+
+    HashKey::NUMBER(value.to_bits())
   }
 }
 
@@ -33,3 +58,249 @@ impl Number {
     self.value.clone()
   }
 }
+
+/// Get the integer argument of a native method (`digits` or `radix`) between a minimum and a
+/// maximum.
+fn get_digits(token: &Token, object: &Objects, name: &str, min: f64, max: f64) -> Result<usize, Box<Objects>> {
+  let digits = object.get_number().map_or(f64::NAN, |number| number.get_value());
+
+  if digits.fract() != 0.0 || digits < min || digits > max {
+    return Err(Error::new(
+      format!("expect a {} value between `{}` and `{}`, got `{}` instead.", name, min, max, object.string()),
+      token.clone(),
+    ));
+  }
+
+  Ok(digits as usize)
+}
+
+/// Round a number to a fixed number of decimals, the ties are rounded away from zero (`2.5` is
+/// `3`).
+fn round_fixed(value: f64, digits: usize) -> String {
+  // Get the exact decimal expansion, the smallest numbers have 1074 decimals.
+  let exact = format!("{:.1074}", value.abs());
+  let (integer, fraction) = exact.split_once('.').unwrap();
+  let mut kept: Vec<u8> = format!("{}{}", integer, &fraction[..digits]).into_bytes();
+
+  // Check if the rest is the half or more of the last kept digit.
+  if fraction.as_bytes()[digits] >= b'5' {
+    let mut position = kept.len();
+
+    loop {
+      if position == 0 {
+        kept.insert(0, b'1');
+        break;
+      }
+
+      position -= 1;
+
+      if kept[position] == b'9' {
+        kept[position] = b'0';
+      } else {
+        kept[position] += 1;
+        break;
+      }
+    }
+  }
+
+  let mut result = String::from_utf8(kept).unwrap();
+
+  if digits > 0 {
+    result.insert(result.len() - digits, '.');
+  }
+
+  if value < 0.0 {
+    result.insert(0, '-');
+  }
+
+  result
+}
+
+// Native methods of the `Number` standard library.
+impl Number {
+  pub fn to_fixed(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+      return error;
+    }
+
+    let value = this.get_number().unwrap().get_value();
+    let digits = match arguments.first().map_or(Ok(0), |digits| get_digits(&token, digits, "digits", 0.0, 100.0)) {
+      Ok(digits) => digits,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    // Check if the number is not finite.
+    if !value.is_finite() {
+      return StringO::new(this.string());
+    }
+
+    StringO::new(round_fixed(value, digits))
+  }
+
+  pub fn to_precision(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let value = this.get_number().unwrap().get_value();
+    let precision = match get_digits(&token, &arguments[0], "digits", 1.0, 100.0) {
+      Ok(precision) => precision,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    if !value.is_finite() {
+      return StringO::new(this.string());
+    }
+
+    // Get the exponent after the rounding (`9.99` with `2` digits is `1.0e1`).
+    let scientific = format!("{:.*e}", precision - 1, value);
+    let (mantissa, exponent) = scientific.split_at(scientific.find('e').unwrap());
+    let exponent: i32 = exponent[1..].parse().unwrap();
+
+    // Check if the number needs the exponential notation.
+    if exponent < -6 || exponent >= precision as i32 {
+      return StringO::new(format!("{}e{}{}", mantissa, if exponent < 0 { "-" } else { "+" }, exponent.abs()));
+    }
+
+    StringO::new(format!("{:.*}", (precision as i32 - 1 - exponent) as usize, value))
+  }
+
+  pub fn to_string(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+      return error;
+    }
+
+    let value = this.get_number().unwrap().get_value();
+    let radix = match arguments.first().map_or(Ok(10), |radix| get_digits(&token, radix, "radix", 2.0, 36.0)) {
+      Ok(radix) => radix,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    // Check if the number uses the default representation.
+    if radix == 10 || !value.is_finite() {
+      return StringO::new(this.string());
+    }
+
+    let radix_value = radix as f64;
+    let mut integer = value.abs().trunc();
+    let mut fraction = value.abs().fract();
+    let mut digits: Vec<char> = Vec::new();
+
+    // Convert the integer part.
+    loop {
+      digits.push(std::char::from_digit((integer % radix_value) as u32, radix as u32).unwrap());
+      integer = (integer / radix_value).trunc();
+
+      if integer == 0.0 {
+        break;
+      }
+    }
+
+    if value < 0.0 {
+      digits.push('-');
+    }
+
+    let mut result: String = digits.iter().rev().collect();
+
+    // Convert the fraction part, limited to the precision of the number.
+    if fraction > 0.0 {
+      result.push('.');
+
+      for _ in 0..52 {
+        fraction *= radix_value;
+        result.push(std::char::from_digit(fraction.trunc() as u32, radix as u32).unwrap());
+        fraction = fraction.fract();
+
+        if fraction == 0.0 {
+          break;
+        }
+      }
+    }
+
+    StringO::new(result)
+  }
+
+  pub fn is_integer(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    let value = this.get_number().unwrap().get_value();
+
+    Boolean::new(value.is_finite() && value.fract() == 0.0)
+  }
+
+  pub fn is_nan(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    Boolean::new(this.get_number().unwrap().get_value().is_nan())
+  }
+
+  /// Parse a number from a string, `Number->parse('1.5')`, the invalid numbers are null.
+  pub fn parse(
+    token: Token,
+    _this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let value = arguments[0].string();
+    let value = value.trim();
+
+    match value {
+      "Infinity" | "+Infinity" => Number::new(f64::INFINITY),
+      "-Infinity" => Number::new(f64::NEG_INFINITY),
+      _ => match value.parse::<f64>() {
+        // The words accepted by Rust (`inf`, `NaN`) are not valid numbers.
+        Ok(number) if number.is_finite() => Number::new(number),
+        _ => Null::new(),
+      },
+    }
+  }
+}
+
+#[test]
+fn round_fixed_ties_away_from_zero() {
+  assert_eq!(round_fixed(2.5, 0), "3");
+  assert_eq!(round_fixed(-2.5, 0), "-3");
+  assert_eq!(round_fixed(0.125, 2), "0.13");
+  assert_eq!(round_fixed(1.005, 2), "1.00");
+  assert_eq!(round_fixed(99.95, 1), "100.0");
+  assert_eq!(round_fixed(-0.001, 2), "-0.00");
+}
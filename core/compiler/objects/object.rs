@@ -1,8 +1,19 @@
 use super::*;
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct HashKey {
-  pub value: f64,
+use crate::Store;
+
+use sflyn_parser::tokens::{
+  Token,
+  Types,
+};
+
+/// Structural key of the hashable objects, the arrays are keyed by their elements as tuples.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HashKey {
+  BOOLEAN(bool),
+  NUMBER(u64),
+  STRING(String),
+  TUPLE(Vec<HashKey>),
 }
 
 pub trait Hashable {
@@ -20,14 +31,18 @@ pub enum Objects {
   BOOLEAN(Boolean),
   BREAK(Break),
   BUILTIN(BuiltIn),
+  BYTES(Bytes),
   CONTINUE(Continue),
   ERROR(Error),
   FORIN(ForIn),
   FOROF(ForOf),
   HASHMAP(HashMap),
+  MAP(Map),
   NULL(Null),
   NUMBER(Number),
+  REGEXP(RegExp),
   RETURN(ReturnO),
+  SET(Set),
   STRING(StringO),
 }
 
@@ -109,6 +124,13 @@ impl Objects {
     }
   }
 
+  pub fn get_map(&self) -> Option<Map> {
+    match self {
+      Objects::MAP(map) => Some(map.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_null(&self) -> Option<Null> {
     match self {
       Objects::NULL(null) => Some(null.clone()),
@@ -116,6 +138,13 @@ impl Objects {
     }
   }
 
+  pub fn get_bytes(&self) -> Option<Bytes> {
+    match self {
+      Objects::BYTES(bytes) => Some(bytes.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_number(&self) -> Option<Number> {
     match self {
       Objects::NUMBER(number) => Some(number.clone()),
@@ -123,6 +152,13 @@ impl Objects {
     }
   }
 
+  pub fn get_regexp(&self) -> Option<RegExp> {
+    match self {
+      Objects::REGEXP(regexp) => Some(regexp.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_return(&self) -> Option<ReturnO> {
     match self {
       Objects::RETURN(return_o) => Some(return_o.clone()),
@@ -130,6 +166,13 @@ impl Objects {
     }
   }
 
+  pub fn get_set(&self) -> Option<Set> {
+    match self {
+      Objects::SET(set) => Some(set.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_string(&self) -> Option<StringO> {
     match self {
       Objects::STRING(string) => Some(string.clone()),
@@ -137,8 +180,194 @@ impl Objects {
     }
   }
 
+  /// Check if the object is a value of the data type, the interfaces are searched in the store.
+  pub fn is_type(&self, data_type: &Token, store: &Store) -> bool {
+    let data_type: Types = match data_type.token.get_type() {
+      Some(data_type) => data_type,
+      None => match store.get_type(&data_type.value) {
+        Some(interface_type) if interface_type.is_interface() || interface_type.is_alias() => interface_type.get_type(),
+        _ => return false,
+      },
+    };
+
+    match data_type {
+      Types::ANY | Types::UNKNOWN => true,
+      Types::BOOLEAN => self.get_boolean().is_some(),
+      Types::NULL => self.get_null().is_some(),
+      Types::NUMBER => self.get_number().is_some(),
+      Types::STRING => self.get_string().is_some(),
+      Types::REGEXP => self.get_regexp().is_some(),
+      Types::BYTES => self.get_bytes().is_some(),
+      Types::VOID => false,
+      Types::LITERAL(literal) => match (self.get_string(), self.get_number()) {
+        (Some(string), _) => literal.get_type().value == "string" && literal.get_content() == string.get_value(),
+        (_, Some(number)) => literal.get_value().parse::<f64>() == Ok(number.get_value()),
+        _ => false,
+      },
+      Types::UNION(union) => union.get_items().iter().any(|item| self.is_type(item, store)),
+      Types::FUNCTION(_) => self.get_anonymous_function().is_some() || self.get_builtin().is_some(),
+      Types::ARRAY(array) => match self.get_array() {
+        Some(array_object) => array_object.get_elements().iter()
+          .all(|element| element.is_type(&array.get_type(), store)),
+        None => false,
+      },
+      Types::TUPLE(tuple) => match self.get_array() {
+        Some(array_object) => {
+          let elements = array_object.get_elements();
+          let items = tuple.get_items();
+
+          elements.len() == items.len() &&
+            elements.iter().zip(items.iter()).all(|(element, item)| element.is_type(item, store))
+        },
+        None => false,
+      },
+      Types::HASHMAP(hashmap) => match self.get_hashmap() {
+        Some(hashmap_object) => {
+          let items = hashmap.get_items();
+
+          // Check the members.
+          for (key, item) in items.iter() {
+            match hashmap_object.get_item(key) {
+              Some(value) => {
+                // The optional members can be null (`"nick": null` in a JSON text).
+                let is_optional_null = hashmap.is_optional(key) && value.get_null().is_some();
+
+                if !is_optional_null && !value.is_type(item, store) {
+                  return false;
+                }
+              },
+              None => {
+                if !hashmap.is_optional(key) {
+                  return false;
+                }
+              },
+            }
+          }
+
+          // Check the rest of the members with the index signature.
+          match hashmap.get_index() {
+            Some((_, index_type)) => hashmap_object.get_data().iter()
+              .filter(|item| !items.contains_key(&item.key))
+              .all(|item| item.value.is_type(&index_type, store)),
+            None => true,
+          }
+        },
+        None => false,
+      },
+      Types::GENERIC(generic) => {
+        let arguments = generic.get_arguments();
+
+        match (generic.get_name().as_str(), self.get_map(), self.get_set()) {
+          ("Map", Some(map), _) if arguments.len() == 2 => map.get_entries().iter()
+            .all(|(key, value)| key.is_type(&arguments[0], store) && value.is_type(&arguments[1], store)),
+          ("Set", _, Some(set)) if arguments.len() == 1 => set.get_items().iter()
+            .all(|item| item.is_type(&arguments[0], store)),
+          _ => false,
+        }
+      },
+    }
+  }
+
+  /// Get the name of the data type of the object for the error messages.
+  pub fn get_type_name(&self) -> String {
+    let name = match self {
+      Objects::ANONYMOUSFUNCTION(_) | Objects::BUILTIN(_) => "function",
+      Objects::ARRAY(_) => "array",
+      Objects::BOOLEAN(_) => "boolean",
+      Objects::BYTES(_) => "bytes",
+      Objects::HASHMAP(_) => "hashmap",
+      Objects::MAP(_) => "map",
+      Objects::NULL(_) => "null",
+      Objects::NUMBER(_) => "number",
+      Objects::REGEXP(_) => "regexp",
+      Objects::SET(_) => "set",
+      Objects::STRING(_) => "string",
+      _ => "unknown",
+    };
+
+    name.to_string()
+  }
+
+  /// Get the first member that does not satisfy a data type, as in the interface errors of the
+  /// typechecker: `missing `address.city`` or ``tags[1]` is number, expected string`.
+  pub fn get_type_error(&self, data_type: &Token, store: &Store, path: &str) -> Option<String> {
+    if self.is_type(data_type, store) {
+      return None;
+    }
+
+    let resolved_type: Option<Types> = match data_type.token.get_type() {
+      Some(data_type) => Some(data_type),
+      None => store.get_type(&data_type.value)
+        .filter(|interface_type| interface_type.is_interface() || interface_type.is_alias())
+        .map(|interface_type| interface_type.get_type()),
+    };
+
+    match (resolved_type, self) {
+      (Some(Types::HASHMAP(hashmap)), Objects::HASHMAP(hashmap_object)) => {
+        let items = hashmap.get_items();
+        let mut keys: Vec<String> = items.keys().cloned().collect();
+        let member_path = |key: &str| if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) };
+
+        keys.sort();
+
+        for key in keys.iter() {
+          match hashmap_object.get_item(key) {
+            Some(value) if hashmap.is_optional(key) && value.get_null().is_some() => {},
+            Some(value) => {
+              if let Some(error) = value.get_type_error(&items[key], store, &member_path(key)) {
+                return Some(error);
+              }
+            },
+            None if hashmap.is_optional(key) => {},
+            None => {
+              return Some(format!("missing `{}`", member_path(key)));
+            },
+          }
+        }
+
+        if let Some((_, index_type)) = hashmap.get_index() {
+          for item in hashmap_object.get_data().iter().filter(|item| !items.contains_key(&item.key)) {
+            if let Some(error) = item.value.get_type_error(&index_type, store, &member_path(&item.key)) {
+              return Some(error);
+            }
+          }
+        }
+      },
+      (Some(Types::ARRAY(array)), Objects::ARRAY(array_object)) => {
+        for (index, element) in array_object.get_elements().iter().enumerate() {
+          if let Some(error) = element.get_type_error(&array.get_type(), store, &format!("{}[{}]", path, index)) {
+            return Some(error);
+          }
+        }
+      },
+      (Some(Types::TUPLE(tuple)), Objects::ARRAY(array_object)) => {
+        let elements = array_object.get_elements();
+        let items = tuple.get_items();
+
+        if elements.len() == items.len() {
+          for (index, (element, item)) in elements.iter().zip(items.iter()).enumerate() {
+            if let Some(error) = element.get_type_error(item, store, &format!("{}[{}]", path, index)) {
+              return Some(error);
+            }
+          }
+        }
+      },
+      _ => {},
+    }
+
+    let name = if path.is_empty() { String::from("the value") } else { format!("`{}`", path) };
+
+    Some(format!("{} is {}, expected {}", name, self.get_type_name(), data_type.value))
+  }
+
+  /// Get the structural key of the object, only the booleans, the numbers, the strings and the
+  /// arrays of them can be keys.
   pub fn get_hashkey(&self) -> Option<HashKey> {
     match self {
+      Objects::ARRAY(array) => array.get_elements().iter()
+        .map(|element| element.get_hashkey())
+        .collect::<Option<Vec<HashKey>>>()
+        .map(HashKey::TUPLE),
       Objects::BOOLEAN(boolean) => Some(boolean.get_hashkey()),
       Objects::NUMBER(number) => Some(number.get_hashkey()),
       Objects::STRING(string) => Some(string.get_hashkey()),
@@ -153,13 +382,17 @@ impl Objects {
       Objects::BOOLEAN(boolean) => boolean.string(),
       Objects::BREAK(break_o) => break_o.string(),
       Objects::BUILTIN(builtin) => builtin.string(),
+      Objects::BYTES(bytes) => bytes.string(),
       Objects::CONTINUE(continue_o) => continue_o.string(),
       Objects::FORIN(for_in) => for_in.string(),
       Objects::FOROF(for_of) => for_of.string(),
       Objects::HASHMAP(hashmap) => hashmap.string(),
+      Objects::MAP(map) => map.string(),
       Objects::NULL(null) => null.string(),
       Objects::NUMBER(number) => number.string(),
+      Objects::REGEXP(regexp) => regexp.string(),
       Objects::RETURN(return_o) => return_o.string(),
+      Objects::SET(set) => set.string(),
       Objects::STRING(string) => string.string(),
       _ => String::new(),
     }
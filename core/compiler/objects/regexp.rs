@@ -0,0 +1,327 @@
+use crate::{
+  compiler::builtins::check_arguments,
+  Environment,
+  utils::{
+    Captures,
+    Regex,
+  },
+};
+
+use sflyn_parser::tokens::Token;
+
+use super::{
+  Array,
+  Boolean,
+  Error,
+  HashItem,
+  HashMap,
+  Null,
+  Number,
+  Object,
+  Objects,
+  StringO,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegExp {
+  regex: Regex,
+}
+
+impl Object for RegExp {
+  fn string(&self) -> String {
+    format!("/{}/{}", self.regex.get_source(), self.regex.get_flags())
+  }
+}
+
+impl RegExp {
+  pub fn new(regex: Regex) -> Box<Objects> {
+    Box::new(Objects::REGEXP(RegExp { regex }))
+  }
+
+  pub fn get_regex(&self) -> Regex {
+    self.regex.clone()
+  }
+}
+
+/// Get the text of a capture.
+fn get_capture(text: &[char], capture: Option<(usize, usize)>) -> Option<String> {
+  capture.map(|(start, end)| text[start..end].iter().collect())
+}
+
+/// Get all the matches without overlaps, an empty match moves the search to the next character.
+fn find_all(regex: &Regex, text: &[char]) -> Vec<Captures> {
+  let mut matches: Vec<Captures> = Vec::new();
+  let mut start: usize = 0;
+
+  while start <= text.len() {
+    let captures = match regex.find_at(text, start) {
+      Some(captures) => captures,
+      None => break,
+    };
+
+    let (match_start, match_end) = captures[0].unwrap();
+
+    start = if match_end == match_start { match_end + 1 } else { match_end };
+
+    matches.push(captures);
+  }
+
+  matches
+}
+
+/// Get the `{ value, index, groups, named }` hashmap of a match, the groups without a match are
+/// `null`.
+fn match_to_hashmap(regex: &Regex, text: &[char], captures: &Captures) -> Box<Objects> {
+  let capture_object = |capture: Option<(usize, usize)>| match get_capture(text, capture) {
+    Some(value) => StringO::new(value),
+    None => Null::new(),
+  };
+
+  let named: Vec<HashItem> = regex.get_names().into_iter()
+    .map(|(key, index)| HashItem { key, value: capture_object(captures[index]) })
+    .collect();
+
+  HashMap::new(vec![
+    HashItem {
+      key: String::from("value"),
+      value: capture_object(captures[0]),
+    },
+    HashItem {
+      key: String::from("index"),
+      value: Number::new(captures[0].map_or(0, |(start, _)| start) as f64),
+    },
+    HashItem {
+      key: String::from("groups"),
+      value: Array::new(captures[1..].iter().map(|capture| capture_object(*capture)).collect()),
+    },
+    HashItem {
+      key: String::from("named"),
+      value: HashMap::new(named),
+    },
+  ])
+}
+
+/// Expand the `$1`, `$<name>`, `$&` and `$$` references of a replacement.
+fn expand_replacement(regex: &Regex, replacement: &[char], text: &[char], captures: &Captures) -> String {
+  let mut result = String::new();
+  let mut index: usize = 0;
+
+  while index < replacement.len() {
+    let current = replacement[index];
+
+    if current != '$' || index + 1 >= replacement.len() {
+      result.push(current);
+      index += 1;
+      continue;
+    }
+
+    match replacement[index + 1] {
+      '$' => {
+        result.push('$');
+        index += 2;
+      },
+      '&' => {
+        result.push_str(&get_capture(text, captures[0]).unwrap_or_default());
+        index += 2;
+      },
+      '0'..='9' => {
+        // Read two digits when the group exists.
+        let mut end = index + 2;
+
+        if end < replacement.len() && replacement[end].is_ascii_digit() {
+          let group: usize = replacement[index + 1..end + 1].iter().collect::<String>().parse().unwrap();
+
+          if group >= 1 && group <= regex.get_groups() {
+            end += 1;
+          }
+        }
+
+        let group: usize = replacement[index + 1..end].iter().collect::<String>().parse().unwrap();
+
+        // The references to missing groups are written as they are.
+        if group == 0 || group > regex.get_groups() {
+          result.push('$');
+          index += 1;
+          continue;
+        }
+
+        result.push_str(&get_capture(text, captures[group]).unwrap_or_default());
+        index = end;
+      },
+      '<' => {
+        let close = replacement[index + 2..].iter().position(|current| *current == '>');
+        let name: Option<String> = close.map(|close| replacement[index + 2..index + 2 + close].iter().collect());
+
+        match name.and_then(|name| regex.get_names().into_iter().find(|(other, _)| *other == name)) {
+          Some((name, group)) => {
+            result.push_str(&get_capture(text, captures[group]).unwrap_or_default());
+            index += name.chars().count() + 3;
+          },
+          None => {
+            result.push('$');
+            index += 1;
+          },
+        }
+      },
+      _ => {
+        result.push('$');
+        index += 1;
+      },
+    }
+  }
+
+  result
+}
+
+// Native methods of the `RegExp` standard library.
+impl RegExp {
+  pub fn create(
+    token: Token,
+    _this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+      return error;
+    }
+
+    let flags = arguments.get(1).map_or(String::new(), |flags| flags.string());
+
+    match Regex::new(&arguments[0].string(), &flags) {
+      Ok(regex) => RegExp::new(regex),
+      Err(message) => Error::new(message, token),
+    }
+  }
+
+  pub fn test(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let text: Vec<char> = arguments[0].string().chars().collect();
+
+    Boolean::new(this.get_regexp().unwrap().regex.find_at(&text, 0).is_some())
+  }
+
+  pub fn exec(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let regex = this.get_regexp().unwrap().regex;
+    let text: Vec<char> = arguments[0].string().chars().collect();
+
+    match regex.find_at(&text, 0) {
+      Some(captures) => match_to_hashmap(&regex, &text, &captures),
+      None => Null::new(),
+    }
+  }
+
+  pub fn match_all(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let regex = this.get_regexp().unwrap().regex;
+    let text: Vec<char> = arguments[0].string().chars().collect();
+
+    Array::new(
+      find_all(&regex, &text).iter()
+        .map(|captures| match_to_hashmap(&regex, &text, captures))
+        .collect()
+    )
+  }
+
+  pub fn replace(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+      return error;
+    }
+
+    let regex = this.get_regexp().unwrap().regex;
+    let text: Vec<char> = arguments[0].string().chars().collect();
+    let replacement: Vec<char> = arguments[1].string().chars().collect();
+
+    // Only the first match is replaced without the `g` flag.
+    let mut matches = find_all(&regex, &text);
+
+    if !regex.is_global() {
+      matches.truncate(1);
+    }
+
+    let mut result = String::new();
+    let mut last: usize = 0;
+
+    for captures in matches.iter() {
+      let (start, end) = captures[0].unwrap();
+
+      result.extend(text[last..start].iter());
+      result.push_str(&expand_replacement(&regex, &replacement, &text, captures));
+
+      last = end;
+    }
+
+    result.extend(text[last..].iter());
+
+    StringO::new(result)
+  }
+
+  pub fn split(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let regex = this.get_regexp().unwrap().regex;
+    let text: Vec<char> = arguments[0].string().chars().collect();
+
+    // An empty text does not have parts.
+    if text.is_empty() {
+      return Array::new(Vec::new());
+    }
+
+    let mut parts: Vec<Box<Objects>> = Vec::new();
+    let mut last: usize = 0;
+
+    for captures in find_all(&regex, &text).iter() {
+      let (start, end) = captures[0].unwrap();
+
+      // The empty matches at the end of the previous match or at the end of the text do not split
+      // it, as in the JavaScript split algorithm.
+      if end == last || start >= text.len() {
+        continue;
+      }
+
+      parts.push(StringO::new(text[last..start].iter().collect()));
+
+      last = end;
+    }
+
+    parts.push(StringO::new(text[last..].iter().collect()));
+
+    Array::new(parts)
+  }
+}
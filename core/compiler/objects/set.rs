@@ -0,0 +1,212 @@
+use crate::{
+  compiler::builtins::check_arguments,
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
+use super::{
+  Array,
+  Boolean,
+  Error,
+  get_collection_key,
+  Map,
+  Null,
+  Object,
+  Objects,
+};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Set {
+  data: Map,  // The items are the keys of the map
+}
+
+impl Object for Set {
+  fn string(&self) -> String {
+    let items: Vec<String> = self.get_items().iter().map(|item| item.string()).collect();
+
+    if items.is_empty() {
+      return String::from("Set {}");
+    }
+
+    format!("Set {{ {} }}", items.join(", "))
+  }
+}
+
+impl Set {
+  pub fn new() -> Box<Objects> {
+    Box::new(Objects::SET(Set::default()))
+  }
+
+  pub fn get_items(&self) -> Vec<Box<Objects>> {
+    self.data.get_entries().into_iter().map(|(item, _)| item).collect()
+  }
+
+  pub fn get_size(&self) -> usize {
+    self.data.get_size()
+  }
+}
+
+/// Borrow the set of a native method receiver, the methods update it in place.
+fn get_receiver<'a>(token: &Token, this: &'a mut Box<Objects>) -> Result<&'a mut Set, Box<Objects>> {
+  match &mut **this {
+    Objects::SET(set) => Ok(set),
+    object => Err(Error::new(format!("`{}` is not a set.", object.string()), token.clone())),
+  }
+}
+
+// Native methods of the `Set` standard library.
+impl Set {
+  pub fn create(
+    token: Token,
+    _this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 1) {
+      return error;
+    }
+
+    // Check if the constructor does not have the initial items.
+    if arguments.is_empty() {
+      return Set::new();
+    }
+
+    let items = match arguments[0].get_array() {
+      Some(array) => array.get_elements(),
+      None => {
+        return Error::new(
+          format!("`{}` is not an array.", arguments[0].string()),
+          token,
+        );
+      },
+    };
+
+    let mut set = Set::default();
+
+    for item in items.into_iter() {
+      match get_collection_key(&token, &item) {
+        Ok(key) => set.data.insert(key, item, Null::new()),
+        Err(error) => {
+          return error;
+        },
+      }
+    }
+
+    Box::new(Objects::SET(set))
+  }
+
+  pub fn add(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let set = match get_receiver(&token, this) {
+      Ok(set) => set,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    match get_collection_key(&token, &arguments[0]) {
+      // The items already in the set are kept.
+      Ok(key) => {
+        if !set.data.has_key(&key) {
+          set.data.insert(key, arguments[0].clone(), Null::new());
+        }
+      },
+      Err(error) => {
+        return error;
+      },
+    }
+
+    Null::new()
+  }
+
+  pub fn has(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let set = match get_receiver(&token, this) {
+      Ok(set) => set,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    match get_collection_key(&token, &arguments[0]) {
+      Ok(key) => Boolean::new(set.data.has_key(&key)),
+      Err(error) => error,
+    }
+  }
+
+  pub fn delete(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let set = match get_receiver(&token, this) {
+      Ok(set) => set,
+      Err(error) => {
+        return error;
+      },
+    };
+
+    match get_collection_key(&token, &arguments[0]) {
+      Ok(key) => Boolean::new(set.data.remove(&key)),
+      Err(error) => error,
+    }
+  }
+
+  pub fn clear(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    match get_receiver(&token, this) {
+      Ok(set) => {
+        *set = Set::default();
+
+        Null::new()
+      },
+      Err(error) => error,
+    }
+  }
+
+  pub fn values(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    match get_receiver(&token, this) {
+      Ok(set) => Array::new(set.get_items()),
+      Err(error) => error,
+    }
+  }
+}
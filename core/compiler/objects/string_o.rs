@@ -1,10 +1,37 @@
+use crate::{
+  compiler::builtins::{
+    check_arguments,
+    relative_index,
+  },
+  Environment,
+};
+
+use sflyn_parser::tokens::Token;
+
 use super::{
+  Array,
+  Boolean,
+  Error,
   Hashable,
   HashKey,
+  Null,
+  Number,
   Object,
   Objects,
 };
 
+/// Maximum number of characters of the strings created by the native methods (`repeat`, `padStart`
+/// and `padEnd`), the same limit of the JavaScript engines.
+pub const MAX_STRING_LENGTH: usize = (1 << 29) - 24;
+
+/// Get the error of a result longer than the maximum string length.
+fn length_error(token: Token) -> Box<Objects> {
+  Error::new(
+    format!("the result of `{}` exceeds the maximum string length ({}).", token.value, MAX_STRING_LENGTH),
+    token,
+  )
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct StringO {
   value: String,
@@ -18,15 +45,7 @@ impl Object for StringO {
 
 impl Hashable for StringO {
   fn get_hashkey(&self) -> HashKey {
-    let mut value: f64 = 0.0;
-
-    for byte in self.value.as_bytes() {
-      value += byte.to_string().parse::<f64>().unwrap();
-    }
-
-    HashKey {
-      value,
-    }
+    HashKey::STRING(self.value.clone())
   }
 }
 
@@ -39,3 +58,359 @@ impl StringO {
     self.value.clone()
   }
 }
+
+// Native methods of the `String` standard library.
+impl StringO {
+  /// Get the character of a position, the positions are Unicode scalar values.
+  fn get_character(value: String, position: &Objects) -> Option<char> {
+    let position = position.get_number().map_or(0.0, |number| number.get_value());
+
+    if position < 0.0 || position.fract() != 0.0 {
+      return None;
+    }
+
+    value.chars().nth(position as usize)
+  }
+
+  /// Pad a string to a length with a fill string, the default fill is a space.
+  fn pad(token: Token, value: String, arguments: &[Box<Objects>], start: bool) -> Box<Objects> {
+    let length = arguments[0].get_number().map_or(0.0, |number| number.get_value()).max(0.0);
+    let fill: Vec<char> = arguments.get(1).map_or(String::from(" "), |fill| fill.string()).chars().collect();
+    let count = value.chars().count();
+
+    // Check if the string does not need a padding.
+    if count as f64 >= length || fill.is_empty() {
+      return StringO::new(value);
+    } else if length > MAX_STRING_LENGTH as f64 {
+      return length_error(token);
+    }
+
+    let padding: String = fill.iter().cycle().take(length as usize - count).collect();
+
+    if start {
+      return StringO::new(padding + &value);
+    }
+
+    StringO::new(value + &padding)
+  }
+
+  pub fn trim(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    StringO::new(this.string().trim().to_string())
+  }
+
+  pub fn trim_start(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    StringO::new(this.string().trim_start().to_string())
+  }
+
+  pub fn trim_end(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    StringO::new(this.string().trim_end().to_string())
+  }
+
+  pub fn to_upper_case(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    StringO::new(this.string().to_uppercase())
+  }
+
+  pub fn to_lower_case(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    StringO::new(this.string().to_lowercase())
+  }
+
+  pub fn starts_with(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    Boolean::new(this.string().starts_with(&arguments[0].string()))
+  }
+
+  pub fn ends_with(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    Boolean::new(this.string().ends_with(&arguments[0].string()))
+  }
+
+  pub fn includes(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    Boolean::new(this.string().contains(&arguments[0].string()))
+  }
+
+  pub fn index_of(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+      return error;
+    }
+
+    let characters: Vec<char> = this.string().chars().collect();
+    let search: Vec<char> = arguments[0].string().chars().collect();
+    let start = arguments.get(1).map_or(0, |start| relative_index(start, characters.len()));
+
+    // Search the characters from the start position.
+    for index in start..=characters.len() {
+      if characters[index..].starts_with(&search) {
+        return Number::new(index as f64);
+      }
+    }
+
+    Number::new(-1.0)
+  }
+
+  pub fn replace(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+      return error;
+    }
+
+    StringO::new(this.string().replacen(&arguments[0].string(), &arguments[1].string(), 1))
+  }
+
+  pub fn replace_all(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 2, 2) {
+      return error;
+    }
+
+    StringO::new(this.string().replace(&arguments[0].string(), &arguments[1].string()))
+  }
+
+  pub fn repeat(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let count = arguments[0].get_number().map_or(0.0, |number| number.get_value());
+
+    // Check if the count is a non-negative integer, the fraction of `NaN` and the infinite counts is
+    // `NaN`.
+    if count < 0.0 || count.fract() != 0.0 {
+      return Error::new(
+        format!("`{}` is not a valid repeat count.", arguments[0].string()),
+        token,
+      );
+    }
+
+    let value = this.string();
+
+    // Check if the result is too long, an empty string can be repeated any times.
+    if !value.is_empty() && count * value.chars().count() as f64 > MAX_STRING_LENGTH as f64 {
+      return length_error(token);
+    }
+
+    StringO::new(value.repeat(count as usize))
+  }
+
+  pub fn pad_start(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+      return error;
+    }
+
+    StringO::pad(token, this.string(), &arguments, true)
+  }
+
+  pub fn pad_end(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+      return error;
+    }
+
+    StringO::pad(token, this.string(), &arguments, false)
+  }
+
+  pub fn slice(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 2) {
+      return error;
+    }
+
+    let characters: Vec<char> = this.string().chars().collect();
+    let start = arguments.first().map_or(0, |start| relative_index(start, characters.len()));
+    let end = arguments.get(1).map_or(characters.len(), |end| relative_index(end, characters.len()));
+
+    // Check if the range is empty.
+    if start >= end {
+      return StringO::new(String::new());
+    }
+
+    StringO::new(characters[start..end].iter().collect())
+  }
+
+  pub fn substring(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 2) {
+      return error;
+    }
+
+    let characters: Vec<char> = this.string().chars().collect();
+    let position = |object: &Objects| {
+      object.get_number().map_or(0.0, |number| number.get_value()).max(0.0).min(characters.len() as f64) as usize
+    };
+    let start = position(&arguments[0]);
+    let end = arguments.get(1).map_or(characters.len(), |end| position(end));
+
+    // The positions are swapped when the start is greater than the end.
+    StringO::new(characters[start.min(end)..start.max(end)].iter().collect())
+  }
+
+  pub fn char_at(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    match StringO::get_character(this.string(), &arguments[0]) {
+      Some(character) => StringO::new(character.to_string()),
+      None => StringO::new(String::new()),
+    }
+  }
+
+  pub fn code_point_at(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    match StringO::get_character(this.string(), &arguments[0]) {
+      Some(character) => Number::new(character as u32 as f64),
+      None => Null::new(),
+    }
+  }
+
+  pub fn chars(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 0, 0) {
+      return error;
+    }
+
+    Array::new(this.string().chars().map(|character| StringO::new(character.to_string())).collect())
+  }
+
+  pub fn split(
+    token: Token,
+    this: &mut Box<Objects>,
+    arguments: Vec<Box<Objects>>,
+    _environment: &mut Environment,
+  ) -> Box<Objects> {
+    if let Some(error) = check_arguments(&token, &arguments, 1, 1) {
+      return error;
+    }
+
+    let value = this.string();
+    let separator = arguments[0].string();
+
+    // Check if the separator is empty to split the string in characters.
+    if separator.is_empty() {
+      return Array::new(value.chars().map(|character| StringO::new(character.to_string())).collect());
+    }
+
+    Array::new(value.split(separator.as_str()).map(|item| StringO::new(item.to_string())).collect())
+  }
+}
@@ -5,15 +5,15 @@ use crate::{
     AnonymousFunction,
     Break,
     Boolean,
+    BuiltIn,
+    builtins::get_module_builtin,
     Continue,
     evaluate_expression,
     Null,
     Objects,
     ReturnO,
-    StringO,
   },
   Environment,
-  Store,
 };
 
 use sflyn_parser::{
@@ -22,6 +22,8 @@ use sflyn_parser::{
   tokens::Keywords,
 };
 
+use std::path::Path;
+
 pub fn evaluate_statement(
   statement: &Box<Statements>,
   environment: &mut Environment,
@@ -30,6 +32,9 @@ pub fn evaluate_statement(
   if let Some(block) = statement.get_block() {
     let mut result_object: Option<Box<Objects>> = None;
 
+    // Create the block scope.
+    environment.store.push_scope();
+
     for statement in block.get_statements().iter() {
       result_object = evaluate_statement(statement, environment);
 
@@ -44,6 +49,8 @@ pub fn evaluate_statement(
       }
     }
 
+    environment.store.pop_scope();
+
     return result_object;
   }
 
@@ -68,11 +75,12 @@ pub fn evaluate_statement(
 
   // For
   if let Some(for_s) = statement.get_for() {
-    let mut for_environment: Environment = environment.clone();
+    // The condition names only exist inside of the for scope.
+    environment.store.push_scope();
 
-    for_environment.store = Store::from_store(&for_environment.store);
+    let condition_obj = evaluate_expression(&for_s.get_condition(), environment);
 
-    let condition_obj = evaluate_expression(&for_s.get_condition(), &mut for_environment);
+    environment.store.pop_scope();
 
     if condition_obj.get_error().is_some() {
       return Some(condition_obj);
@@ -81,67 +89,54 @@ pub fn evaluate_statement(
     if let Some(for_in) = condition_obj.get_for_in() {
       if for_in.get_elements().len() > 0 {
         for obj in for_in.get_elements().iter() {
-          let mut new_environment: Environment = environment.clone();
+          // Create the iteration scope.
+          environment.store.push_scope();
+          environment.store.set_object(for_in.get_name(), obj.clone());
 
-          new_environment.store = Store::from_store(&new_environment.store);
+          let result_object = evaluate_statement(&for_s.get_body(), environment);
 
-          new_environment.store.set_object(for_in.get_name(), obj.clone());
+          environment.store.pop_scope();
 
-          if let Some(obj) = evaluate_statement(&for_s.get_body(), &mut new_environment) {
-            if obj.get_error().is_some() {
+          if let Some(obj) = result_object {
+            if obj.get_error().is_some() || obj.get_return().is_some() {
               return Some(obj);
             }
 
-            if obj.get_continue().is_some() {
-              continue;
-            }
-
             if obj.get_break().is_some() {
               break;
             }
           }
-
-          new_environment.store.delete_object(&for_in.get_name());
-
-          if let Some(outer) = new_environment.store.get_outer() {
-            environment.store = outer;
-          }
         }
       }
     } else if let Some(for_of) = condition_obj.get_for_of() {
       if for_of.get_names().len() == 2 {
-        for item in for_of.get_data().iter() {
-          let mut new_environment: Environment = environment.clone();
-
-          new_environment.store = Store::from_store(&environment.store);
+        for (key, value) in for_of.get_data().iter() {
+          // Create the iteration scope.
+          environment.store.push_scope();
 
-          new_environment.store.set_object(
+          environment.store.set_object(
             for_of.get_names()[0].clone(),
-            StringO::new(item.key.clone()),
+            key.clone(),
           );
 
-          new_environment.store.set_object(
+          environment.store.set_object(
             for_of.get_names()[1].clone(),
-            item.value.clone(),
+            value.clone(),
           );
 
-          if let Some(obj) = evaluate_statement(&for_s.get_body(), &mut new_environment) {
-            if obj.get_error().is_some() {
-              return Some(obj);
-            }
+          let result_object = evaluate_statement(&for_s.get_body(), environment);
 
-            if obj.get_continue().is_some() {
-              continue;
+          environment.store.pop_scope();
+
+          if let Some(obj) = result_object {
+            if obj.get_error().is_some() || obj.get_return().is_some() {
+              return Some(obj);
             }
 
             if obj.get_break().is_some() {
               break;
             }
           }
-
-          if let Some(outer) = new_environment.store.get_outer() {
-            environment.store = outer.clone();
-          }
         }
       }
     }
@@ -216,6 +211,44 @@ pub fn evaluate_statement(
         return Some(object);
       }
 
+      // Check if is a destructuring.
+      if variable.is_destructuring() {
+        let mut object = object;
+
+        // Check if the value object is a return.
+        if let Some(return_o) = object.get_return() {
+          object = return_o.get_value();
+        }
+
+        let elements = match object.get_array() {
+          Some(array) => array.get_elements(),
+          None => Vec::new(),
+        };
+
+        for (index, name) in variable.get_names().iter().enumerate() {
+          let element = match elements.get(index) {
+            Some(element) => element.clone(),
+            None => Null::new(),
+          };
+
+          environment.store.set_object(name.value.clone(), element);
+        }
+
+        return None;
+      }
+
+      // Check if the value is a native function of a standard module.
+      if object.get_builtin().is_some() && value.get_anonymous_function().is_some() {
+        let module = environment.current_file.clone()
+          .and_then(|file| Path::new(&file.name).file_stem().map(|stem| stem.to_string_lossy().to_string()))
+          .unwrap_or_default();
+
+        if let Some(fun) = get_module_builtin(&module, &variable.get_name().value) {
+          environment.store.set_object(variable.get_name().value, BuiltIn::new_box(None, Some(fun)));
+          return None;
+        }
+      }
+
       environment.store.set_object(variable.get_name().value, object);
     }
   }
@@ -8,6 +8,7 @@ use crate::{
   },
   Environment,
   program::run_file,
+  utils::get_import_path,
   Store,
 };
 
@@ -69,10 +70,10 @@ pub fn evaluate(
   }
 
   // Get the parent directory from the current file.
-  let parent_path = current_path.parent().unwrap().display();
+  let parent_path = current_path.parent().unwrap().display().to_string();
 
   // Get the new path for the import.
-  let new_path = format!("{}/{}.sf", parent_path, path_to);
+  let new_path = get_import_path(&parent_path, &path_to);
   
   // Clone the current environment.
   let mut import_environment = environment.clone();
@@ -83,6 +84,15 @@ pub fn evaluate(
   // Parse and compile the file imported.
   run_file(new_path.clone(), &mut import_environment, false, true, false);
 
+  // Check if the file imported requested an exit.
+  if let Some(exit_code) = import_environment.exit_code {
+    return Some(Error::new_exit(exit_code, import.get_token()));
+  }
+
+  // The runtime errors of the file imported were shown, the program continues but exits with a
+  // failure code.
+  environment.has_errors |= import_environment.has_errors;
+
   // Get the file imported from the environment.
   let new_file = import_environment.get_file(new_path);
 
@@ -94,10 +104,22 @@ pub fn evaluate(
     ));
   }
 
-  let file_exports = new_file.unwrap().exports;
+  let new_file = new_file.unwrap();
+  let file_exports = new_file.exports;
   let mut exports_items: Vec<HashItem> = Vec::new();
 
+  // Get the exported interfaces, they are only data types and do not have objects.
+  let interfaces: Vec<String> = new_file.statements.iter()
+    .filter_map(|statement| statement.get_export())
+    .filter_map(|export| export.get_value().get_interface())
+    .map(|interface| interface.get_name().value)
+    .collect();
+
   for export in file_exports.iter() {
+    if interfaces.contains(export) {
+      continue;
+    }
+
     if let Some(env_obj) = import_environment.store.get_object(export) {
       exports_items.push(HashItem {
         key: export.clone(),
@@ -122,6 +144,10 @@ pub fn evaluate(
     for module in import.get_modules() {
       // Check if the module is an identifier.
       if let Some(identifier) = module.get_identifier() {
+        if interfaces.contains(&identifier.get_value()) {
+          continue;
+        }
+
         if let Some(env_obj) = import_environment.store.get_object(&identifier.get_value()) {
           environment.store.set_object(identifier.get_value(), env_obj);
           continue;
@@ -138,6 +164,10 @@ pub fn evaluate(
         if infix.is_alias() {
           // Get the left identifier.
           if let Some(left_identifier) = infix.get_left().get_identifier() {
+            if interfaces.contains(&left_identifier.get_value()) {
+              continue;
+            }
+
             // Get the object from the environment.
             if let Some(env_obj) = import_environment.store.get_object(&left_identifier.get_value()) {
               if let Some(right_identifier) = infix.get_right().unwrap().get_identifier() {
@@ -8,11 +8,19 @@ pub use store::Store;
 use sflyn_parser::{
   run as run_parser,
   File,
+  tokens::Token,
 };
 
-use std::collections::BTreeMap;
+use std::{
+  cell::RefCell,
+  collections::BTreeMap,
+  rc::Rc,
+};
 
-use super::error::show_error;
+use super::{
+  error::show_error,
+  typechecker::TTypes,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Environment {
@@ -25,6 +33,13 @@ pub struct Environment {
   pub stdlibs: BTreeMap<String, File>,       // Lib name + Lib file
 
   pub store: Box<Store>,
+
+  pub return_type: Option<Token>,            // Data type of the current function
+  pub returns: Rc<RefCell<Vec<TTypes>>>,     // Return types of the current function
+  pub inferred: Vec<String>,                 // Names of the functions with an inferred return type
+
+  pub exit_code: Option<i32>,                // Exit code requested with `process->exit()`
+  pub has_errors: bool,                      // The program showed a runtime error
 }
 
 impl Environment {
@@ -39,6 +54,13 @@ impl Environment {
       stdlibs: BTreeMap::new(),
 
       store: Store::new_box(),
+
+      return_type: None,
+      returns: Rc::new(RefCell::new(Vec::new())),
+      inferred: Vec::new(),
+
+      exit_code: None,
+      has_errors: false,
     }
   }
 
@@ -113,6 +135,46 @@ impl Environment {
       return array_lib;
     }
 
+    let hashmap_lib = self.load_stdlib(format!("{}std/HashMap.sf", sflyn_path), "HashMap");
+    if hashmap_lib != 0 {
+      return hashmap_lib;
+    }
+
+    let map_lib = self.load_stdlib(format!("{}std/Map.sf", sflyn_path), "Map");
+    if map_lib != 0 {
+      return map_lib;
+    }
+
+    let set_lib = self.load_stdlib(format!("{}std/Set.sf", sflyn_path), "Set");
+    if set_lib != 0 {
+      return set_lib;
+    }
+
+    let regexp_lib = self.load_stdlib(format!("{}std/RegExp.sf", sflyn_path), "RegExp");
+    if regexp_lib != 0 {
+      return regexp_lib;
+    }
+
+    let bytes_lib = self.load_stdlib(format!("{}std/Bytes.sf", sflyn_path), "Bytes");
+    if bytes_lib != 0 {
+      return bytes_lib;
+    }
+
+    let process_lib = self.load_stdlib(format!("{}std/process.sf", sflyn_path), "process");
+    if process_lib != 0 {
+      return process_lib;
+    }
+
+    let json_lib = self.load_stdlib(format!("{}std/JSON.sf", sflyn_path), "JSON");
+    if json_lib != 0 {
+      return json_lib;
+    }
+
+    let math_lib = self.load_stdlib(format!("{}std/Math.sf", sflyn_path), "Math");
+    if math_lib != 0 {
+      return math_lib;
+    }
+
     self.load_stdlib(format!("{}std/range.sf", sflyn_path), "range")
   }
 }
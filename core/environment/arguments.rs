@@ -1,35 +1,123 @@
+use crate::utils::resolve_path;
+
+/// Access of the script to the files, all the paths or only the paths inside of the allowed paths.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Permission {
+  Denied,
+  All,
+  Paths(Vec<String>),    // Absolute paths
+}
+
+impl Permission {
+  /// Get the permission of a `--allow-read[=paths]` flag, the paths are separated by commas.
+  fn from_flag(paths: Option<&str>) -> Permission {
+    match paths {
+      Some(paths) => Permission::Paths(
+        paths.split(',')
+          .filter(|path| !path.is_empty())
+          .map(|path| resolve_path(&[path]))
+          .collect()
+      ),
+      None => Permission::All,
+    }
+  }
+
+  /// Join the permissions of a repeated flag, the access to all the paths wins.
+  fn merge(self, other: Permission) -> Permission {
+    match (self, other) {
+      (Permission::Paths(mut paths), Permission::Paths(other)) => {
+        paths.extend(other);
+        Permission::Paths(paths)
+      },
+      (Permission::Denied, other) => other,
+      _ => Permission::All,
+    }
+  }
+
+  /// Check if the path is one of the allowed paths or is inside of them.
+  pub fn allows(&self, path: &str) -> bool {
+    match self {
+      Permission::Denied => false,
+      Permission::All => true,
+      Permission::Paths(paths) => {
+        let path = resolve_path(&[path]);
+
+        paths.iter().any(|allowed| {
+          path == *allowed || allowed == "/" || path.starts_with(&format!("{}/", allowed))
+        })
+      },
+    }
+  }
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Arguments {
   pub file: String,
+  pub args: Vec<String>,    // Arguments after the file, used by the script as `process->args`
 
   pub flag_version: bool,
+  pub flag_allow_run: bool,   // Allow the external commands (`exec` and `spawn`)
+  pub flag_allow_env: bool,   // Allow the environment variables (`process->env`)
+  pub allow_read: Permission,
+  pub allow_write: Permission,
+  pub invalid_flag: Option<String>,
 }
 
 impl Arguments {
   pub fn new() -> Arguments {
     Arguments {
       file: String::new(),
+      args: Vec::new(),
 
       flag_version: false,
+      flag_allow_run: false,
+      flag_allow_env: false,
+      allow_read: Permission::Denied,
+      allow_write: Permission::Denied,
+      invalid_flag: None,
     }
   }
 
+  /// Get the arguments of the console, the interpreter flags are before the file:
+  /// `sflyn --allow-read=./data --allow-run script.sf arg1 arg2`.
   pub fn from_console() -> Arguments {
     let arguments: Vec<String> = std::env::args().collect();
 
-    if arguments.len() == 1 {
-      Arguments::new()
-    } else {
-      Arguments {
-        file: if std::path::Path::new(&arguments[1].clone()).exists() {
-          arguments[1].clone()
-        } else {
-          String::new()
-        },
+    Arguments::from_list(&arguments[1..])
+  }
+
+  pub fn from_list(arguments: &[String]) -> Arguments {
+    let mut result = Arguments::new();
+    let mut index: usize = 0;
+
+    // Get the interpreter flags.
+    while index < arguments.len() && arguments[index].starts_with("--") {
+      let (flag, value) = match arguments[index].split_once('=') {
+        Some((flag, value)) => (flag, Some(value)),
+        None => (arguments[index].as_str(), None),
+      };
 
-        flag_version: arguments.contains(&String::from("--version")),
+      match (flag, value) {
+        ("--version", None) => result.flag_version = true,
+        ("--allow-run", None) => result.flag_allow_run = true,
+        ("--allow-env", None) => result.flag_allow_env = true,
+        ("--allow-read", paths) => result.allow_read = result.allow_read.merge(Permission::from_flag(paths)),
+        ("--allow-write", paths) => result.allow_write = result.allow_write.merge(Permission::from_flag(paths)),
+        _ => {
+          if result.invalid_flag.is_none() {
+            result.invalid_flag = Some(arguments[index].clone());
+          }
+        },
       }
+
+      index += 1;
     }
+
+    if index < arguments.len() {
+      result.file = arguments[index].clone();
+      result.args = arguments[index + 1..].to_vec();
+    }
+
+    result
   }
 }
@@ -43,8 +43,21 @@ impl Store {
     self.consts.clone()
   }
 
+  /// Check if the name is a const in the scope where it is declared.
   pub fn has_const(&mut self, name: &String) -> bool {
-    self.consts.contains(name)
+    if self.consts.contains(name) {
+      return true;
+    }
+
+    // Check if the name is shadowed in this scope.
+    if self.has_type(name) || self.has_object(name) {
+      return false;
+    }
+
+    match self.outer.as_mut() {
+      Some(outer) => outer.has_const(name),
+      None => false,
+    }
   }
 
   pub fn set_const(&mut self, name: String) {
@@ -105,7 +118,25 @@ impl Store {
     self.types.insert(key, value);
   }
 
+  pub fn has_type(&self, key: &String) -> bool {
+    self.types.contains_key(key)
+  }
+
   pub fn get_outer(&self) -> Option<Box<Store>> {
     self.outer.clone()
   }
+
+  /// Open a new block scope, the names declared inside of it can shadow the outer names.
+  pub fn push_scope(&mut self) {
+    let outer: Store = std::mem::replace(self, Store::new());
+
+    self.outer = Some(Box::new(outer));
+  }
+
+  /// Close the current block scope keeping the changes made to the outer scopes.
+  pub fn pop_scope(&mut self) {
+    if let Some(outer) = self.outer.take() {
+      *self = *outer;
+    }
+  }
 }
@@ -32,6 +32,9 @@ pub fn run_file(
       }
 
       environment.files.push(file.clone());
+
+      // The programs with runtime errors exit with a failure code.
+      environment.exit_code.unwrap_or(if environment.has_errors { 1 } else { 0 })
     },
 
     Err((error, file)) => {
@@ -41,11 +44,9 @@ pub fn run_file(
         println!("{}", error.message);
       }
 
-      return 1;
+      1
     },
   }
-
-  0
 }
 
 pub fn start() -> i32 {
@@ -58,6 +59,11 @@ pub fn start() -> i32 {
     return 1;
   }
 
+  if let Some(flag) = environment.arguments.invalid_flag.clone() {
+    println!("`{}` is not a valid flag.", flag);
+    return 1;
+  }
+
   if environment.arguments.flag_version {
     println!("Sflyn v1.0.0");
     return 0;
@@ -12,14 +12,22 @@ pub use argument::function_arguments_to_string;
 
 use crate::{
   Environment,
-  typechecker::TTypes,
+  typechecker::{
+    has_literal_types,
+    TTypes,
+  },
+  utils::Regex,
 };
 
 use sflyn_parser::{
   Error,
   Expression,
   Expressions,
-  tokens::Types,
+  tokens::{
+    Literal,
+    Token,
+    Types,
+  },
 };
 
 pub fn check_expression(
@@ -92,6 +100,16 @@ pub fn check_expression(
     return prefix::check(&prefix_exp, environment);
   }
 
+  // Regular Expression
+  if let Some(regexp) = expression.get_regexp() {
+    // Check the pattern and the flags before running the program.
+    if let Err(message) = Regex::new(&regexp.get_pattern(), &regexp.get_flags()) {
+      return Err(Error::from_token(message, regexp.get_token()));
+    }
+
+    return Ok(TTypes::new_type(Types::REGEXP, String::from("regexp"), regexp.get_token()));
+  }
+
   // String
   if let Some(string) = expression.get_string() {
     return Ok(TTypes::new_type(Types::STRING, String::from("string"), string.get_token()));
@@ -108,3 +126,66 @@ pub fn check_expression(
     expression.token(),
   ))
 }
+
+/// Check an expression using the data type expected by the context, for example
+/// an array expression assigned to a tuple.
+pub fn check_expression_with_type(
+  expression: &Box<Expressions>,
+  data_type: &Token,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  let mut data_type: Token = data_type.clone();
+
+  // Get the data type of the interfaces and the type aliases.
+  if data_type.token.is_identifier() {
+    if let Some(ttype) = environment.store.get_type(&data_type.value) {
+      if ttype.is_interface() || ttype.is_alias() {
+        data_type = Token::from_value(&ttype.get_value(), data_type.line, data_type.position);
+      }
+    }
+  }
+
+  // Check if is an array expression with a tuple data type.
+  if let Some(array_exp) = expression.get_array() {
+    if let Some(tuple) = data_type.token.get_type().and_then(|data_type| data_type.get_tuple()) {
+      return array::check_tuple(&array_exp, &tuple, environment);
+    }
+
+    // Check if the data type is an union with tuples, the first tuple that matches is used.
+    if let Some(union) = data_type.token.get_type().and_then(|data_type| data_type.get_union()) {
+      for tuple in union.get_items().iter().filter_map(|item| item.token.get_type().and_then(|item| item.get_tuple())) {
+        if let Ok(ttype) = array::check_tuple(&array_exp, &tuple, environment) {
+          return Ok(ttype);
+        }
+      }
+    }
+  }
+
+  // Check if is a hashmap expression with a hashmap data type.
+  if let Some(hashmap_exp) = expression.get_hashmap() {
+    if let Some(hashmap_type) = data_type.token.get_type().and_then(|data_type| data_type.get_hashmap()) {
+      return hashmap::check_with_type(&hashmap_exp, &hashmap_type, environment);
+    }
+  }
+
+  // Check if the data type expects literals.
+  if data_type.token.get_type().is_some_and(|data_type| has_literal_types(&data_type)) {
+    if let Some(literal_type) = check_literal(expression) {
+      return Ok(literal_type);
+    }
+  }
+
+  check_expression(expression, environment)
+}
+
+/// Get the literal data type of a string or a number expression, for example `'read'`.
+pub fn check_literal(expression: &Expressions) -> Option<TTypes> {
+  if expression.get_string().is_none() && expression.get_number().is_none() {
+    return None;
+  }
+
+  let token: Token = expression.token();
+  let literal = Literal::from_value(&token.value).ok()?;
+
+  Some(TTypes::new_type(Types::LITERAL(literal), token.value.clone(), token))
+}
@@ -2,9 +2,9 @@ use crate::{
   Environment,
   Store,
   typechecker::{
-    check_statement,
-    equal_types,
+    check_function_body,
     function_arguments_to_string,
+    get_type_parameters,
     TTypes,
   },
 };
@@ -13,7 +13,10 @@ use sflyn_parser::{
   AnonymousFunction,
   Error,
   Expression,
-  tokens::Token,
+  tokens::{
+    Token,
+    Types,
+  },
 };
 
 pub fn check(
@@ -25,9 +28,21 @@ pub fn check(
 
   function_environment.store = Store::from_store(&environment.store);
 
+  let mut arguments_environment: Environment = environment.clone();
+
+  // Check if the function is native to declare its type parameters (`T`, `U`) as `any`.
+  if anonymous_function.is_native() {
+    for parameter in get_type_parameters(&anonymous_function.string()) {
+      arguments_environment.store.set_type(
+        parameter,
+        TTypes::new_alias(Types::ANY, String::from("any"), anonymous_function.get_token()),
+      );
+    }
+  }
+
   let arguments: Vec<String>;
 
-  match function_arguments_to_string(anonymous_function.get_arguments(), environment, &mut function_environment) {
+  match function_arguments_to_string(anonymous_function.get_arguments(), &mut arguments_environment, &mut function_environment) {
     Ok(args) => {
       arguments = args;
     },
@@ -37,29 +52,30 @@ pub fn check(
   }
 
   // Get the function data type.
-  let data_type: Token = anonymous_function.get_type();
+  let data_type: Token = if anonymous_function.is_native() {
+    // Native functions are implemented in Rust, so their declared signature is trusted.
+    if anonymous_function.get_type().token.get_type().is_none() &&
+      !anonymous_function.get_type().token.is_identifier() {
+      return Err(Error::from_token(
+        format!("`{}` is not a valid data type.", anonymous_function.get_type().value),
+        anonymous_function.get_type(),
+      ));
+    }
 
-  // Get the ttypes from the function body.
-  match check_statement(&anonymous_function.get_body(), &mut function_environment) {
-    Ok(token) => {
-      if let Some(ttoken) = data_type.token.get_type() {
-        if !equal_types(ttoken, token.get_type()) && token.get_value() != "any" {
-          return Err(Error::from_token(
-            format!("`{}` not satisfied the `{}` data type.", token.get_token().value, data_type.value),
-            token.get_token(),
-          ));
-        }
-      } else {
-        return Err(Error::from_token(
-          format!("`{}` is not a valid data type.", data_type.value),
-          data_type,
-        ));
-      }
-    },
-    Err(error) => {
-      return Err(error);
-    },
-  }
+    anonymous_function.get_type()
+  } else {
+    match check_function_body(
+      anonymous_function.get_token(),
+      anonymous_function.get_type(),
+      anonymous_function.get_body(),
+      &mut function_environment,
+    ) {
+      Ok(data_type) => data_type,
+      Err(error) => {
+        return Err(error);
+      },
+    }
+  };
 
   let value = format!("({}) => {}", arguments.join(", "), data_type.value);
   let token = Token::from_value(value.as_str(), 0, 0);
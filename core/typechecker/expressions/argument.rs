@@ -1,6 +1,10 @@
 use crate::{
   Environment,
-  typechecker::TTypes,
+  typechecker::{
+    get_ttypes_from_token,
+    resolve_type_token,
+    TTypes,
+  },
 };
 
 use sflyn_parser::{
@@ -8,11 +12,7 @@ use sflyn_parser::{
   Error,
   Expression,
   Expressions,
-  tokens::{
-    Token,
-    Tokens,
-    Types,
-  },
+  tokens::Token,
 };
 
 pub fn function_arguments_to_string(
@@ -42,58 +42,41 @@ pub fn function_arguments_to_string(
 
     // Check if the argument data type is an identifier.
     if argument.get_type().token.is_identifier() {
-      // Get the interface type from the environment store.
-      match environment.store.get_type(&argument.get_type().value) {
+      // Get the interface or the type alias from the environment store.
+      ttype = resolve_type_token(argument.get_type(), environment)?;
+    }
+    // Check if the argument data type is a type.
+    else if argument.get_type().token.get_type().is_none() {
+      return Err(Error::from_token(
+        format!(
+          "`{}` is not a valid data type.",
+          argument.get_type().value,
+        ),
+        argument.get_type(),
+      ));
+    } else {
+      match get_ttypes_from_token(argument.get_type(), argument.get_token()) {
         Some(data_type) => {
           ttype = data_type;
-        }
+        },
         None => {
           return Err(Error::from_token(
             format!(
-              "`{}` is not a valid interface.",
+              "`{}` is not a valid data type.",
               argument.get_type().value,
             ),
             argument.get_type(),
           ));
-        }
+        },
       }
     }
-    // Check if the argument data type is a type.
-    else if argument.get_type().token.get_type().is_none() {
+
+    // Check if the rest argument collects the values in an array.
+    if argument.is_rest() && argument.get_type().token.get_type().and_then(|data_type| data_type.get_array()).is_none() {
       return Err(Error::from_token(
-        format!(
-          "`{}` is not a valid data type.",
-          argument.get_type().value,
-        ),
+        format!("the rest argument must be an array, got `{}` instead.", argument.get_type().value),
         argument.get_type(),
       ));
-    } else {
-      let data_type: Types = argument.get_type().token.get_type().unwrap();
-
-      if let Some(function) = data_type.get_function() {
-        let mut arguments: Vec<Box<Expressions>> = Vec::new();
-
-        for (key, value) in function.get_arguments().iter() {
-          arguments.push(Argument::new_box_full(
-            Token::new(Box::new(Tokens::IDENTIFIER), key.clone(), 0, 0),
-            value.clone(),
-            None,
-          ));
-        }
-
-        ttype = TTypes::new_function(
-          data_type,
-          argument.get_type().value,
-          argument.get_token(),
-          arguments,
-        );
-      } else {
-        ttype = TTypes::new_type(
-          data_type,
-          argument.get_type().value,
-          argument.get_token()
-        );
-      }
     }
 
     // Add the argument to the closed environment.
@@ -2,8 +2,14 @@ use crate::{
   Environment,
   typechecker::{
     check_expression,
+    check_expression_with_type,
     equal_types,
+    get_element_type,
+    get_ttypes_from_token,
+    is_assignable_ttypes,
+    resolve_type_token,
     TTypes,
+    widen_type,
   },
 };
 
@@ -14,7 +20,9 @@ use sflyn_parser::{
   Expression,
   tokens::{
     Array as ArrayType,
+    Signs,
     Token,
+    Tuple,
     Types,
   },
 };
@@ -25,56 +33,74 @@ pub fn check(
 ) -> Result<TTypes, Error> {
   if array.get_data().len() == 0 {
     return Ok(TTypes::new_array(
-      Types::ARRAY(ArrayType::from_value("null[]").unwrap()),
-      String::from("any"),
+      Types::ARRAY(ArrayType::from_value("any[]").unwrap()),
+      String::from("any[]"),
       array.get_token(),
     ));
   }
 
-  let mut data_type: Option<TTypes> = None;
+  let mut items: Vec<TTypes> = Vec::new();
 
   for item in array.get_data().iter() {
-    match check_expression(item, environment) {
-      Ok(token) => {
-        if data_type.clone().is_some() {
-          if !equal_types(data_type.clone().unwrap().get_type(), token.get_type()) {
-            return Err(Error::from_token(
-              format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.unwrap().get_value()),
-              token.get_token(),
-            ));
-          }
-
-          continue;
-        }
-
-        data_type = Some(token);
-      },
-      Err(error) => {
-        return Err(error);
-      },
-    }
+    items.push(widen_type(check_expression(item, environment)?));
   }
 
-  if let Some(data_type) = data_type {
-    let value = format!("{}[]", data_type.get_value());
-    let token = Token::from_value(value.as_str(), 0, 0);
+  let mut value = format!("{}[]", items[0].get_value());
 
-    if token.token.get_type().is_none() {
-      return Err(Error::from_token(
-        String::from("is not a valid array."),
-        array.get_token(),
-      ));
-    }
+  // Check if the items have different data types.
+  if items.iter().any(|item| !equal_types(items[0].get_type(), item.get_type())) {
+    let values: Vec<String> = items.iter().map(|item| item.get_value()).collect();
 
-    return Ok(TTypes::new_array(
-      token.token.get_type().unwrap(),
-      value,
+    value = format!("[{}]", values.join(", "));
+  }
+
+  let token = Token::from_value(value.as_str(), 0, 0);
+
+  if token.token.get_type().is_none() {
+    return Err(Error::from_token(
+      String::from("is not a valid array."),
+      array.get_token(),
+    ));
+  }
+
+  Ok(TTypes::new_array(
+    token.token.get_type().unwrap(),
+    value,
+    array.get_token(),
+  ))
+}
+
+/// Check an array expression with the items of a tuple type.
+pub fn check_tuple(
+  array: &Array,
+  tuple: &Tuple,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  let items = tuple.get_items();
+
+  if array.get_data().len() != items.len() {
+    return Err(Error::from_token(
+      format!("expect `{}` elements, got `{}` instead.", items.len(), array.get_data().len()),
       array.get_token(),
     ));
   }
 
-  Err(Error::from_token(
-    String::from("invalid array expression."),
+  for (item, data_type) in array.get_data().iter().zip(items.iter()) {
+    let item_type: TTypes = check_expression_with_type(item, data_type, environment)?;
+
+    let expected: TTypes = resolve_type_token(data_type.clone(), environment)?;
+
+    if !is_assignable_ttypes(&expected, &item_type) {
+      return Err(Error::from_token(
+        format!("`{}` not satisfied the `{}` data type.", item_type.get_value(), data_type.value),
+        item.token(),
+      ));
+    }
+  }
+
+  Ok(TTypes::new_array(
+    Types::TUPLE(tuple.clone()),
+    tuple.get_value(),
     array.get_token(),
   ))
 }
@@ -105,7 +131,22 @@ pub fn check_index(
     }
   }
 
-  let array_type = array_type.unwrap();
+  let array_type = widen_type(array_type.unwrap());
+
+  // Check if is a dynamic type.
+  if array_type.get_type() == Types::ANY {
+    return Ok(TTypes::new_type(Types::ANY, String::from("any"), array_index.get_token()));
+  } else if array_type.get_type() == Types::UNKNOWN {
+    return Err(Error::from_token(
+      format!("`{}` is unknown, narrow it with `is` or `as` before use.", array_index.get_token().value),
+      array_index.get_token(),
+    ));
+  }
+
+  // Check if is a hashmap.
+  if array_type.is_hashmap() || array_type.is_interface() {
+    return check_hashmap_index(array_index, array_type, environment);
+  }
 
   if array_type.get_type() == Types::STRING {
     return Ok(TTypes::new_type(
@@ -120,11 +161,125 @@ pub fn check_index(
     ));
   }
 
+  // Check if is a tuple.
+  if let Some(tuple) = array_type.get_type().get_tuple() {
+    return check_tuple_index(array_index, &tuple, environment);
+  }
+
   let array = array_type.get_type().get_array().unwrap().get_type();
 
+  // Check if the element is an array or a hashmap, they keep their methods (`list[0]->push(1)`).
+  if let Some(ttype) = get_ttypes_from_token(array.clone(), array_index.get_token()) {
+    return Ok(ttype);
+  }
+
   Ok(TTypes::new_type(
     array.token.get_type().unwrap(),
     array.value,
     array_index.get_token(),
   ))
 }
+
+fn check_tuple_index(
+  array_index: &ArrayIndex,
+  tuple: &Tuple,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  let items = tuple.get_items();
+  let index = array_index.get_index();
+  let mut offset: Option<(f64, bool)> = None;
+
+  // Get the literal index, the negative indexes start from the end (`-1` is the last item).
+  if let Some(number) = index.get_number() {
+    offset = Some((number.get_value(), false));
+  } else if let Some(prefix) = index.get_prefix() {
+    if prefix.get_token().token.expect_sign(&Signs::MINUS) {
+      offset = prefix.get_right().get_number().map(|number| (number.get_value(), true));
+    }
+  }
+
+  let item: Token = match offset {
+    Some((value, negative)) => {
+      let position = if negative { items.len() as f64 - value } else { value };
+
+      if value.fract() != 0.0 || position < 0.0 || position >= items.len() as f64 {
+        return Err(Error::from_token(
+          format!("index `{}` is out of range for `{}`.", index.string(), tuple.get_value()),
+          index.token(),
+        ));
+      }
+
+      items[position as usize].clone()
+    },
+    None => {
+      let index_type = widen_type(check_expression(&index, environment)?);
+
+      // Check if the index is a number.
+      if index_type.get_type() != Types::NUMBER && index_type.get_type() != Types::ANY {
+        return Err(Error::from_token(
+          format!("`{}` is not a valid index for `{}`, expect a number.", index_type.get_value(), tuple.get_value()),
+          index.token(),
+        ));
+      }
+
+      // The items with a dynamic index are the union of the items data types.
+      match get_element_type(&Types::TUPLE(tuple.clone())) {
+        Some(item) => item,
+        None => {
+          return Err(Error::from_token(
+            format!("`{}` does not have items.", tuple.get_value()),
+            index.token(),
+          ));
+        },
+      }
+    },
+  };
+
+  match get_ttypes_from_token(item.clone(), array_index.get_token()) {
+    Some(ttype) => Ok(ttype),
+    None => Err(Error::from_token(
+      format!("`{}` is not a valid data type.", item.value),
+      array_index.get_token(),
+    )),
+  }
+}
+
+fn check_hashmap_index(
+  array_index: &ArrayIndex,
+  hashmap_type: TTypes,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  let index_type: TTypes = check_expression(&array_index.get_index(), environment)?;
+
+  // Check if the index is a known key.
+  if let Some(string) = array_index.get_index().get_string() {
+    let key = string.get_value();
+
+    if let Some(ttype) = hashmap_type.get_methods().get(&key[1..key.len() - 1]) {
+      return Ok(ttype.clone());
+    }
+  }
+
+  match hashmap_type.get_type().get_hashmap().and_then(|hashmap| hashmap.get_index()) {
+    Some((key_type, value_type)) => {
+      if !key_type.token.get_type().is_some_and(|data_type| equal_types(data_type, index_type.get_type())) {
+        return Err(Error::from_token(
+          format!("`{}` not satisfied the `{}` data type.", index_type.get_value(), key_type.value),
+          array_index.get_index().token(),
+        ));
+      }
+
+      match get_ttypes_from_token(value_type.clone(), array_index.get_token()) {
+        Some(ttype) => Ok(ttype),
+        None => Err(Error::from_token(
+          format!("`{}` is not a valid data type.", value_type.value),
+          value_type,
+        )),
+      }
+    },
+    None => Err(Error::from_token(
+      format!("`{}` does not have an index signature.", hashmap_type.get_value()),
+      array_index.get_token(),
+    )),
+  }
+}
@@ -2,9 +2,17 @@ use crate::{
   Environment,
   typechecker::{
     check_expression,
-    equal_types,
+    check_expression_with_type,
     get_ttypes_from_token,
+    get_type_parameters,
+    infer_type_parameters,
+    instantiate_function,
+    is_assignable,
+    is_assignable_ttypes,
+    resolve_type_token,
+    satisfies_interface,
     TTypes,
+    widen_type,
   },
 };
 
@@ -13,15 +21,28 @@ use sflyn_parser::{
   Call,
   Error,
   Expression,
-  tokens::Token,
+  tokens::{
+    Token,
+    Types,
+  },
 };
 
+use std::collections::HashMap;
+
 pub fn check(
   call: &Call,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
   let function_type = environment.store.get_type(&call.get_token().value);
 
+  // Check if the call is a recursive call of a function without a return data type.
+  if function_type.is_none() && environment.inferred.contains(&call.get_token().value) {
+    return Err(Error::from_token(
+      format!("`{}` is recursive, the recursive functions need a return type annotation.", call.get_token().value),
+      call.get_token(),
+    ));
+  }
+
   // Check if the call token exists in the environment store.
   if function_type.is_none() {
     return Err(Error::from_token(
@@ -30,7 +51,21 @@ pub fn check(
     ));
   }
 
-  let function_type: TTypes = function_type.unwrap();
+  let mut function_type: TTypes = function_type.unwrap();
+
+  // Check if the function is dynamic.
+  if function_type.get_type() == Types::ANY {
+    for argument in call.get_arguments().iter() {
+      check_expression(argument, environment)?;
+    }
+
+    return Ok(TTypes::new_type(Types::ANY, String::from("any"), call.get_token()));
+  } else if function_type.get_type() == Types::UNKNOWN {
+    return Err(Error::from_token(
+      format!("`{}` is unknown, narrow it with `is` or `as` before use.", call.get_token().value),
+      call.get_token(),
+    ));
+  }
 
   if !function_type.is_function() {
     return Err(Error::from_token(
@@ -41,11 +76,18 @@ pub fn check(
 
   let mut min_arguments: usize = 0;
   let mut max_arguments: usize = 0;
+  let mut has_rest = false;
 
   for argument in function_type.get_arguments().iter() {
     // Get the argument expression.
     let argument: Argument = argument.get_argument().unwrap();
 
+    // Check if the argument collects the remaining arguments.
+    if argument.is_rest() {
+      has_rest = true;
+      continue;
+    }
+
     max_arguments += 1;
 
     // Check if the argument has a default value.
@@ -65,7 +107,7 @@ pub fn check(
     ));
   }
 
-  if call.get_arguments().len() > max_arguments {
+  if !has_rest && call.get_arguments().len() > max_arguments {
     return Err(Error::from_token(
       format!(
         "expected maximum `{}` arguments, got `{}` instead.",
@@ -76,11 +118,41 @@ pub fn check(
     ));
   }
 
+  // Check if the function has type parameters to infer them from the arguments.
+  let type_parameters: Vec<String> = get_type_parameters(&function_type.get_value()).into_iter()
+    .filter(|parameter| environment.store.get_type(parameter).is_none())
+    .collect();
+
+  if !type_parameters.is_empty() {
+    let mut bindings: HashMap<String, String> = HashMap::new();
+
+    for (index, argument) in call.get_arguments().iter().enumerate() {
+      let function_argument: Token = get_argument_type(&function_type, index);
+
+      if get_type_parameters(&function_argument.value).is_empty() {
+        continue;
+      }
+
+      let argument_type: TTypes = widen_type(check_expression(argument, environment)?);
+
+      infer_type_parameters(&function_argument.value, &argument_type.get_value(), &mut bindings);
+    }
+
+    // The type parameters without arguments are dynamic.
+    for parameter in type_parameters.iter() {
+      let data_type = bindings.get(parameter).cloned().unwrap_or_else(|| String::from("any"));
+
+      function_type = instantiate_function(&function_type, parameter, &data_type);
+    }
+  }
+
   // Get call arguments types.
   let mut call_arguments_types: Vec<TTypes> = Vec::new();
 
-  for argument in call.get_arguments().iter() {
-    match check_expression(argument, environment) {
+  for (index, argument) in call.get_arguments().iter().enumerate() {
+    let function_argument: Token = get_argument_type(&function_type, index);
+
+    match check_expression_with_type(argument, &function_argument, environment) {
       Ok(token) => {
         call_arguments_types.push(token);
       },
@@ -91,37 +163,32 @@ pub fn check(
   }
 
   // Compare arguments types.
-  let mut index: usize = 0;
-
-  for argument in call_arguments_types.iter() {
+  for (index, argument) in call_arguments_types.iter().enumerate() {
     let call_token: Token = call.get_arguments()[index].token();
-    let function_argument: Token = function_type.get_arguments()[index].get_argument().unwrap().get_type();
+    let function_argument: Token = get_argument_type(&function_type, index);
 
     if function_argument.token.is_identifier() {
-      if let Some(interface_type) = environment.store.get_type(&function_argument.value) {
-        if interface_type.is_interface() {
-          if equal_types(interface_type.get_type(), argument.get_type()) {
-            continue;
-          }
+      let expected: TTypes = resolve_type_token(function_argument.clone(), environment)?;
 
+      if expected.is_interface() {
+        if let Err(message) = satisfies_interface(&expected, argument) {
           return Err(Error::from_token(
-            format!("`{}` not satisfied the `{}` interface.", argument.get_value(), function_argument.value),
+            format!("`{}` not satisfied the `{}` interface: {}.", argument.get_value(), function_argument.value, message),
             call_token,
           ));
         }
 
+        continue;
+      }
+
+      if !is_assignable_ttypes(&expected, argument) {
         return Err(Error::from_token(
-          format!("`{}` is not a valid interface.", function_argument.value),
+          format!("`{}` not satisfied the `{}` data type.", argument.get_value(), function_argument.value),
           call_token,
         ));
       }
-
-      return Err(Error::from_token(
-        format!("`{}` identifier not found.", function_argument.value),
-        call_token,
-      ));
     } else if function_argument.token.get_type().is_some() {
-      if equal_types(function_argument.token.get_type().unwrap(), argument.get_type()) {
+      if is_assignable(function_argument.token.get_type().unwrap(), argument.get_type()) {
         continue;
       }
 
@@ -130,11 +197,14 @@ pub fn check(
         call_token,
       ));
     }
-
-    index += 1;
   }
 
   if let Some(function) = function_type.get_type().get_function() {
+    // Check if the function returns an interface or a type alias.
+    if function.get_type().token.is_identifier() {
+      return resolve_type_token(function.get_type(), environment);
+    }
+
     if let Some(ttype) = get_ttypes_from_token(function.get_type(), call.get_token()) {
       return Ok(ttype);
     }
@@ -145,3 +215,19 @@ pub fn check(
     call.get_token(),
   ))
 }
+
+/// Get the data type of the function argument that receives the call argument in a position, the
+/// arguments after a rest argument use its element type.
+fn get_argument_type(function_type: &TTypes, index: usize) -> Token {
+  let arguments = function_type.get_arguments();
+  let argument: Argument = arguments[index.min(arguments.len() - 1)].get_argument().unwrap();
+
+  if index + 1 < arguments.len() || !argument.is_rest() {
+    return argument.get_type();
+  }
+
+  match argument.get_type().token.get_type().and_then(|data_type| data_type.get_array()) {
+    Some(array) => array.get_type(),
+    None => argument.get_type(),
+  }
+}
@@ -11,6 +11,7 @@ use sflyn_parser::{
   Expression,
   ForCondition,
   tokens::{
+    Array as ArrayType,
     Token,
     Types,
   },
@@ -54,11 +55,13 @@ pub fn check(
               // Evaluate the third expression.
               match check_expression(&for_condition.get_third(), environment) {
                 Ok(third_ttype) => {
-                  let token = Token::from_value(format!("{}[]", third_ttype.get_value()).as_str(), 0, 0);
+                  // The elements can be unions (`string | null` of `readLine()`).
+                  let element = Token::from_value(&third_ttype.get_value(), 0, 0);
+                  let value = format!("{}[]", third_ttype.get_value());
 
                   return Ok(TTypes::new_for_in(
-                    token.token.get_type().unwrap(),
-                    token.value,
+                    Types::ARRAY(ArrayType::new(element, value.clone())),
+                    value,
                     for_condition.get_third().token(),
                     key_value.clone(),
                   ));
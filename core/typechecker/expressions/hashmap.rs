@@ -2,6 +2,7 @@ use crate::{
   Environment,
   typechecker::{
     check_expression,
+    check_expression_with_type,
     TTypes,
   },
 };
@@ -10,7 +11,10 @@ use sflyn_parser::{
   Error,
   Expression,
   HashMap,
-  tokens::Token,
+  tokens::{
+    HashMap as HashMapType,
+    Token,
+  },
 };
 
 use std::collections::HashMap as HashMapSTD;
@@ -18,15 +22,30 @@ use std::collections::HashMap as HashMapSTD;
 pub fn check(
   hashmap: &HashMap,
   environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  check_with_type(hashmap, &HashMapType::new(String::new()), environment)
+}
+
+/// Check a hashmap expression using the items data types expected by the context.
+pub fn check_with_type(
+  hashmap: &HashMap,
+  hashmap_type: &HashMapType,
+  environment: &mut Environment,
 ) -> Result<TTypes, Error> {
   let mut items: Vec<String> = Vec::new();
   let mut methods: HashMapSTD<String, TTypes> = HashMapSTD::new();
+  let expected_items = hashmap_type.get_items();
 
   for (key, value) in hashmap.get_items().iter() {
     let mut new_item: String = key.clone();
 
     // Check item value data type.
-    match check_expression(value, environment) {
+    let item_type = match expected_items.get(key) {
+      Some(expected) => check_expression_with_type(value, expected, environment),
+      None => check_expression(value, environment),
+    };
+
+    match item_type {
       Ok(data_type) => {
         new_item.push_str(": ");
         new_item.push_str(data_type.get_value().as_str());
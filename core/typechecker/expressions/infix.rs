@@ -3,9 +3,16 @@ use crate::{
   Store,
   typechecker::{
     check_expression,
-    equal_types,
+    check_expression_with_type,
+    get_element_type,
     get_ttypes_from_token,
+    get_values_type,
+    instantiate_function,
+    is_assignable_ttypes,
+    nullable_type,
+    resolve_type_token,
     TTypes,
+    widen_type,
   },
 };
 
@@ -14,6 +21,8 @@ use sflyn_parser::{
   Expression,
   Infix,
   tokens::{
+    Array as ArrayType,
+    item_from_value,
     Keywords,
     Signs,
     Token,
@@ -39,7 +48,7 @@ pub fn check(
   }
   // Check if the token is 'of'.
   else if infix.get_token().token.expect_keyword(&Keywords::OF) {
-    if infix.get_left().get_array().is_none() {
+    if infix.get_left().get_array().is_none() && infix.get_left().get_identifier().is_none() {
       return Err(Error::from_token(
         String::from("is not a valid expression for an `of`."),
         infix.get_left().token(),
@@ -48,7 +57,8 @@ pub fn check(
   } else {
     match check_expression(&infix.get_left(), environment) {
       Ok(token) => {
-        left_type = Some(token);
+        // Use the base data type of the literals in the methods and the operations.
+        left_type = Some(if infix.is_variable_set() || infix.is_alias() { token } else { widen_type(token) });
       },
       Err(error) => {
         return Err(error);
@@ -81,6 +91,24 @@ pub fn check(
 
   right_environment.store = Store::from_store(&environment.store);
 
+  // Check if the left type is dynamic.
+  if infix.is_method() || (
+    infix.is_infix() &&
+    !infix.get_token().token.expect_sign(&Signs::EQUAL) &&
+    !infix.get_token().token.expect_sign(&Signs::NOTEQUAL)
+  ) {
+    if let Some(left_type) = left_type.clone() {
+      if left_type.get_type() == Types::UNKNOWN {
+        return Err(Error::from_token(
+          format!("`{}` is unknown, narrow it with `is` or `as` before use.", infix.get_left().string()),
+          infix.get_left().token(),
+        ));
+      } else if left_type.get_type() == Types::ANY && infix.is_method() {
+        return Ok(TTypes::new_type(Types::ANY, String::from("any"), infix.get_token()));
+      }
+    }
+  }
+
   // Check if is a method.
   if infix.is_method() && left_type.clone().is_some() {
     let left_type: TTypes = left_type.clone().unwrap();
@@ -102,14 +130,63 @@ pub fn check(
     else if left_type.get_type() == Types::BOOLEAN {
       from_std = "Boolean";
     }
-    // Check if the left type is an array.
-    else if left_type.get_type().get_array().is_some() {
+    // Check if the left type is a regular expression.
+    else if left_type.get_type() == Types::REGEXP {
+      from_std = "RegExp";
+    }
+    // Check if the left type is a bytes value.
+    else if left_type.get_type() == Types::BYTES {
+      from_std = "Bytes";
+    }
+    // Check if the left type is an array or a tuple.
+    else if left_type.is_array() {
       from_std = "Array";
     }
+    // Check if the left type is a map or a set.
+    else if let Some(generic) = left_type.get_type().get_generic() {
+      if generic.get_name() == "Map" {
+        from_std = "Map";
+      } else if generic.get_name() == "Set" {
+        from_std = "Set";
+      }
+    }
     // Check if the left type is a hashmap or an interface.
     else if left_type.is_hashmap() || left_type.is_interface() {
+      let optionals = left_type.get_type().get_hashmap().map_or(Vec::new(), |hashmap| hashmap.get_optionals());
+
       for (key, value) in left_type.get_methods() {
-        right_environment.store.set_type(key, value);
+        // Check if the member is optional.
+        if optionals.contains(&key) {
+          right_environment.store.set_type(key, nullable_type(value));
+        } else {
+          right_environment.store.set_type(key, value);
+        }
+      }
+
+      // The hashmap items have priority over the `HashMap` standard library.
+      if let Some(right) = infix.get_right() {
+        if !left_type.get_methods().contains_key(&right.token().value) {
+          from_std = "HashMap";
+        }
+      }
+
+      // Check if the hashmap has an index signature.
+      if let Some((_, index_type)) = left_type.get_type().get_hashmap().and_then(|hashmap| hashmap.get_index()) {
+        if let Some(identifier) = infix.get_right().and_then(|right| right.get_identifier()) {
+          if !left_type.get_methods().contains_key(&identifier.get_value()) {
+            match get_ttypes_from_token(index_type.clone(), identifier.get_token()) {
+              Some(ttype) => {
+                right_environment.store.set_type(identifier.get_value(), ttype);
+              },
+              None => {
+                return Err(Error::from_token(
+                  format!("`{}` is not a valid data type.", index_type.value),
+                  index_type,
+                ));
+              },
+            }
+          }
+        }
       }
     }
 
@@ -117,7 +194,30 @@ pub fn check(
     if !from_std.is_empty() {
       if let Some(data_type) = environment.store.get_type(&from_std.to_string()) {
         if data_type.is_hashmap() {
-          for (key, value) in data_type.get_methods() {
+          // Get the type parameters of the methods: `T` is the elements data type of an array or a
+          // set, `K` and `V` are the keys and values data types and `H` is the data type of a hashmap.
+          let mut parameters: Vec<(&str, String)> = Vec::new();
+
+          if from_std == "Array" {
+            parameters.push(("T", get_element_type(&left_type.get_type()).map_or(String::from("any"), |element| element.value)));
+          } else if from_std == "HashMap" {
+            parameters.push(("V", get_values_type(&left_type)));
+            parameters.push(("H", left_type.get_value()));
+          } else if let Some(generic) = left_type.get_type().get_generic() {
+            let names: &[&str] = if from_std == "Map" { &["K", "V"] } else { &["T"] };
+
+            for (name, argument) in names.iter().zip(generic.get_arguments().iter()) {
+              parameters.push((name, argument.value.clone()));
+            }
+          }
+
+          for (key, mut value) in data_type.get_methods() {
+            if value.is_function() {
+              for (name, parameter) in parameters.iter() {
+                value = instantiate_function(&value, name, parameter);
+              }
+            }
+
             right_environment.store.set_type(key, value);
           }
         }
@@ -128,24 +228,24 @@ pub fn check(
   // Get the right type.
   let right_type: TTypes;
 
-  if infix.is_type() {
-    let right_token = infix.get_right_type().unwrap();
+  if infix.is_type() || infix.is_alias() {
+    right_type = resolve_type_token(infix.get_right_type().unwrap(), environment)?;
+  } else if infix.is_variable_set() && left_type.is_some() {
+    // Check the right expression with the left data type.
+    let left_token = Token::from_value(&left_type.clone().unwrap().get_value(), 0, 0);
 
-    match get_ttypes_from_token(right_token.clone(), right_token.clone()) {
-      Some(token) => {
+    match check_expression_with_type(&infix.get_right().unwrap(), &left_token, &mut right_environment) {
+      Ok(token) => {
         right_type = token;
       },
-      None => {
-        return Err(Error::from_token(
-          String::from("is not a valid data type."),
-          right_token,
-        ));
+      Err(error) => {
+        return Err(error);
       },
     }
   } else {
     match check_expression(&infix.get_right().unwrap(), &mut right_environment) {
       Ok(token) => {
-        right_type = token;
+        right_type = if infix.is_infix() { widen_type(token) } else { token };
       },
       Err(error) => {
         return Err(error);
@@ -155,6 +255,16 @@ pub fn check(
 
   // Check if is a method.
   if infix.is_method() {
+    // Check if is a `JSON->parse(text, 'User')` call, the value has the checked data type.
+    if let Some(data_type) = get_json_data_type(infix, environment)? {
+      return Ok(data_type);
+    }
+
+    // Check if is a `hashmap->merge(other)` or a `hashmap->get(key, fallback)` call.
+    if let Some(data_type) = get_hashmap_data_type(infix, &left_type, &mut right_environment)? {
+      return Ok(data_type);
+    }
+
     return Ok(right_type);
   }
   // Check if is an infix without 'in' or 'of'.
@@ -168,21 +278,21 @@ pub fn check(
       infix.get_token().token.expect_sign(&Signs::CARER) ||
       infix.get_token().token.expect_sign(&Signs::EMPOWERMENT) ||
       infix.get_token().token.expect_sign(&Signs::MODULE) {
-      if left_type.get_type() != Types::NUMBER || right_type.get_type() != Types::NUMBER {
+      if !is_number(&left_type) || !is_number(&right_type) {
         return Err(Error::from_token(
           String::from("only can do this with numbers."),
           infix.get_token(),
         ));
       }
 
-      return Ok(left_type);
+      return Ok(TTypes::new_type(Types::NUMBER, String::from("number"), infix.get_token()));
     }
     // Parse '<', '<=', '>' and '>=' with numbers.
     else if infix.get_token().token.expect_sign(&Signs::LESSTHAN) ||
       infix.get_token().token.expect_sign(&Signs::LESSOREQUALTHAN) ||
       infix.get_token().token.expect_sign(&Signs::GREATERTHAN) ||
       infix.get_token().token.expect_sign(&Signs::GREATEROREQUALTHAN) {
-      if left_type.get_type() != Types::NUMBER || right_type.get_type() != Types::NUMBER {
+      if !is_number(&left_type) || !is_number(&right_type) {
         return Err(Error::from_token(
           String::from("only can do this with numbers."),
           infix.get_token(),
@@ -197,6 +307,8 @@ pub fn check(
         return Ok(left_type);
       } else if left_type.get_type() == Types::STRING && right_type.get_type() == Types::STRING {
         return Ok(left_type);
+      } else if left_type.get_type() == Types::ANY || right_type.get_type() == Types::ANY {
+        return Ok(TTypes::new_type(Types::ANY, String::from("any"), infix.get_token()));
       }
 
       return Err(Error::from_token(
@@ -210,11 +322,11 @@ pub fn check(
     }
     // Parse '||'.
     else if infix.get_token().token.expect_sign(&Signs::OR) {
-      if left_type.get_type() == Types::NULL {
+      if left_type.get_type() == Types::NULL || left_type.get_type() == Types::ANY {
         return Ok(right_type);
       }
 
-      if !equal_types(left_type.get_type(), right_type.get_type()) {
+      if !is_assignable_ttypes(&left_type, &right_type) {
         return Err(Error::from_token(
           format!("`{}` not satisfied the `{}` data type.", right_type.get_value(), left_type.get_value()),
           right_type.get_token(),
@@ -225,7 +337,7 @@ pub fn check(
     }
     // Parse '&&'.
     else if infix.get_token().token.expect_sign(&Signs::AND) {
-      if left_type.get_type() != Types::BOOLEAN || right_type.get_type() != Types::BOOLEAN {
+      if !is_boolean(&left_type) || !is_boolean(&right_type) {
         return Err(Error::from_token(
           String::from("only can compare two boolean."),
           infix.get_token(),
@@ -239,7 +351,7 @@ pub fn check(
   else if infix.is_infix() && left_type.clone().is_none() {
     // Check if the token is 'in'.
     if infix.get_token().token.expect_keyword(&Keywords::IN) {
-      if right_type.is_array() && right_type.get_type().get_array().is_some() {
+      if right_type.is_array() && get_element_type(&right_type.get_type()).is_some() {
         return Ok(TTypes::new_for_in(
           right_type.get_type(),
           right_type.get_value(),
@@ -247,15 +359,55 @@ pub fn check(
           infix.get_left().get_identifier().unwrap().get_value(),
         ));
       }
+      // Check if is a tuple with different data types.
+      else if right_type.get_type().get_tuple().is_some() {
+        return Err(Error::from_token(
+          format!("can not iterate `{}`, the elements have different data types.", right_type.get_value()),
+          infix.get_right().unwrap().token(),
+        ));
+      }
+      // Iterate the hashmap keys, the keys are always strings.
+      else if right_type.get_type().get_hashmap().is_some() {
+        return Ok(TTypes::new_for_in(
+          Types::ARRAY(ArrayType::from_value("string[]").unwrap()),
+          right_type.get_value(),
+          right_type.get_token(),
+          infix.get_left().get_identifier().unwrap().get_value(),
+        ));
+      }
+
+      // Iterate the map keys or the set items.
+      else if let Some(element_type) = get_collection_element(&right_type.get_type(), false) {
+        return Ok(TTypes::new_for_in(
+          Types::ARRAY(ArrayType::new(element_type.clone(), format!("{}[]", element_type.value))),
+          right_type.get_value(),
+          right_type.get_token(),
+          infix.get_left().get_identifier().unwrap().get_value(),
+        ));
+      }
 
       return Err(Error::from_token(
-        String::from("expect an array expression."),
+        String::from("expect an array, hashmap, map or set expression."),
         infix.get_right().unwrap().token(),
       ));
     }
     // Check if the token is 'of'.
     else if infix.get_token().token.expect_keyword(&Keywords::OF) {
-      if right_type.is_hashmap() && right_type.get_type().get_hashmap().is_some() {
+      // Iterate the set items.
+      if let Some(identifier) = infix.get_left().get_identifier() {
+        if let Some(element_type) = get_collection_element(&right_type.get_type(), true) {
+          return Ok(TTypes::new_for_in(
+            Types::ARRAY(ArrayType::new(element_type.clone(), format!("{}[]", element_type.value))),
+            right_type.get_value(),
+            right_type.get_token(),
+            identifier.get_value(),
+          ));
+        }
+      }
+
+      let is_map = right_type.get_type().get_generic().is_some_and(|generic| generic.get_name() == "Map");
+
+      if is_map || ((right_type.is_hashmap() || right_type.is_interface()) && right_type.get_type().get_hashmap().is_some()) {
         if let Some(left_array) = infix.get_left().get_array() {
           if left_array.get_data().len() != 2 {
             return Err(Error::from_token(
@@ -289,7 +441,7 @@ pub fn check(
       }
 
       return Err(Error::from_token(
-        String::from("expect an hashmap expression."),
+        String::from("expect an hashmap, map or set expression."),
         infix.get_right().unwrap().token(),
       ));
     }
@@ -298,21 +450,35 @@ pub fn check(
   else if infix.is_type() {
     return Ok(TTypes::new_type(Types::BOOLEAN, String::from("boolean"), infix.get_token()));
   }
+  // Check if is a cast.
+  else if let (true, Some(left_type)) = (infix.is_alias(), left_type.clone()) {
+    // Dynamic values can be casted to any type, the rest only to a super type or a sub type.
+    if left_type.get_type() == Types::ANY ||
+      left_type.get_type() == Types::UNKNOWN ||
+      is_assignable_ttypes(&right_type, &left_type) ||
+      is_assignable_ttypes(&left_type, &right_type) {
+      return Ok(right_type);
+    }
+
+    return Err(Error::from_token(
+      format!("can not cast `{}` to `{}`.", left_type.get_value(), right_type.get_value()),
+      infix.get_token(),
+    ));
+  }
   // Check if is a variable set.
-  else if infix.is_variable_set() && left_type.is_some() {
-    let left_type: TTypes = left_type.unwrap();
+  else if let (true, Some(left_type)) = (infix.is_variable_set(), left_type.as_ref()) {
 
     if (
       infix.get_token().token.expect_sign(&Signs::MINUSASSIGN) ||
       infix.get_token().token.expect_sign(&Signs::MULTIPLYASSIGN) ||
       infix.get_token().token.expect_sign(&Signs::DIVIDEASSIGN)
-    ) && left_type.get_type() != Types::NUMBER {
+    ) && !is_number(left_type) {
       return Err(Error::from_token(
         format!("`{}` is not a number.", infix.get_left().string()),
         infix.get_left().token(),
       ));
     } else if infix.get_token().token.expect_sign(&Signs::PLUSASSIGN) &&
-      left_type.get_type() != Types::NUMBER &&
+      !is_number(left_type) &&
       left_type.get_type() != Types::STRING {
       return Err(Error::from_token(
         format!("`{}` is not a number or a string.", infix.get_left().string()),
@@ -320,8 +486,7 @@ pub fn check(
       ));
     }
     
-    if equal_types(left_type.get_type(), right_type.get_type()) ||
-      left_type.get_value() == "any" {
+    if is_assignable_ttypes(left_type, &right_type) {
       return Ok(right_type);
     }
 
@@ -340,3 +505,138 @@ pub fn check(
     infix.get_token(),
   ))
 }
+
+fn is_number(ttype: &TTypes) -> bool {
+  ttype.get_type() == Types::NUMBER || ttype.get_type() == Types::ANY
+}
+
+fn is_boolean(ttype: &TTypes) -> bool {
+  ttype.get_type() == Types::BOOLEAN || ttype.get_type() == Types::ANY
+}
+
+/// Get the data type of the iterated elements of a collection, the keys of a map or the items of
+/// a set (`only_set` ignores the maps).
+fn get_collection_element(data_type: &Types, only_set: bool) -> Option<Token> {
+  let generic = data_type.get_generic()?;
+
+  match generic.get_name().as_str() {
+    "Map" if !only_set => generic.get_arguments().first().cloned(),
+    "Set" => generic.get_arguments().first().cloned(),
+    _ => None,
+  }
+}
+
+/// Get the data type that a `JSON->parse(text, 'User')` call checks at runtime.
+/// Get the data type of a `HashMap` standard library `merge` call, the members of the other hashmap
+/// replace the members with the same name. The `get` calls with a fallback are not null.
+fn get_hashmap_data_type(
+  infix: &Infix,
+  left_type: &Option<TTypes>,
+  environment: &mut Environment,
+) -> Result<Option<TTypes>, Error> {
+  let call = infix.get_right().and_then(|right| right.get_call());
+  let name = call.clone().map_or(String::new(), |call| call.get_token().value);
+
+  let left_type = match left_type {
+    Some(left_type) if (left_type.is_hashmap() || left_type.is_interface()) && !left_type.get_methods().contains_key(&name) => left_type,
+    _ => return Ok(None),
+  };
+
+  // Check if is a `get(key, fallback)` call with a fallback that is not null.
+  if let Some(fallback) = call.clone().filter(|_| name == "get").and_then(|call| call.get_arguments().get(1).cloned()) {
+    if check_expression(&fallback, environment)?.get_type() == Types::NULL {
+      return Ok(None);
+    }
+
+    let values_type = Token::from_value(&get_values_type(left_type), infix.get_token().line, infix.get_token().position);
+
+    return Ok(get_ttypes_from_token(values_type, infix.get_token()));
+  }
+
+  let other = call
+    .filter(|_| name == "merge")
+    .and_then(|call| call.get_arguments().first().cloned());
+
+  let other = match other {
+    Some(other) => other,
+    None => return Ok(None),
+  };
+
+  let other_type = check_expression(&other, environment)?;
+
+  // The members are in the declaration order of the interfaces.
+  let get_names = |ttype: &TTypes| {
+    let mut names = ttype.get_names();
+
+    if names.is_empty() {
+      names = ttype.get_methods().keys().cloned().collect();
+      names.sort();
+    }
+
+    names
+  };
+
+  let mut methods = left_type.get_methods();
+  let mut names = get_names(left_type);
+
+  for name in get_names(&other_type).into_iter() {
+    methods.insert(name.clone(), other_type.get_methods()[&name].clone());
+
+    if !names.contains(&name) {
+      names.push(name);
+    }
+  }
+
+  let mut items: Vec<String> = Vec::new();
+
+  // Keep the index signature of the hashmaps.
+  let index = left_type.get_type().get_hashmap().and_then(|hashmap| hashmap.get_index())
+    .or_else(|| other_type.get_type().get_hashmap().and_then(|hashmap| hashmap.get_index()));
+
+  if let Some((key, value)) = index {
+    items.push(format!("[key: {}]: {}", key.value, value.value));
+  }
+
+  for name in names.iter() {
+    items.push(format!("{}: {}", name, methods[name].get_value()));
+  }
+
+  let value = format!("{{ {} }}", items.join(", "));
+
+  match Token::from_value(&value, 0, 0).token.get_type() {
+    Some(data_type) => Ok(Some(TTypes::new_hashmap(data_type, value, infix.get_token(), methods))),
+    None => Ok(None),
+  }
+}
+
+fn get_json_data_type(infix: &Infix, environment: &Environment) -> Result<Option<TTypes>, Error> {
+  if infix.get_left().get_identifier().is_none_or(|identifier| identifier.get_value() != "JSON") {
+    return Ok(None);
+  }
+
+  let data_type = infix.get_right()
+    .and_then(|right| right.get_call())
+    .filter(|call| call.get_token().value == "parse")
+    .and_then(|call| call.get_arguments().get(1).and_then(|argument| argument.get_string()));
+
+  match data_type {
+    Some(data_type) => {
+      let value = data_type.get_value();
+      let value = &value[1..value.len() - 1];
+
+      match item_from_value(value) {
+        Some(mut token) => {
+          token.line = data_type.get_token().line;
+          token.position = data_type.get_token().position;
+
+          resolve_type_token(token, environment).map(Some)
+        },
+        None => Err(Error::from_token(
+          format!("`{}` is not a valid data type.", value),
+          data_type.get_token(),
+        )),
+      }
+    },
+    None => Ok(None),
+  }
+}
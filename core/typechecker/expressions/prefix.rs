@@ -3,6 +3,7 @@ use crate::{
   typechecker::{
     check_expression,
     TTypes,
+    widen_type,
   },
 };
 
@@ -24,7 +25,7 @@ pub fn check(
 
   match check_expression(&prefix.get_right(), environment) {
     Ok(token) => {
-      right_type = token;
+      right_type = widen_type(token);
     },
     Err(error) => {
       return Err(error);
@@ -4,8 +4,13 @@ mod function;
 mod if_else;
 mod import;
 mod interface;
+mod return_s;
+mod type_alias;
 mod variable;
 
+pub use function::check_function_body;
+pub use return_s::check as check_return;
+
 use crate::{
   Environment,
   typechecker::{
@@ -76,11 +81,12 @@ pub fn check_statement(
 
   // Return
   if let Some(return_stmt) = statement.get_return() {
-    if let Some(value) = return_stmt.get_value() {
-      return check_expression(&value, environment);
-    }
+    return check_return(&return_stmt, environment);
+  }
 
-    return Ok(TTypes::new_type(Types::VOID, String::from("void"), return_stmt.get_token()));
+  // Type alias
+  if let Some(type_alias_stmt) = statement.get_type_alias() {
+    return type_alias::check(&type_alias_stmt, environment);
   }
 
   // Variable
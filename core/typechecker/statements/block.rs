@@ -2,7 +2,6 @@ use crate::{
   Environment,
   typechecker::{
     check_statement,
-    equal_types,
     TTypes,
   },
 };
@@ -18,36 +17,21 @@ pub fn check(
   block: &Block,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
-  let mut return_token: Option<TTypes> = None;
+  let mut result: Result<TTypes, Error> = Ok(TTypes::new_type(Types::VOID, String::from("void"), block.get_token()));
 
-  for statement in block.get_statements().iter() {
-    // Get the token for the current statement.
-    match check_statement(statement, environment) {
-      Ok(token) => {
-        if statement.get_return().is_some() || statement.get_if_else().is_some() {
-          if let Some(rtoken) = return_token.clone() {
-            if equal_types(rtoken.get_type(), token.get_type()) || token.get_value() == "any" {
-              continue;
-            }
-
-            return Err(Error::from_token(
-              format!("`{}` not satisfied the `{}` data type.", token.get_token().value, rtoken.get_token().value),
-              token.get_token(),
-            ));
-          }
+  // Create the block scope.
+  environment.store.push_scope();
 
-          return_token = Some(token);
-        }
-      },
-      Err(error) => {
-        return Err(error);
-      },
+  for statement in block.get_statements().iter() {
+    // Check the current statement.
+    if let Err(error) = check_statement(statement, environment) {
+      result = Err(error);
+      break;
     }
   }
 
-  if let Some(token) = return_token {
-    return Ok(token);
-  }
+  environment.store.pop_scope();
 
-  Ok(TTypes::new_type(Types::VOID, String::from("void"), block.get_token()))
+  // The return types are collected by the function.
+  result
 }
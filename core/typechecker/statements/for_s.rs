@@ -4,6 +4,7 @@ use crate::{
   typechecker::{
     check_expression,
     check_statement,
+    get_element_type,
     get_ttypes_from_token,
     TTypes,
   },
@@ -14,8 +15,8 @@ use sflyn_parser::{
   For,
   Statement,
   tokens::{
-    Array,
     HashMap,
+    Token,
     Types,
   },
 };
@@ -30,10 +31,10 @@ pub fn check(
 
   match check_expression(&for_s.get_condition(), &mut for_environment) {
     Ok(obj) => {
-      if obj.is_for_in() && obj.get_type().get_array().is_some() {
-        let right_array: Array = obj.get_type().get_array().unwrap();
+      if obj.is_for_in() && get_element_type(&obj.get_type()).is_some() {
+        let element_type: Token = get_element_type(&obj.get_type()).unwrap();
 
-        if let Some(ttype) = get_ttypes_from_token(right_array.get_type(), obj.get_token()) {
+        if let Some(ttype) = get_ttypes_from_token(element_type, obj.get_token()) {
           let mut new_environment = for_environment.clone();
 
           new_environment.store.set_type(
@@ -41,6 +42,19 @@ pub fn check(
             ttype.clone(),
           );
 
+          return check_statement(&for_s.get_body(), &mut new_environment);
+        }
+      } else if let (true, Some(generic)) = (obj.is_for_of(), obj.get_type().get_generic()) {
+        // Check the body with the keys and values data types of the map.
+        if obj.get_names().len() == 2 && generic.get_arguments().len() == 2 {
+          let mut new_environment = for_environment.clone();
+
+          for (name, data_type) in obj.get_names().iter().zip(generic.get_arguments()) {
+            if let Some(ttype) = get_ttypes_from_token(data_type.clone(), data_type) {
+              new_environment.store.set_type(name.clone(), ttype);
+            }
+          }
+
           return check_statement(&for_s.get_body(), &mut new_environment);
         }
       } else if obj.is_for_of() && obj.get_type().get_hashmap().is_some() {
@@ -73,6 +87,35 @@ pub fn check(
             }
           }
 
+          // Check the body with the index signature types.
+          if let Some((key_type, index_type)) = hashmap.get_index() {
+            let mut new_environment = for_environment.clone();
+
+            if let (Some(key_ttype), Some(ttype)) = (
+              get_ttypes_from_token(key_type.clone(), key_type),
+              get_ttypes_from_token(index_type.clone(), index_type),
+            ) {
+              new_environment.store.set_type(
+                obj.get_names()[0].clone(),
+                key_ttype,
+              );
+
+              new_environment.store.set_type(
+                obj.get_names()[1].clone(),
+                ttype,
+              );
+
+              match check_statement(&for_s.get_body(), &mut new_environment) {
+                Ok(token) => {
+                  return_ttype = Some(token);
+                },
+                Err(error) => {
+                  return Err(error);
+                },
+              }
+            }
+          }
+
           if let Some(ttype) = return_ttype {
             return Ok(ttype);
           }
@@ -13,15 +13,24 @@ use sflyn_parser::{
   Error,
   Function,
   Statement,
-  tokens::Token,
+  Statements,
+  tokens::{
+    Token,
+    Types,
+  },
+};
+
+use std::{
+  cell::RefCell,
+  rc::Rc,
 };
 
 pub fn check(
   function: &Function,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
-  // Check if the function name is already in use.
-  if environment.store.get_type(&function.get_name().value).is_some() {
+  // Check if the function name is already in use in the current scope.
+  if environment.store.has_type(&function.get_name().value) {
     return Err(Error::from_token(
       format!("`{}` is already in use.", function.get_name().value),
       function.get_name(),
@@ -44,30 +53,34 @@ pub fn check(
     },
   }
 
-  // Get the function data type.
-  let data_type: Token = function.get_type();
+  // Add the function to the closed environment to allow recursive calls.
+  if !function.get_type().value.is_empty() {
+    let value = format!("({}) => {}", arguments.join(", "), function.get_type().value);
+    let token = Token::from_value(value.as_str(), 0, 0);
 
-  // Get the ttypes from the function body.
-  match check_statement(&function.get_body(), &mut function_environment) {
-    Ok(token) => {
-      if let Some(ttoken) = data_type.token.get_type() {
-        if !equal_types(ttoken, token.get_type()) && token.get_value() != "any" {
-          return Err(Error::from_token(
-            format!("`{}` not satisfied the `{}` data type.", token.get_token().value, data_type.value),
-            token.get_token(),
-          ));
-        }
-      } else {
-        return Err(Error::from_token(
-          format!("`{}` is not a valid data type.", data_type.value),
-          data_type,
-        ));
-      }
-    },
+    if let Some(data_type) = token.token.get_type() {
+      function_environment.store.set_type(
+        function.get_name().value,
+        TTypes::new_function(data_type, token.value, function.get_token(), function.get_arguments()),
+      );
+    }
+  } else {
+    // The recursive calls need the return data type before the body is checked.
+    function_environment.inferred.push(function.get_name().value);
+  }
+
+  // Get the function data type.
+  let data_type: Token = match check_function_body(
+    function.get_name(),
+    function.get_type(),
+    function.get_body(),
+    &mut function_environment,
+  ) {
+    Ok(data_type) => data_type,
     Err(error) => {
       return Err(error);
     },
-  }
+  };
 
   let value = format!("({}) => {}", arguments.join(", "), data_type.value);
   let token = Token::from_value(value.as_str(), 0, 0);
@@ -90,3 +103,84 @@ pub fn check(
 
   Ok(ttype)
 }
+
+/// Check the body of a function and get the return data type.
+///
+/// When the function does not have a return data type, it is inferred from the `return` statements.
+pub fn check_function_body(
+  name: Token,
+  data_type: Token,
+  body: Box<Statements>,
+  function_environment: &mut Environment,
+) -> Result<Token, Error> {
+  let inferred = data_type.value.is_empty();
+
+  // Check if the return data type is valid.
+  if !inferred && data_type.token.get_type().is_none() && !data_type.token.is_identifier() {
+    return Err(Error::from_token(
+      format!("`{}` is not a valid data type.", data_type.value),
+      data_type,
+    ));
+  }
+
+  function_environment.return_type = if inferred { None } else { Some(data_type.clone()) };
+  function_environment.returns = Rc::new(RefCell::new(Vec::new()));
+
+  check_statement(&body, function_environment)?;
+
+  let returns: Vec<TTypes> = function_environment.returns.borrow().clone();
+  let mut return_type: Token = data_type.clone();
+
+  // Infer the return data type.
+  if inferred {
+    return_type = Token::from_value("void", 0, 0);
+
+    if let Some(first) = returns.first() {
+      for ttype in returns.iter() {
+        if !equal_types(first.get_type(), ttype.get_type()) {
+          return Err(Error::from_token(
+            format!("`{}` not satisfied the `{}` data type.", ttype.get_value(), first.get_value()),
+            ttype.get_token(),
+          ));
+        }
+      }
+
+      return_type = Token::from_value(first.get_value().as_str(), 0, 0);
+    }
+  }
+
+  // Check if all the paths return a value.
+  if return_type.token.get_type() != Some(Types::VOID) && !always_returns(&body) {
+    return Err(Error::from_token(
+      format!("`{}` does not return a value in all the paths, expect `{}`.", name.value, return_type.value),
+      name,
+    ));
+  }
+
+  Ok(return_type)
+}
+
+/// Check if a statement returns a value in all the control paths.
+fn always_returns(statement: &Statements) -> bool {
+  // Return
+  if statement.get_return().is_some() {
+    return true;
+  }
+
+  // Block
+  if let Some(block) = statement.get_block() {
+    return block.get_statements().iter().any(|statement| always_returns(statement));
+  }
+
+  // If else
+  if let Some(if_else) = statement.get_if_else() {
+    if let Some(alternative) = if_else.get_alternative() {
+      return always_returns(&alternative) && if_else.get_conditions().iter().all(|condition| {
+        always_returns(&condition.get_consequence())
+      });
+    }
+  }
+
+  // The loops can not run the body.
+  false
+}
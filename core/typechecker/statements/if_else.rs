@@ -3,7 +3,7 @@ use crate::{
   typechecker::{
     check_expression,
     check_statement,
-    equal_types,
+    resolve_type_token,
     TTypes,
   },
 };
@@ -19,12 +19,10 @@ pub fn check(
   if_else: &IfElse,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
-  let mut data_type: Option<TTypes> = None;
-
   for condition in if_else.get_conditions().iter() {
     match check_expression(&condition.get_condition(), environment) {
       Ok(token) => {
-        if token.get_type() != Types::BOOLEAN {
+        if token.get_type() != Types::BOOLEAN && token.get_type() != Types::ANY {
           return Err(Error::from_token(
             String::from("the condition is not a `boolean`."),
             condition.get_token(),
@@ -36,49 +34,30 @@ pub fn check(
       },
     }
 
-    match check_statement(&condition.get_consequence(), environment) {
-      Ok(token) => {
-        if data_type.clone().is_some() {
-          if !equal_types(data_type.clone().unwrap().get_type(), token.get_type()) && token.get_value() != "any" {
-            return Err(Error::from_token(
-              format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.unwrap().get_value()),
-              token.get_token(),
-            ));
-          }
+    // Check if the condition narrows an identifier (`value is string`).
+    if let Some(infix) = condition.get_condition().get_infix() {
+      if let (true, Some(identifier)) = (infix.is_type(), infix.get_left().get_identifier()) {
+        let narrowed_type = resolve_type_token(infix.get_right_type().unwrap(), environment)?;
 
-          continue;
-        }
+        environment.store.push_scope();
+        environment.store.set_type(identifier.get_value(), narrowed_type);
 
-        data_type = Some(token);
-      },
-      Err(error) => {
-        return Err(error);
-      },
+        let result = check_statement(&condition.get_consequence(), environment);
+
+        environment.store.pop_scope();
+
+        result?;
+        continue;
+      }
     }
-  }
 
-  if data_type.is_none() {
-    return Err(Error::from_token(
-      String::from("invalid data type."),
-      if_else.get_token(),
-    ));
+    check_statement(&condition.get_consequence(), environment)?;
   }
 
   if let Some(alternative) = if_else.get_alternative() {
-    match check_statement(&alternative, environment) {
-      Ok(token) => {
-        if !equal_types(data_type.clone().unwrap().get_type(), token.get_type()) && token.get_value() != "any" {
-          return Err(Error::from_token(
-            format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.unwrap().get_value()),
-            token.get_token(),
-          ));
-        }
-      },
-      Err(error) => {
-        return Err(error);
-      },
-    }
+    check_statement(&alternative, environment)?;
   }
 
-  Ok(data_type.unwrap())
+  // The return types are collected by the function.
+  Ok(TTypes::new_type(Types::VOID, String::from("void"), if_else.get_token()))
 }
@@ -1,6 +1,7 @@
 use crate::{
   Environment,
   program::run_file,
+  utils::get_import_path,
   Store,
   typechecker::{
     check_expression,
@@ -9,8 +10,10 @@ use crate::{
 };
 
 use sflyn_parser::{
+  Argument,
   Error,
   Expression,
+  Expressions,
   Import,
   Statement,
   tokens::{
@@ -69,10 +72,10 @@ pub fn check(
   }
 
   // Get the parent directory from the current file.
-  let parent_path = current_path.parent().unwrap().display();
+  let parent_path = current_path.parent().unwrap().display().to_string();
 
   // Get the new path for the import.
-  let new_path = format!("{}/{}.sf", parent_path, path_to);
+  let new_path = get_import_path(&parent_path, &path_to);
 
   // Clone the current environment.
   let mut import_environment = environment.clone();
@@ -101,6 +104,8 @@ pub fn check(
 
   for export in file_exports.iter() {
     if let Some(env_type) = import_environment.store.get_type(export) {
+      let env_type = inline_interfaces(env_type, &import_environment);
+
       values.push(format!("{}: {}", export.clone(), env_type.get_value()));
       methods.insert(export.clone(), env_type);
       continue;
@@ -142,7 +147,7 @@ pub fn check(
       // Check if the module is an identifier.
       if let Some(identifier) = module.get_identifier() {
         if let Some(token) = import_environment.store.get_type(&identifier.get_value()) {
-          environment.store.set_type(identifier.get_value(), token);
+          environment.store.set_type(identifier.get_value(), inline_interfaces(token, &import_environment));
           continue;
         }
 
@@ -160,7 +165,7 @@ pub fn check(
             // Get the type from the environment.
             if let Some(token) = import_environment.store.get_type(&left_identifier.get_value()) {
               if let Some(right_identifier) = infix.get_right().unwrap().get_identifier() {
-                environment.store.set_type(right_identifier.get_value(), token);
+                environment.store.set_type(right_identifier.get_value(), inline_interfaces(token, &import_environment));
                 continue;
               }
             }
@@ -194,3 +199,53 @@ pub fn check(
 
   Ok(ttype)
 }
+
+/// Replace the interfaces of an imported function with their data types, the importing file can
+/// call the function without importing the interfaces.
+fn inline_interfaces(ttype: TTypes, import_environment: &Environment) -> TTypes {
+  let function = match ttype.get_type().get_function() {
+    Some(function) => function,
+    None => {
+      return ttype;
+    },
+  };
+
+  // Get the data type of a token when it is an interface.
+  let inline = |token: Token| -> Token {
+    match import_environment.store.get_type(&token.value) {
+      Some(interface) if token.token.is_identifier() && interface.is_interface() => {
+        Token::from_value(&interface.get_value(), token.line, token.position)
+      },
+      _ => token,
+    }
+  };
+
+  let mut arguments: Vec<Box<Expressions>> = Vec::new();
+  let mut values: Vec<String> = Vec::new();
+
+  for argument in ttype.get_arguments().iter() {
+    let argument: Argument = argument.get_argument().unwrap();
+    let data_type = inline(argument.get_type());
+
+    if argument.is_rest() {
+      values.push(format!("...{}: {}", argument.get_token().value, data_type.value));
+      arguments.push(Argument::new_box_rest(argument.get_token(), data_type));
+      continue;
+    }
+
+    // Keep the default values of the arguments.
+    match argument.get_value() {
+      Some(value) => values.push(format!("{}: {} = {}", argument.get_token().value, data_type.value, value.string())),
+      None => values.push(format!("{}: {}", argument.get_token().value, data_type.value)),
+    }
+
+    arguments.push(Argument::new_box_full(argument.get_token(), data_type, argument.get_value()));
+  }
+
+  let value = format!("({}) => {}", values.join(", "), inline(function.get_type()).value);
+
+  match Token::from_value(&value, 0, 0).token.get_type() {
+    Some(data_type) => TTypes::new_function(data_type, value, ttype.get_token(), arguments),
+    None => ttype,
+  }
+}
@@ -1,13 +1,21 @@
 use crate::{
   Environment,
-  typechecker::TTypes,
+  typechecker::{
+    equal_types,
+    get_ttypes_from_token,
+    resolve_type_token,
+    TTypes,
+  },
 };
 
 use sflyn_parser::{
   Error,
   Interface,
   Statement,
-  tokens::Token,
+  tokens::{
+    Token,
+    Types,
+  },
 };
 
 use std::collections::HashMap;
@@ -16,35 +24,153 @@ pub fn check(
   interface: &Interface,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
-  // Check if the interface name is already in use.
-  if environment.store.get_type(&interface.get_name().value).is_some() {
+  // Check if the interface name is already in use in the current scope.
+  if environment.store.has_type(&interface.get_name().value) {
     return Err(Error::from_token(
       format!("`{}` is already in use.", interface.get_name().value),
       interface.get_name(),
     ));
   }
 
-  let mut values: Vec<String> = Vec::new();
+  let mut names: Vec<String> = Vec::new();
+  let mut optionals: Vec<String> = Vec::new();
   let mut methods: HashMap<String, TTypes> = HashMap::new();
+  let mut index: Option<(Token, Token)> = None;
+
+  // Get the members of the parent interfaces.
+  for parent in interface.get_extends().iter() {
+    let parent_type = match environment.store.get_type(&parent.value) {
+      Some(parent_type) => parent_type,
+      None => {
+        return Err(Error::from_token(
+          format!("`{}` identifier not found.", parent.value),
+          parent.clone(),
+        ));
+      },
+    };
+
+    if !parent_type.is_interface() {
+      return Err(Error::from_token(
+        format!("`{}` is not a valid interface.", parent.value),
+        parent.clone(),
+      ));
+    }
+
+    let parent_hashmap = parent_type.get_type().get_hashmap().unwrap();
+
+    if parent_hashmap.get_index().is_some() {
+      index = parent_hashmap.get_index();
+    }
+
+    for name in parent_type.get_names().iter() {
+      let method = parent_type.get_methods().get(name).unwrap().clone();
+
+      // Check if the member is already declared by other parent.
+      if let Some(current) = methods.get(name) {
+        if !equal_types(current.get_type(), method.get_type()) {
+          return Err(Error::from_token(
+            format!("`{}` is declared with different types in the parent interfaces.", name),
+            parent.clone(),
+          ));
+        }
+
+        continue;
+      }
+
+      if parent_hashmap.is_optional(name) {
+        optionals.push(name.clone());
+      }
+
+      names.push(name.clone());
+      methods.insert(name.clone(), method);
+    }
+  }
 
   for method in interface.get_methods().iter() {
-    if method.get_type().token.get_type().is_none() {
+    let method_type: Token = method.get_type();
+
+    let ttype: TTypes = if method_type.token.is_identifier() {
+      // Get the interface or the type alias data type from the store.
+      match environment.store.get_type(&method_type.value) {
+        Some(ttype) if ttype.is_interface() || ttype.is_alias() => resolve_type_token(method_type.clone(), environment)?,
+        _ => {
+          return Err(Error::from_token(
+            format!("`{}` is not a valid data type.", method_type.value),
+            method_type,
+          ));
+        },
+      }
+    } else {
+      match get_ttypes_from_token(method_type.clone(), method.get_token()) {
+        Some(ttype) => ttype,
+        None => {
+          return Err(Error::from_token(
+            format!("`{}` is not a valid data type.", method_type.value),
+            method_type,
+          ));
+        },
+      }
+    };
+
+    // Check if the member overrides a parent member with other data type.
+    if let Some(current) = methods.get(&method.get_token().value) {
+      if !equal_types(current.get_type(), ttype.get_type()) {
+        return Err(Error::from_token(
+          format!(
+            "`{}` is {}, expected {} as in the parent interface.",
+            method.get_token().value,
+            ttype.get_value(),
+            current.get_value(),
+          ),
+          method.get_token(),
+        ));
+      }
+    } else {
+      names.push(method.get_token().value);
+    }
+
+    // Check if the member is optional.
+    if method.is_optional() {
+      optionals.push(method.get_token().value);
+    } else {
+      optionals.retain(|name| name != &method.get_token().value);
+    }
+
+    methods.insert(method.get_token().value, ttype);
+  }
+
+  // Check if the interface has an index signature.
+  if let Some((key_type, value_type)) = interface.get_index() {
+    if key_type.token.get_type() != Some(Types::STRING) && key_type.token.get_type() != Some(Types::NUMBER) {
       return Err(Error::from_token(
-        format!("`{}` is not a valid data type.", method.get_type().value),
-        method.get_type(),
+        format!("`{}` is not a valid index signature key, expect `string` or `number`.", key_type.value),
+        key_type,
       ));
     }
 
-    values.push(format!("{}: {}", method.get_token().value, method.get_type().value));
+    if get_ttypes_from_token(value_type.clone(), value_type.clone()).is_none() {
+      return Err(Error::from_token(
+        format!("`{}` is not a valid data type.", value_type.value),
+        value_type,
+      ));
+    }
+
+    index = Some((key_type, value_type));
+  }
+
+  let mut values: Vec<String> = Vec::new();
+
+  if let Some((key_type, value_type)) = index.clone() {
+    values.push(format!("[key: {}]: {}", key_type.value, value_type.value));
+  }
 
-    methods.insert(
-      method.get_token().value,
-      TTypes::new_type(
-        method.get_type().token.get_type().unwrap(),
-        method.get_type().value,
-        method.get_token(),
-      ),
-    );
+  for name in names.iter() {
+    values.push(format!(
+      "{}{}: {}",
+      name,
+      if optionals.contains(name) { "?" } else { "" },
+      methods.get(name).unwrap().get_value(),
+    ));
   }
 
   let mut value = String::from("{");
@@ -66,6 +192,7 @@ pub fn check(
     value,
     interface.get_token(),
     methods,
+    names,
   );
 
   environment.store.set_type(interface.get_name().value, ttype.clone());
@@ -0,0 +1,59 @@
+use crate::{
+  Environment,
+  typechecker::{
+    check_expression,
+    check_expression_with_type,
+    is_assignable_ttypes,
+    resolve_type_token,
+    TTypes,
+  },
+};
+
+use sflyn_parser::{
+  Error,
+  Return,
+  Statement,
+  tokens::Types,
+};
+
+pub fn check(
+  return_s: &Return,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  let mut value_type: TTypes = TTypes::new_type(Types::VOID, String::from("void"), return_s.get_token());
+
+  if let Some(value) = return_s.get_value() {
+    value_type = match environment.return_type.clone() {
+      Some(return_type) => check_expression_with_type(&value, &return_type, environment)?,
+      None => check_expression(&value, environment)?,
+    };
+  }
+
+  // Check if the function has a return data type.
+  if let Some(return_type) = environment.return_type.clone() {
+    let expected: Option<TTypes> = resolve_type_token(return_type.clone(), environment).ok();
+
+    if let Some(expected) = expected {
+      if expected.get_type() == Types::VOID && return_s.get_value().is_some() {
+        return Err(Error::from_token(
+          String::from("a `void` function can not return a value."),
+          return_s.get_token(),
+        ));
+      } else if expected.get_type() != Types::VOID && return_s.get_value().is_none() {
+        return Err(Error::from_token(
+          format!("expect a `{}` value.", return_type.value),
+          return_s.get_token(),
+        ));
+      } else if !is_assignable_ttypes(&expected, &value_type) {
+        return Err(Error::from_token(
+          format!("`{}` not satisfied the `{}` data type.", value_type.get_value(), return_type.value),
+          return_s.get_value().unwrap().token(),
+        ));
+      }
+    }
+  }
+
+  environment.returns.borrow_mut().push(value_type.clone());
+
+  Ok(value_type)
+}
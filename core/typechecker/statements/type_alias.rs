@@ -0,0 +1,43 @@
+use crate::{
+  Environment,
+  typechecker::{
+    resolve_type_token,
+    TTypes,
+  },
+};
+
+use sflyn_parser::{
+  Error,
+  TypeAlias,
+};
+
+pub fn check(
+  type_alias: &TypeAlias,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  // Check if the type alias name is already in use in the current scope.
+  if environment.store.has_type(&type_alias.get_name().value) {
+    return Err(Error::from_token(
+      format!("`{}` is already in use.", type_alias.get_name().value),
+      type_alias.get_name(),
+    ));
+  }
+
+  // Check if the data type is valid.
+  let data_type: TTypes = resolve_type_token(type_alias.get_type(), environment)?;
+
+  // The interface aliases are the same interface.
+  let ttype: TTypes = if data_type.is_interface() {
+    data_type
+  } else {
+    TTypes::new_alias(
+      data_type.get_type(),
+      data_type.get_value(),
+      type_alias.get_name(),
+    )
+  };
+
+  environment.store.set_type(type_alias.get_name().value, ttype.clone());
+
+  Ok(ttype)
+}
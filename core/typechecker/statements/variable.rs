@@ -1,9 +1,16 @@
 use crate::{
   Environment,
   typechecker::{
-    check_expression,
-    equal_types,
+    check_expression_with_type,
+    check_literal,
+    get_element_type,
+    get_ttypes_from_token,
+    is_assignable,
+    is_assignable_ttypes,
+    resolve_type_token,
+    satisfies_interface,
     TTypes,
+    widen_type,
   },
 };
 
@@ -11,15 +18,23 @@ use sflyn_parser::{
   Error,
   Variable,
   Statement,
-  tokens::Keywords,
+  tokens::{
+    Keywords,
+    Token,
+  },
 };
 
 pub fn check(
   variable: &Variable,
   environment: &mut Environment,
 ) -> Result<TTypes, Error> {
-  // Check if the variable name is already in use.
-  if environment.store.get_type(&variable.get_name().value).is_some() {
+  // Check if is a destructuring.
+  if variable.is_destructuring() {
+    return check_destructuring(variable, environment);
+  }
+
+  // Check if the variable name is already in use in the current scope.
+  if environment.store.has_type(&variable.get_name().value) {
     return Err(Error::from_token(
       format!("`{}` is already in use.", variable.get_name().value),
       variable.get_name(),
@@ -29,30 +44,54 @@ pub fn check(
   let data_type = variable.get_type();
 
   if let Some(value) = variable.get_value() {
-    match check_expression(&value, environment) {
+    match check_expression_with_type(&value, &data_type, environment) {
       Ok(token) => {
         if variable.get_token().token.expect_keyword(&Keywords::CONST) {
           environment.store.set_const(variable.get_name().value);
         }
 
-        if data_type.value == "any" {
+        if data_type.value.is_empty() {
+          // The constants keep the literal values and the mutable variables use the base data type.
+          let token: TTypes = if variable.get_token().token.expect_keyword(&Keywords::CONST) {
+            check_literal(&value).unwrap_or(token)
+          } else {
+            widen_type(token)
+          };
+
           environment.store.set_type(variable.get_name().value, token.clone());
           return Ok(token);
+        } else if data_type.token.is_identifier() {
+          // Get the interface or the type alias from the store.
+          let declared_type: TTypes = resolve_type_token(data_type.clone(), environment)?;
+
+          if declared_type.is_interface() {
+            if let Err(message) = satisfies_interface(&declared_type, &token) {
+              return Err(Error::from_token(
+                format!("`{}` not satisfied the `{}` interface: {}.", token.get_value(), data_type.value, message),
+                value.token(),
+              ));
+            }
+          } else if !is_assignable_ttypes(&declared_type, &token) {
+            return Err(Error::from_token(
+              format!("`{}` not satisfied the `{}` data type.", token.get_value(), data_type.value),
+              value.token(),
+            ));
+          }
+
+          environment.store.set_type(variable.get_name().value, declared_type.clone());
+          return Ok(declared_type);
         } else if let Some(ttype) = data_type.token.get_type() {
-          if token.is_array() && token.get_value() == "any" && ttype.get_array().is_some() {
-            let new_token: TTypes = TTypes::new_array(
-              ttype,
-              data_type.value.clone(),
-              data_type,
-            );
-
-            environment.store.set_type(variable.get_name().value, new_token.clone());
-            return Ok(new_token);
+          // Check if the generic data type is valid.
+          if ttype.get_generic().is_some() {
+            resolve_type_token(data_type.clone(), environment)?;
           }
 
-          if equal_types(ttype, token.get_type()) || token.get_value() == "any" {
-            environment.store.set_type(variable.get_name().value, token.clone());
-            return Ok(token);
+          if is_assignable(ttype, token.get_type()) {
+            // Use the declared data type, the value can have more items.
+            let ttype = get_ttypes_from_token(data_type.clone(), variable.get_name()).unwrap_or(token);
+
+            environment.store.set_type(variable.get_name().value, ttype.clone());
+            return Ok(ttype);
           }
 
           return Err(Error::from_token(
@@ -72,3 +111,76 @@ pub fn check(
     variable.get_token(),
   ))
 }
+
+fn check_destructuring(
+  variable: &Variable,
+  environment: &mut Environment,
+) -> Result<TTypes, Error> {
+  // Check if the variable names are already in use in the current scope.
+  for name in variable.get_names().iter() {
+    if environment.store.has_type(&name.value) {
+      return Err(Error::from_token(
+        format!("`{}` is already in use.", name.value),
+        name.clone(),
+      ));
+    }
+  }
+
+  let data_type = variable.get_type();
+  let value = variable.get_value().unwrap();
+  let mut value_type: TTypes = check_expression_with_type(&value, &data_type, environment)?;
+
+  // Check if the value satisfies the variable data type.
+  if let Some(ttype) = data_type.token.get_type() {
+    if !is_assignable(ttype, value_type.get_type()) {
+      return Err(Error::from_token(
+        format!("`{}` not satisfied the `{}` data type.", value_type.get_value(), data_type.value),
+        value.token(),
+      ));
+    }
+
+    value_type = get_ttypes_from_token(data_type.clone(), variable.get_name()).unwrap_or(value_type);
+  }
+
+  let mut items: Vec<Token> = Vec::new();
+
+  if let Some(tuple) = value_type.get_type().get_tuple() {
+    items = tuple.get_items();
+
+    if variable.get_names().len() > items.len() {
+      return Err(Error::from_token(
+        format!("expect maximum `{}` names, got `{}` instead.", items.len(), variable.get_names().len()),
+        variable.get_name(),
+      ));
+    }
+  } else if let Some(element_type) = get_element_type(&value_type.get_type()) {
+    items = vec![element_type; variable.get_names().len()];
+  }
+
+  if items.is_empty() {
+    return Err(Error::from_token(
+      format!("`{}` is not an array or a tuple.", value_type.get_value()),
+      value.token(),
+    ));
+  }
+
+  for (name, item) in variable.get_names().iter().zip(items.iter()) {
+    match get_ttypes_from_token(item.clone(), name.clone()) {
+      Some(ttype) => {
+        environment.store.set_type(name.value.clone(), ttype);
+      },
+      None => {
+        return Err(Error::from_token(
+          format!("`{}` is not a valid data type.", item.value),
+          name.clone(),
+        ));
+      },
+    }
+
+    if variable.get_token().token.expect_keyword(&Keywords::CONST) {
+      environment.store.set_const(name.value.clone());
+    }
+  }
+
+  Ok(value_type)
+}
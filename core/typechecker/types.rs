@@ -13,6 +13,7 @@ pub enum TType {
   NONE,
 
   INTERFACE,
+  ALIAS,
   FUNCTION,
   HASHMAP,
   ARRAY,
@@ -75,17 +76,34 @@ impl TTypes {
     type_value: String,
     token: Token,
     methods: HashMap<String, TTypes>,
+    names: Vec<String>,
   ) -> Self {
     Self::new(
       TType::INTERFACE,
       data_type,
       type_value,
       token,
-      Vec::new(),
+      names,
       Vec::new(),
       methods,
     )
-  } 
+  }
+
+  pub fn new_alias(
+    data_type: Types,
+    type_value: String,
+    token: Token,
+  ) -> Self {
+    Self::new(
+      TType::ALIAS,
+      data_type,
+      type_value,
+      token,
+      Vec::new(),
+      Vec::new(),
+      HashMap::new(),
+    )
+  }
 
   pub fn new_function(
     data_type: Types,
@@ -176,6 +194,10 @@ impl TTypes {
     self.ttype == TType::INTERFACE
   }
 
+  pub fn is_alias(&self) -> bool {
+    self.ttype == TType::ALIAS
+  }
+
   pub fn is_function(&self) -> bool {
     self.ttype == TType::FUNCTION
   }
@@ -1,8 +1,18 @@
-use crate::typechecker::TTypes;
+use crate::{
+  Environment,
+  typechecker::TTypes,
+};
 
-use sflyn_parser::tokens::{
-  Token,
-  Types,
+use sflyn_parser::{
+  Argument,
+  Error,
+  Expression,
+  Expressions,
+  tokens::{
+    Token,
+    Tokens,
+    Types,
+  },
 };
 
 use std::collections::HashMap;
@@ -15,11 +25,9 @@ pub fn equal_types(one: Types, two: Types) -> bool {
 
     if one_function.get_arguments().len() == two_function.get_arguments().len() &&
       equal_tokens(one_function.get_type(), two_function.get_type()) {
-      for (key, value) in one_function.get_arguments().iter() {
-        if let Some(value_token) = two_function.get_arguments().get(key) {
-          if equal_tokens(value.clone(), value_token.clone()) {
-            continue;
-          }
+      for (index, (_, value)) in one_function.get_arguments().iter().enumerate() {
+        if equal_tokens(value.clone(), two_function.get_arguments()[index].1.clone()) {
+          continue;
         }
 
         return false;
@@ -33,7 +41,23 @@ pub fn equal_types(one: Types, two: Types) -> bool {
     let one_hashmap = one.get_hashmap().unwrap();
     let two_hashmap = two.get_hashmap().unwrap();
 
-    if one_hashmap.get_items().len() == two_hashmap.get_items().len() {
+    let mut one_optionals = one_hashmap.get_optionals();
+    let mut two_optionals = two_hashmap.get_optionals();
+
+    one_optionals.sort();
+    two_optionals.sort();
+
+    let equal_indexes = match (one_hashmap.get_index(), two_hashmap.get_index()) {
+      (Some((one_key, one_value)), Some((two_key, two_value))) => {
+        equal_tokens(one_key, two_key) && equal_tokens(one_value, two_value)
+      },
+      (None, None) => true,
+      _ => false,
+    };
+
+    if one_hashmap.get_items().len() == two_hashmap.get_items().len() &&
+      one_optionals == two_optionals &&
+      equal_indexes {
       for (key, value) in one_hashmap.get_items().iter() {
         if let Some(value_token) = two_hashmap.get_items().get(key) {
           if equal_tokens(value.clone(), value_token.clone()) {
@@ -54,6 +78,35 @@ pub fn equal_types(one: Types, two: Types) -> bool {
 
     return equal_tokens(one_array.get_type(), two_array.get_type());
   }
+  // Check if both types are tuples.
+  else if let (Some(one_tuple), Some(two_tuple)) = (one.get_tuple(), two.get_tuple()) {
+    let one_items = one_tuple.get_items();
+    let two_items = two_tuple.get_items();
+
+    return one_items.len() == two_items.len() &&
+      one_items.iter().zip(two_items.iter()).all(|(one, two)| equal_tokens(one.clone(), two.clone()));
+  }
+  // Check if both types are generics.
+  else if let (Some(one_generic), Some(two_generic)) = (one.get_generic(), two.get_generic()) {
+    let one_arguments = one_generic.get_arguments();
+    let two_arguments = two_generic.get_arguments();
+
+    return one_generic.get_name() == two_generic.get_name() &&
+      one_arguments.len() == two_arguments.len() &&
+      one_arguments.iter().zip(two_arguments.iter()).all(|(one, two)| equal_tokens(one.clone(), two.clone()));
+  }
+  // Check if both types are literals.
+  else if let (Some(one_literal), Some(two_literal)) = (one.get_literal(), two.get_literal()) {
+    return one_literal.is_equal(&two_literal);
+  }
+  // Check if both types are unions, the members order does not matter.
+  else if let (Some(one_union), Some(two_union)) = (one.get_union(), two.get_union()) {
+    let one_items = one_union.get_items();
+    let two_items = two_union.get_items();
+
+    return one_items.iter().all(|one| two_items.iter().any(|two| equal_tokens(one.clone(), two.clone()))) &&
+      two_items.iter().all(|two| one_items.iter().any(|one| equal_tokens(one.clone(), two.clone())));
+  }
 
   one == two
 }
@@ -66,14 +119,211 @@ pub fn equal_tokens(one: Token, two: Token) -> bool {
   one == two
 }
 
+/// Check if a value of the `value` type can be used where the `target` type is expected.
+///
+/// Hashmaps can have more items than the target (width) and their items can be subtypes (depth),
+/// arrays are covariant and functions are contravariant in the arguments and covariant in the return.
+/// Everything can be assigned to `any` and `unknown` but an `unknown` value must be narrowed first.
+pub fn is_assignable(target: Types, value: Types) -> bool {
+  // Check if the target or the value are dynamic types.
+  if target == Types::ANY || target == Types::UNKNOWN || value == Types::ANY {
+    return true;
+  }
+
+  // Check if the value is an union, all the members must be assignable.
+  if let Some(value_union) = value.get_union() {
+    return value_union.get_items().iter()
+      .all(|item| item.token.get_type().is_some_and(|item| is_assignable(target.clone(), item)));
+  }
+  // Check if the target is an union, the value must be assignable to one of the members.
+  else if let Some(target_union) = target.get_union() {
+    return target_union.get_items().iter()
+      .any(|item| item.token.get_type().is_some_and(|item| is_assignable(item, value.clone())));
+  }
+  // Check if the target is a literal, only the same literal can be assigned.
+  else if target.get_literal().is_some() {
+    return equal_types(target, value);
+  }
+  // Check if the value is a literal, it can be used as its base type.
+  else if let Some(value_literal) = value.get_literal() {
+    return is_assignable_token(Token::new(Box::new(Tokens::TYPE(target)), String::new(), 0, 0), value_literal.get_type());
+  }
+
+  // Check if both types are functions.
+  if let (Some(target_function), Some(value_function)) = (target.get_function(), value.get_function()) {
+    let target_arguments = target_function.get_arguments();
+    let value_arguments = value_function.get_arguments();
+
+    // The value function can ignore the last arguments.
+    if value_arguments.len() > target_arguments.len() {
+      return false;
+    }
+
+    for (index, (_, value_argument)) in value_arguments.iter().enumerate() {
+      if !is_assignable_token(value_argument.clone(), target_arguments[index].1.clone()) {
+        return false;
+      }
+    }
+
+    // Any return value can be ignored when the target returns void.
+    if target_function.get_type().token.get_type() == Some(Types::VOID) {
+      return true;
+    }
+
+    return is_assignable_token(target_function.get_type(), value_function.get_type());
+  }
+  // Check if both types are hashmaps.
+  else if let (Some(target_hashmap), Some(value_hashmap)) = (target.get_hashmap(), value.get_hashmap()) {
+    let target_items = target_hashmap.get_items();
+    let value_items = value_hashmap.get_items();
+
+    for (key, target_item) in target_items.iter() {
+      match value_items.get(key) {
+        Some(value_item) => {
+          // A required item can not be optional in the value.
+          if !is_assignable_token(target_item.clone(), value_item.clone()) ||
+            (!target_hashmap.is_optional(key) && value_hashmap.is_optional(key)) {
+            return false;
+          }
+        },
+        None => {
+          if !target_hashmap.is_optional(key) {
+            return false;
+          }
+        },
+      }
+    }
+
+    // Check if the target has an index signature.
+    if let Some((_, target_index)) = target_hashmap.get_index() {
+      for (key, value_item) in value_items.iter() {
+        if !target_items.contains_key(key) && !is_assignable_token(target_index.clone(), value_item.clone()) {
+          return false;
+        }
+      }
+
+      if let Some((_, value_index)) = value_hashmap.get_index() {
+        return is_assignable_token(target_index, value_index);
+      }
+    }
+
+    return true;
+  }
+  // Check if both types are arrays.
+  else if let (Some(target_array), Some(value_array)) = (target.get_array(), value.get_array()) {
+    return is_assignable_token(target_array.get_type(), value_array.get_type());
+  }
+  // Check if both types are tuples.
+  else if let (Some(target_tuple), Some(value_tuple)) = (target.get_tuple(), value.get_tuple()) {
+    let target_items = target_tuple.get_items();
+    let value_items = value_tuple.get_items();
+
+    return target_items.len() == value_items.len() &&
+      target_items.iter().zip(value_items.iter()).all(|(target, value)| is_assignable_token(target.clone(), value.clone()));
+  }
+  // Check if both types are generics, the collections are invariant but the `any` arguments
+  // (an empty `Map->new()`) can be used as any data type.
+  else if let (Some(target_generic), Some(value_generic)) = (target.get_generic(), value.get_generic()) {
+    let target_arguments = target_generic.get_arguments();
+    let value_arguments = value_generic.get_arguments();
+
+    return target_generic.get_name() == value_generic.get_name() &&
+      target_arguments.len() == value_arguments.len() &&
+      target_arguments.iter().zip(value_arguments.iter()).all(|(target, value)| {
+        value.token.get_type() == Some(Types::ANY) || equal_tokens(target.clone(), value.clone())
+      });
+  }
+  // Check if a tuple is used as an array.
+  else if let (Some(target_array), Some(value_tuple)) = (target.get_array(), value.get_tuple()) {
+    return value_tuple.get_items().iter().all(|item| is_assignable_token(target_array.get_type(), item.clone()));
+  }
+
+  equal_types(target, value)
+}
+
+pub fn is_assignable_token(target: Token, value: Token) -> bool {
+  if target.token.get_type().is_some() && value.token.get_type().is_some() {
+    return is_assignable(target.token.get_type().unwrap(), value.token.get_type().unwrap());
+  }
+
+  equal_tokens(target, value)
+}
+
+/// Same as `is_assignable` but using the interfaces information of the typechecker types.
+pub fn is_assignable_ttypes(target: &TTypes, value: &TTypes) -> bool {
+  if target.is_interface() {
+    return satisfies_interface(target, value).is_ok();
+  }
+
+  is_assignable(target.get_type(), value.get_type())
+}
+
+/// Get the elements data type of an array or a tuple.
+pub fn get_element_type(data_type: &Types) -> Option<Token> {
+  if let Some(array) = data_type.get_array() {
+    return Some(array.get_type());
+  }
+
+  // The elements of a tuple are the union of the items data types (`[string, number]` has
+  // `string | number` elements).
+  if let Some(tuple) = data_type.get_tuple() {
+    let mut items: Vec<Token> = Vec::new();
+
+    for item in tuple.get_items() {
+      if !items.iter().any(|current| equal_tokens(current.clone(), item.clone())) {
+        items.push(item);
+      }
+    }
+
+    if items.len() <= 1 {
+      return items.pop();
+    }
+
+    let values: Vec<String> = items.iter().map(|item| {
+      // The function types are grouped to keep the union out of the return type.
+      if item.token.get_type().is_some_and(|item_type| item_type.get_function().is_some()) {
+        format!("({})", item.value)
+      } else {
+        item.value.clone()
+      }
+    }).collect();
+
+    let token = Token::from_value(&values.join(" | "), items[0].line, items[0].position);
+
+    if token.token.get_type().is_some() {
+      return Some(token);
+    }
+  }
+
+  None
+}
+
+/// Get the values data type of a hashmap or an interface, the values with different data types
+/// are `any`.
+pub fn get_values_type(hashmap: &TTypes) -> String {
+  let mut values: Vec<Token> = hashmap.get_methods().values()
+    .map(|value| Token::from_value(&widen_type(value.clone()).get_value(), 0, 0))
+    .collect();
+
+  // Check if the hashmap has an index signature.
+  if let Some((_, index_type)) = hashmap.get_type().get_hashmap().and_then(|hashmap| hashmap.get_index()) {
+    values.push(index_type);
+  }
+
+  match values.first() {
+    Some(first) if values.iter().all(|value| equal_tokens(value.clone(), first.clone())) => first.value.clone(),
+    _ => String::from("any"),
+  }
+}
+
 pub fn get_ttypes_from_token(
   token: Token,
   token_to_ttype: Token,
 ) -> Option<TTypes> {
   // Get the token data type.
   if let Some(token_type) = token.token.get_type() {
-    // Check if is an array.
-    if token_type.get_array().is_some() {
+    // Check if is an array or a tuple.
+    if token_type.get_array().is_some() || token_type.get_tuple().is_some() {
       return Some(TTypes::new_array(
         token_type,
         token.value,
@@ -102,8 +352,32 @@ pub fn get_ttypes_from_token(
     }
     // Check if is a function.
     else if let Some(function) = token_type.get_function() {
-      println!("Function TType: {:?}\n", function);
-      return None;
+      let mut arguments: Vec<Box<Expressions>> = Vec::new();
+
+      for (key, value) in function.get_arguments().iter() {
+        // Check if is a rest argument (`...values: any[]`).
+        if let Some(key) = key.strip_prefix("...") {
+          arguments.push(Argument::new_box_rest(
+            Token::new(Box::new(Tokens::IDENTIFIER), key.to_string(), 0, 0),
+            value.clone(),
+          ));
+
+          continue;
+        }
+
+        arguments.push(Argument::new_box_full(
+          Token::new(Box::new(Tokens::IDENTIFIER), key.clone(), 0, 0),
+          value.clone(),
+          None,
+        ));
+      }
+
+      return Some(TTypes::new_function(
+        token_type,
+        token.value,
+        token_to_ttype,
+        arguments,
+      ));
     }
 
     return Some(TTypes::new_type(
@@ -115,3 +389,355 @@ pub fn get_ttypes_from_token(
 
   None
 }
+
+/// Get the typechecker type of a data type token, the identifiers are searched as interfaces
+/// or type aliases.
+pub fn resolve_type_token(token: Token, environment: &Environment) -> Result<TTypes, Error> {
+  // Check if the token is an interface or a type alias.
+  if token.token.is_identifier() {
+    return match environment.store.get_type(&token.value) {
+      Some(interface_type) if interface_type.is_interface() => Ok(interface_type),
+      Some(alias_type) if alias_type.is_alias() => {
+        resolve_type_token(Token::from_value(&alias_type.get_value(), token.line, token.position), environment)
+      },
+      Some(_) => Err(Error::from_token(
+        format!("`{}` is not a valid interface.", token.value),
+        token,
+      )),
+      None => Err(Error::from_token(
+        format!("`{}` identifier not found.", token.value),
+        token,
+      )),
+    };
+  }
+
+  // Check if the generic data type is a map or a set.
+  if let Some(generic) = token.token.get_type().and_then(|data_type| data_type.get_generic()) {
+    let parameters = match generic.get_name().as_str() {
+      "Map" => 2,
+      "Set" => 1,
+      _ => {
+        return Err(Error::from_token(
+          format!("`{}` is not a generic data type.", generic.get_name()),
+          token,
+        ));
+      },
+    };
+
+    if generic.get_arguments().len() != parameters {
+      return Err(Error::from_token(
+        format!("`{}` expect `{}` data types, got `{}` instead.", generic.get_name(), parameters, generic.get_arguments().len()),
+        token,
+      ));
+    }
+  }
+
+  match get_ttypes_from_token(token.clone(), token.clone()) {
+    Some(ttype) => Ok(ttype),
+    None => Err(Error::from_token(
+      String::from("is not a valid data type."),
+      token,
+    )),
+  }
+}
+
+/// Get the base data type of the literals (`'read'` is a `string`), used by the operations and
+/// by the mutable variables.
+pub fn widen_type(ttype: TTypes) -> TTypes {
+  let widen_token = |token: &Token| match token.token.get_type().and_then(|data_type| data_type.get_literal()) {
+    Some(literal) => literal.get_type(),
+    None => token.clone(),
+  };
+
+  // Check if is a literal.
+  if let Some(literal) = ttype.get_type().get_literal() {
+    let base = literal.get_type();
+
+    return TTypes::new_type(base.token.get_type().unwrap(), base.value, ttype.get_token());
+  }
+
+  // Check if is an union with members of the same base data type.
+  if let Some(union) = ttype.get_type().get_union() {
+    let items: Vec<Token> = union.get_items().iter().map(widen_token).collect();
+
+    if items.iter().all(|item| item.token.get_type().is_some() && equal_tokens(item.clone(), items[0].clone())) {
+      if let Some(base) = get_ttypes_from_token(items[0].clone(), ttype.get_token()) {
+        return base;
+      }
+    }
+  }
+
+  ttype
+}
+
+/// Get the data type of an optional member (`label?: string` is a `string | null`).
+pub fn nullable_type(ttype: TTypes) -> TTypes {
+  // Check if the data type already accepts null values.
+  let is_null = |token: &Token| token.token.get_type() == Some(Types::NULL);
+
+  if ttype.get_type() == Types::NULL ||
+    ttype.get_type().get_union().is_some_and(|union| union.get_items().iter().any(is_null)) {
+    return ttype;
+  }
+
+  // The function types are grouped to keep `null` out of the return type.
+  let value = if ttype.is_function() {
+    format!("({}) | null", ttype.get_value())
+  } else {
+    format!("{} | null", ttype.get_value())
+  };
+
+  let token = Token::from_value(&value, ttype.get_token().line, ttype.get_token().position);
+
+  get_ttypes_from_token(token, ttype.get_token()).unwrap_or(ttype)
+}
+
+/// Check if a data type has literals, the expressions checked with it keep their literal values.
+pub fn has_literal_types(data_type: &Types) -> bool {
+  match data_type {
+    Types::LITERAL(_) => true,
+    Types::UNION(union) => union.get_items().iter()
+      .any(|item| item.token.get_type().is_some_and(|item| has_literal_types(&item))),
+    _ => false,
+  }
+}
+
+/// Check if a value type has all the members of an interface.
+///
+/// The error contains the first member that does not match, for example:
+/// "missing `label`" or "`size` is string, expected number".
+pub fn satisfies_interface(interface: &TTypes, value: &TTypes) -> Result<(), String> {
+  // Check if the value is dynamic.
+  if value.get_type() == Types::ANY {
+    return Ok(());
+  }
+
+  if !value.is_hashmap() && !value.is_interface() {
+    return Err(format!("`{}` is not a hashmap", value.get_value()));
+  }
+
+  let value_methods = value.get_methods();
+  let interface_hashmap = interface.get_type().get_hashmap();
+  let mut names: Vec<String> = interface.get_names();
+
+  // Hashmap types does not have the members order.
+  if names.is_empty() {
+    names = interface.get_methods().keys().cloned().collect();
+    names.sort();
+  }
+
+  for name in names.iter() {
+    let expected = interface.get_methods().get(name).unwrap().clone();
+
+    let is_optional = interface_hashmap.clone().is_some_and(|hashmap| hashmap.is_optional(name));
+
+    match value_methods.get(name) {
+      // The optional members can be null.
+      Some(actual) if is_optional && actual.get_type() == Types::NULL => {},
+      Some(actual) => {
+        if (expected.is_interface() || expected.is_hashmap()) &&
+          (actual.is_interface() || actual.is_hashmap()) {
+          if let Err(message) = satisfies_interface(&expected, actual) {
+            if let Some(member) = message.strip_prefix("missing `") {
+              return Err(format!("missing `{}.{}", name, member));
+            } else if let Some(member) = message.strip_prefix('`') {
+              return Err(format!("`{}.{}", name, member));
+            }
+
+            return Err(message);
+          }
+
+          continue;
+        }
+
+        if !is_assignable(expected.get_type(), actual.get_type()) {
+          return Err(format!(
+            "`{}` is {}, expected {}",
+            name,
+            actual.get_value(),
+            expected.get_value(),
+          ));
+        }
+      },
+      None => {
+        // Check if the member is optional.
+        if is_optional {
+          continue;
+        }
+
+        return Err(format!("missing `{}`", name));
+      },
+    }
+  }
+
+  // Check the rest of the members with the index signature.
+  if let Some((_, index_type)) = interface_hashmap.and_then(|hashmap| hashmap.get_index()) {
+    let mut keys: Vec<String> = value_methods.keys().cloned().collect();
+
+    keys.sort();
+
+    for key in keys.iter() {
+      if names.contains(key) {
+        continue;
+      }
+
+      let actual = value_methods.get(key).unwrap();
+
+      if !index_type.token.get_type().is_some_and(|data_type| is_assignable(data_type, actual.get_type())) {
+        return Err(format!(
+          "`{}` is {}, expected {}",
+          key,
+          actual.get_value(),
+          index_type.value,
+        ));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Check if an identifier is a type parameter of a standard library signature (`T`, `U`).
+pub fn is_type_parameter(value: &str) -> bool {
+  value.len() == 1 && value.chars().all(|character| character.is_ascii_uppercase())
+}
+
+/// Apply a function to the identifiers of a data type value, the literals are ignored.
+fn map_type_identifiers<F: FnMut(&str) -> String>(value: &str, mut fun: F) -> String {
+  let mut result = String::new();
+  let mut identifier = String::new();
+  let mut quote: Option<char> = None;
+
+  for character in value.chars() {
+    if let Some(quote_character) = quote {
+      if character == quote_character {
+        quote = None;
+      }
+
+      result.push(character);
+      continue;
+    }
+
+    if character.is_alphanumeric() || character == '_' {
+      identifier.push(character);
+      continue;
+    }
+
+    if !identifier.is_empty() {
+      result.push_str(&fun(&identifier));
+      identifier.clear();
+    }
+
+    if character == '\'' || character == '"' {
+      quote = Some(character);
+    }
+
+    result.push(character);
+  }
+
+  if !identifier.is_empty() {
+    result.push_str(&fun(&identifier));
+  }
+
+  result
+}
+
+/// Get the type parameters used by a data type value.
+pub fn get_type_parameters(value: &str) -> Vec<String> {
+  let mut parameters: Vec<String> = Vec::new();
+
+  map_type_identifiers(value, |identifier| {
+    if is_type_parameter(identifier) && !parameters.contains(&identifier.to_string()) {
+      parameters.push(identifier.to_string());
+    }
+
+    identifier.to_string()
+  });
+
+  parameters
+}
+
+/// Replace a type parameter of a data type value, `(item: T) => boolean` with `T = string` is
+/// `(item: string) => boolean`.
+pub fn replace_type_parameter(value: &str, name: &str, data_type: &str) -> String {
+  map_type_identifiers(value, |identifier| {
+    if identifier == name {
+      return data_type.to_string();
+    }
+
+    identifier.to_string()
+  })
+}
+
+/// Replace a type parameter of a function signature, the default values of the arguments are kept.
+pub fn instantiate_function(function: &TTypes, name: &str, data_type: &str) -> TTypes {
+  let value = replace_type_parameter(&function.get_value(), name, data_type);
+  let mut arguments: Vec<Box<Expressions>> = Vec::new();
+
+  for argument in function.get_arguments().iter() {
+    let argument: Argument = argument.get_argument().unwrap();
+    let argument_type: Token = argument.get_type();
+    let argument_type: Token = Token::from_value(
+      &replace_type_parameter(&argument_type.value, name, data_type),
+      argument_type.line,
+      argument_type.position,
+    );
+
+    if argument.is_rest() {
+      arguments.push(Argument::new_box_rest(argument.get_token(), argument_type));
+    } else {
+      arguments.push(Argument::new_box_full(argument.get_token(), argument_type, argument.get_value()));
+    }
+  }
+
+  match Token::from_value(&value, 0, 0).token.get_type() {
+    Some(function_type) => TTypes::new_function(function_type, value, function.get_token(), arguments),
+    None => function.clone(),
+  }
+}
+
+/// Infer the type parameters of an expected data type from the data type of a value, for example
+/// `U` is `string` when `(item: T) => U` receives a `(item: number) => string` callback.
+pub fn infer_type_parameters(expected: &str, value: &str, bindings: &mut HashMap<String, String>) {
+  let expected = expected.trim();
+  let value = value.trim();
+
+  // Check if the expected data type is a type parameter.
+  if is_type_parameter(expected) {
+    if !bindings.contains_key(expected) {
+      bindings.insert(expected.to_string(), value.to_string());
+    }
+
+    return;
+  }
+
+  let expected_type = Token::from_value(expected, 0, 0).token.get_type();
+  let value_type = Token::from_value(value, 0, 0).token.get_type();
+
+  if let (Some(expected_type), Some(value_type)) = (expected_type, value_type) {
+    // Check if both data types are functions.
+    if let (Some(expected_function), Some(value_function)) = (expected_type.get_function(), value_type.get_function()) {
+      for ((_, expected_argument), (_, value_argument)) in expected_function.get_arguments().iter().zip(value_function.get_arguments().iter()) {
+        infer_type_parameters(&expected_argument.value, &value_argument.value, bindings);
+      }
+
+      infer_type_parameters(&expected_function.get_type().value, &value_function.get_type().value, bindings);
+    }
+    // Check if both data types are arrays.
+    else if let (Some(expected_array), Some(value_array)) = (expected_type.get_array(), value_type.get_array()) {
+      infer_type_parameters(&expected_array.get_type().value, &value_array.get_type().value, bindings);
+    }
+    // Check if both data types are tuples.
+    else if let (Some(expected_tuple), Some(value_tuple)) = (expected_type.get_tuple(), value_type.get_tuple()) {
+      for (expected_item, value_item) in expected_tuple.get_items().iter().zip(value_tuple.get_items().iter()) {
+        infer_type_parameters(&expected_item.value, &value_item.value, bindings);
+      }
+    }
+    // Check if both data types are generics.
+    else if let (Some(expected_generic), Some(value_generic)) = (expected_type.get_generic(), value_type.get_generic()) {
+      for (expected_argument, value_argument) in expected_generic.get_arguments().iter().zip(value_generic.get_arguments().iter()) {
+        infer_type_parameters(&expected_argument.value, &value_argument.value, bindings);
+      }
+    }
+  }
+}
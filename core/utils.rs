@@ -1,3 +1,13 @@
+mod digest;
+mod encoding;
+mod path;
+mod regex;
+
+pub use digest::*;
+pub use encoding::*;
+pub use path::*;
+pub use regex::*;
+
 use std::env;
 
 pub fn get_sflyn_path() -> String {
@@ -7,6 +17,33 @@ pub fn get_sflyn_path() -> String {
   }
 }
 
+/// Get the file of an import path, the relative paths (`./utils`) start in the directory of the
+/// current file and the rest are standard modules (`fs`). The paths are normalized as in the
+/// `path` standard module.
+///
+/// ## Example
+/// ```
+/// use sflynlang::utils::get_import_path;
+///
+/// let path = get_import_path("examples/modules", "./utils");
+/// // Returns: "examples/modules/utils.sf"
+/// ```
+pub fn get_import_path(parent_path: &str, path_to: &str) -> String {
+  let file_name = format!("{}.sf", path_to);
+
+  // Check if the path is a standard module.
+  if !path_to.starts_with('.') && !path_to.starts_with('/') {
+    return join_paths(&[&get_sflyn_path(), "std", &file_name]);
+  }
+
+  // Check if the path is absolute.
+  if path_to.starts_with('/') {
+    return normalize_path(&file_name);
+  }
+
+  join_paths(&[parent_path, &file_name])
+}
+
 /// Repeat a character and get the final string.
 /// 
 /// ## Example
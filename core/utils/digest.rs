@@ -0,0 +1,247 @@
+const SHA256_CONSTANTS: [u32; 64] = [
+  0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+  0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+  0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+  0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+  0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+  0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+  0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+  0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const MD5_SHIFTS: [u32; 64] = [
+  7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22,
+  5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20,
+  4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23,
+  6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// Add the Merkle–Damgård padding: a `1` bit, zeros and the length in bits (big endian for the SHA
+/// digests and little endian for MD5).
+fn pad_message(data: &[u8], big_endian: bool) -> Vec<u8> {
+  let mut message = data.to_vec();
+  let length = (data.len() as u64).wrapping_mul(8);
+
+  message.push(0x80);
+
+  while message.len() % 64 != 56 {
+    message.push(0);
+  }
+
+  if big_endian {
+    message.extend_from_slice(&length.to_be_bytes());
+  } else {
+    message.extend_from_slice(&length.to_le_bytes());
+  }
+
+  message
+}
+
+/// Get the SHA-256 digest (32 bytes) of the data.
+///
+/// ## Example
+/// ```
+/// use sflynlang::utils::{encode_hex, sha256};
+///
+/// let digest = encode_hex(&sha256(b"abc"));
+/// // Returns: "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// ```
+pub fn sha256(data: &[u8]) -> Vec<u8> {
+  let mut state: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+  ];
+
+  for block in pad_message(data, true).chunks(64) {
+    let mut words = [0u32; 64];
+
+    for (index, word) in block.chunks(4).enumerate() {
+      words[index] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+    }
+
+    for index in 16..64 {
+      let s0 = words[index - 15].rotate_right(7) ^ words[index - 15].rotate_right(18) ^ (words[index - 15] >> 3);
+      let s1 = words[index - 2].rotate_right(17) ^ words[index - 2].rotate_right(19) ^ (words[index - 2] >> 10);
+
+      words[index] = words[index - 16].wrapping_add(s0).wrapping_add(words[index - 7]).wrapping_add(s1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+
+    for index in 0..64 {
+      let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+      let choice = (e & f) ^ (!e & g);
+      let temp1 = h.wrapping_add(s1).wrapping_add(choice).wrapping_add(SHA256_CONSTANTS[index]).wrapping_add(words[index]);
+      let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+      let majority = (a & b) ^ (a & c) ^ (b & c);
+      let temp2 = s0.wrapping_add(majority);
+
+      h = g;
+      g = f;
+      f = e;
+      e = d.wrapping_add(temp1);
+      d = c;
+      c = b;
+      b = a;
+      a = temp1.wrapping_add(temp2);
+    }
+
+    for (value, new_value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+      *value = value.wrapping_add(new_value);
+    }
+  }
+
+  state.iter().flat_map(|value| value.to_be_bytes()).collect()
+}
+
+/// Get the SHA-1 digest (20 bytes) of the data.
+pub fn sha1(data: &[u8]) -> Vec<u8> {
+  let mut state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+  for block in pad_message(data, true).chunks(64) {
+    let mut words = [0u32; 80];
+
+    for (index, word) in block.chunks(4).enumerate() {
+      words[index] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+    }
+
+    for index in 16..80 {
+      words[index] = (words[index - 3] ^ words[index - 8] ^ words[index - 14] ^ words[index - 16]).rotate_left(1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = state;
+
+    for (index, word) in words.iter().enumerate() {
+      let (function, constant) = match index {
+        0..=19 => ((b & c) | (!b & d), 0x5a827999),
+        20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+        40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+        _ => (b ^ c ^ d, 0xca62c1d6),
+      };
+
+      let temp = a.rotate_left(5).wrapping_add(function).wrapping_add(e).wrapping_add(constant).wrapping_add(*word);
+
+      e = d;
+      d = c;
+      c = b.rotate_left(30);
+      b = a;
+      a = temp;
+    }
+
+    for (value, new_value) in state.iter_mut().zip([a, b, c, d, e]) {
+      *value = value.wrapping_add(new_value);
+    }
+  }
+
+  state.iter().flat_map(|value| value.to_be_bytes()).collect()
+}
+
+/// Get the MD5 digest (16 bytes) of the data, it is only useful for checksums.
+pub fn md5(data: &[u8]) -> Vec<u8> {
+  let mut state: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+  // The constants are the integer part of `abs(sin(i + 1)) * 2^32`.
+  let constants: Vec<u32> = (0..64).map(|index| ((index as f64 + 1.0).sin().abs() * 4_294_967_296.0) as u32).collect();
+
+  for block in pad_message(data, false).chunks(64) {
+    let mut words = [0u32; 16];
+
+    for (index, word) in block.chunks(4).enumerate() {
+      words[index] = u32::from_le_bytes([word[0], word[1], word[2], word[3]]);
+    }
+
+    let [mut a, mut b, mut c, mut d] = state;
+
+    for index in 0..64 {
+      let (function, word) = match index {
+        0..=15 => ((b & c) | (!b & d), index),
+        16..=31 => ((d & b) | (!d & c), (5 * index + 1) % 16),
+        32..=47 => (b ^ c ^ d, (3 * index + 5) % 16),
+        _ => (c ^ (b | !d), (7 * index) % 16),
+      };
+
+      let temp = d;
+
+      d = c;
+      c = b;
+      b = b.wrapping_add(
+        a.wrapping_add(function).wrapping_add(constants[index]).wrapping_add(words[word]).rotate_left(MD5_SHIFTS[index])
+      );
+      a = temp;
+    }
+
+    for (value, new_value) in state.iter_mut().zip([a, b, c, d]) {
+      *value = value.wrapping_add(new_value);
+    }
+  }
+
+  state.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+/// Get the CRC-32 checksum (IEEE) of the data.
+///
+/// ## Example
+/// ```
+/// use sflynlang::utils::crc32;
+///
+/// let checksum = crc32(b"123456789");
+/// // Returns: 0xcbf43926
+/// ```
+pub fn crc32(data: &[u8]) -> u32 {
+  let mut crc: u32 = 0xffffffff;
+
+  for byte in data.iter() {
+    crc ^= *byte as u32;
+
+    for _ in 0..8 {
+      crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 };
+    }
+  }
+
+  !crc
+}
+
+#[cfg(test)]
+use super::encode_hex;
+
+#[test]
+fn sha256_vectors() {
+  assert_eq!(encode_hex(&sha256(b"")), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+  assert_eq!(encode_hex(&sha256(b"abc")), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+  assert_eq!(
+    encode_hex(&sha256(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+    "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1",
+  );
+}
+
+#[test]
+fn sha256_block_boundaries() {
+  assert_eq!(encode_hex(&sha256(&[b'a'; 55])), "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318");
+  assert_eq!(encode_hex(&sha256(&[b'a'; 56])), "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a");
+  assert_eq!(encode_hex(&sha256(&[b'a'; 64])), "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb");
+}
+
+#[test]
+fn sha1_vectors() {
+  assert_eq!(encode_hex(&sha1(b"")), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+  assert_eq!(encode_hex(&sha1(b"abc")), "a9993e364706816aba3e25717850c26c9cd0d89d");
+  assert_eq!(encode_hex(&sha1(&[b'a'; 55])), "c1c8bbdc22796e28c0e15163d20899b65621d65a");
+  assert_eq!(encode_hex(&sha1(&[b'a'; 56])), "c2db330f6083854c99d4b5bfb6e8f29f201be699");
+  assert_eq!(encode_hex(&sha1(&[b'a'; 64])), "0098ba824b5c16427bd7a1122a5a442a25ec644d");
+}
+
+#[test]
+fn md5_vectors() {
+  assert_eq!(encode_hex(&md5(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+  assert_eq!(encode_hex(&md5(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+  assert_eq!(encode_hex(&md5(&[b'a'; 55])), "ef1772b6dff9a122358552954ad0df65");
+  assert_eq!(encode_hex(&md5(&[b'a'; 56])), "3b0c8ac703f828b04c6c197006d17218");
+  assert_eq!(encode_hex(&md5(&[b'a'; 64])), "014842d480b571495a4a0363793f7367");
+}
+
+#[test]
+fn crc32_vectors() {
+  assert_eq!(crc32(b""), 0);
+  assert_eq!(crc32(b"123456789"), 0xcbf43926);
+  assert_eq!(crc32(b"The quick brown fox jumps over the lazy dog"), 0x414fa339);
+  assert_eq!(crc32(&[b'a'; 64]), 0x89b46555);
+}
@@ -0,0 +1,134 @@
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encode the bytes in base64, the URL safe alphabet uses `-` and `_` without padding.
+///
+/// ## Example
+/// ```
+/// use sflynlang::utils::encode_base64;
+///
+/// let text = encode_base64(b"Sflyn", false);
+/// // Returns: "U2ZseW4="
+/// ```
+pub fn encode_base64(data: &[u8], url_safe: bool) -> String {
+  let alphabet = if url_safe { BASE64_URL_ALPHABET } else { BASE64_ALPHABET };
+  let mut result = String::new();
+
+  for chunk in data.chunks(3) {
+    let value = ((chunk[0] as u32) << 16) |
+      ((*chunk.get(1).unwrap_or(&0) as u32) << 8) |
+      (*chunk.get(2).unwrap_or(&0) as u32);
+
+    for index in 0..4 {
+      if index <= chunk.len() {
+        result.push(alphabet[((value >> (18 - index * 6)) & 0x3f) as usize] as char);
+      } else if !url_safe {
+        result.push('=');
+      }
+    }
+  }
+
+  result
+}
+
+/// Decode a base64 text with the standard or the URL safe alphabet, the padding is optional.
+pub fn decode_base64(text: &str) -> Result<Vec<u8>, String> {
+  let text = text.trim_end_matches('=');
+  let mut result: Vec<u8> = Vec::new();
+  let mut buffer: u32 = 0;
+  let mut bits: u32 = 0;
+
+  for (index, character) in text.chars().enumerate() {
+    let value = match character {
+      'A'..='Z' => character as u32 - 'A' as u32,
+      'a'..='z' => character as u32 - 'a' as u32 + 26,
+      '0'..='9' => character as u32 - '0' as u32 + 52,
+      '+' | '-' => 62,
+      '/' | '_' => 63,
+      _ => {
+        return Err(format!("`{}` is not a base64 character at position {}", character, index + 1));
+      },
+    };
+
+    buffer = (buffer << 6) | value;
+    bits += 6;
+
+    if bits >= 8 {
+      bits -= 8;
+      result.push(((buffer >> bits) & 0xff) as u8);
+    }
+  }
+
+  // Check if the last character has an incomplete byte.
+  if text.chars().count() % 4 == 1 {
+    return Err(String::from("the base64 text has an incomplete byte"));
+  }
+
+  Ok(result)
+}
+
+/// Encode the bytes with two lowercase hexadecimal digits per byte.
+pub fn encode_hex(data: &[u8]) -> String {
+  data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Decode a hexadecimal text, the digits can be uppercase or lowercase.
+pub fn decode_hex(text: &str) -> Result<Vec<u8>, String> {
+  let digits: Vec<char> = text.chars().collect();
+
+  if !digits.len().is_multiple_of(2) {
+    return Err(String::from("the hexadecimal text must have an even number of digits"));
+  }
+
+  let mut result: Vec<u8> = Vec::new();
+
+  for (index, pair) in digits.chunks(2).enumerate() {
+    let high = pair[0].to_digit(16);
+    let low = pair[1].to_digit(16);
+
+    match (high, low) {
+      (Some(high), Some(low)) => result.push((high * 16 + low) as u8),
+      _ => {
+        return Err(format!("`{}{}` is not a hexadecimal byte at position {}", pair[0], pair[1], index * 2 + 1));
+      },
+    }
+  }
+
+  Ok(result)
+}
+
+#[test]
+fn base64_round_trip() {
+  assert_eq!(encode_base64(b"", false), "");
+  assert_eq!(encode_base64(b"f", false), "Zg==");
+  assert_eq!(encode_base64(b"fo", false), "Zm8=");
+  assert_eq!(encode_base64(b"foo", false), "Zm9v");
+  assert_eq!(encode_base64(&[0xfb, 0xff], false), "+/8=");
+  assert_eq!(encode_base64(&[0xfb, 0xff], true), "-_8");
+
+  for text in ["", "f", "fo", "foo", "foob", "fooba", "foobar"] {
+    assert_eq!(decode_base64(&encode_base64(text.as_bytes(), false)), Ok(text.as_bytes().to_vec()));
+    assert_eq!(decode_base64(&encode_base64(text.as_bytes(), true)), Ok(text.as_bytes().to_vec()));
+  }
+}
+
+#[test]
+fn base64_invalid_text() {
+  assert!(decode_base64("a$b").is_err());
+  assert!(decode_base64("Zm9vY").is_err());
+  assert!(decode_base64("Zm 9v").is_err());
+}
+
+#[test]
+fn hex_round_trip() {
+  assert_eq!(encode_hex(&[0x00, 0x0f, 0xab, 0xff]), "000fabff");
+  assert_eq!(decode_hex("000FabfF"), Ok(vec![0x00, 0x0f, 0xab, 0xff]));
+  assert_eq!(decode_hex(""), Ok(Vec::new()));
+}
+
+#[test]
+fn hex_invalid_text() {
+  assert!(decode_hex("abc").is_err());
+  assert!(decode_hex("zz").is_err());
+  assert!(decode_hex("0g").is_err());
+}
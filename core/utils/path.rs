@@ -0,0 +1,143 @@
+use std::env;
+
+/// Normalize a path with `/` separators, the `.` segments are removed and the `..` segments
+/// remove the previous segment.
+///
+/// ## Example
+/// ```
+/// use sflynlang::utils::normalize_path;
+///
+/// let path = normalize_path("examples/./modules//basic/../fs/");
+/// // Returns: "examples/modules/fs"
+/// ```
+pub fn normalize_path(path: &str) -> String {
+  let is_absolute = path.starts_with('/');
+  let mut segments: Vec<&str> = Vec::new();
+
+  for segment in path.split('/') {
+    match segment {
+      "" | "." => {},
+      ".." => {
+        // Check if the previous segment can be removed, the root does not have a parent.
+        if segments.last().is_some_and(|last| *last != "..") {
+          segments.pop();
+        } else if !is_absolute {
+          segments.push(segment);
+        }
+      },
+      _ => segments.push(segment),
+    }
+  }
+
+  let path = segments.join("/");
+
+  if is_absolute {
+    return format!("/{}", path);
+  } else if path.is_empty() {
+    return String::from(".");
+  }
+
+  path
+}
+
+/// Join the paths and normalize the result, the empty paths are ignored.
+///
+/// ## Example
+/// ```
+/// use sflynlang::utils::join_paths;
+///
+/// let path = join_paths(&["examples/modules", "./utils.sf"]);
+/// // Returns: "examples/modules/utils.sf"
+/// ```
+pub fn join_paths(paths: &[&str]) -> String {
+  let paths: Vec<&str> = paths.iter().filter(|path| !path.is_empty()).copied().collect();
+
+  normalize_path(&paths.join("/"))
+}
+
+/// Get the directory of a path, `.` when the path does not have one.
+pub fn dirname(path: &str) -> String {
+  let trimmed = path.trim_end_matches('/');
+
+  if trimmed.is_empty() {
+    return String::from(if path.starts_with('/') { "/" } else { "." });
+  }
+
+  match trimmed.rfind('/') {
+    Some(index) => {
+      let parent = trimmed[..index].trim_end_matches('/');
+
+      String::from(if parent.is_empty() { "/" } else { parent })
+    },
+    None => String::from("."),
+  }
+}
+
+/// Get the last segment of a path, without the extension when it ends with it.
+pub fn basename(path: &str, extension: &str) -> String {
+  let trimmed = path.trim_end_matches('/');
+  let name = trimmed.rsplit('/').next().unwrap_or_default();
+
+  match name.strip_suffix(extension) {
+    Some(stem) if !extension.is_empty() && !stem.is_empty() => stem.to_string(),
+    _ => name.to_string(),
+  }
+}
+
+/// Get the extension of the last segment of a path with the dot (`.sf`), the hidden files
+/// (`.env`) do not have an extension.
+pub fn extname(path: &str) -> String {
+  let name = basename(path, "");
+
+  match name.rfind('.') {
+    Some(index) if index > 0 => name[index..].to_string(),
+    _ => String::new(),
+  }
+}
+
+/// Resolve the paths from the current directory to an absolute path, an absolute path starts
+/// again from it.
+pub fn resolve_path(paths: &[&str]) -> String {
+  let mut resolved = env::current_dir()
+    .map(|directory| directory.display().to_string())
+    .unwrap_or_else(|_| String::from("/"));
+
+  for path in paths.iter() {
+    if path.starts_with('/') {
+      resolved = path.to_string();
+    } else if !path.is_empty() {
+      resolved = format!("{}/{}", resolved, path);
+    }
+  }
+
+  normalize_path(&resolved)
+}
+
+/// Get the relative path from a path to another path, `.` when both are the same.
+///
+/// ## Example
+/// ```
+/// use sflynlang::utils::relative_path;
+///
+/// let path = relative_path("/home/sflyn/examples", "/home/sflyn/std/fs.sf");
+/// // Returns: "../std/fs.sf"
+/// ```
+pub fn relative_path(from: &str, to: &str) -> String {
+  let from = resolve_path(&[from]);
+  let to = resolve_path(&[to]);
+
+  let from: Vec<&str> = from.split('/').filter(|segment| !segment.is_empty()).collect();
+  let to: Vec<&str> = to.split('/').filter(|segment| !segment.is_empty()).collect();
+
+  let common = from.iter().zip(to.iter()).take_while(|(from, to)| from == to).count();
+
+  let mut segments: Vec<&str> = vec![".."; from.len() - common];
+
+  segments.extend(to[common..].iter());
+
+  if segments.is_empty() {
+    return String::from(".");
+  }
+
+  segments.join("/")
+}
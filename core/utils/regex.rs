@@ -0,0 +1,964 @@
+/// Capture positions of a match (in characters), the first one is the whole match.
+pub type Captures = Vec<Option<(usize, usize)>>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ClassItem {
+  Range(char, char),
+  Digit(bool),  // Negated (`\D`)
+  Word(bool),   // Negated (`\W`)
+  Space(bool),  // Negated (`\S`)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+  Char(char),
+  Any,
+  Class(Vec<ClassItem>, bool),                // Items + Negated
+  Start,
+  End,
+  WordBoundary(bool),                         // `\b` is true and `\B` is false
+  Group(Box<Node>, Option<usize>),            // Node + Capture index
+  Concat(Vec<Node>),
+  Alternation(Vec<Node>),
+  Repeat(Box<Node>, usize, Option<usize>, bool),  // Node + Minimum + Maximum + Greedy
+  Backreference(usize),
+  LookAhead(Box<Node>, bool),                 // Node + Negated
+}
+
+/// Instruction of the compiled pattern, the matcher runs them with an explicit backtrack stack.
+#[derive(Debug, Clone, PartialEq)]
+enum Instruction {
+  Char(Node),                                   // `Node::Char`, `Node::Any` or `Node::Class`
+  RepeatChar(Node, usize, Option<usize>, bool), // Node + Minimum + Maximum + Greedy
+  Assert(Node),                                 // `Node::Start`, `Node::End` or `Node::WordBoundary`
+  Split(usize, usize),                          // Preferred + Alternative
+  Jump(usize),
+  Mark(usize),                                  // Register
+  Progress(usize),                              // Register of the repetition start
+  Capture(usize, usize),                        // Capture index + Register of the group start
+  Backreference(usize),
+  LookAhead(Vec<Instruction>, bool),            // Program + Negated
+  Match,
+}
+
+/// Maximum number of instructions of a pattern, the counted repetitions (`(ab){1000}`) are copies.
+const MAX_INSTRUCTIONS: usize = 100_000;
+
+/// Maximum nesting of the groups of a pattern, the parser and the compiler are recursive.
+const MAX_DEPTH: usize = 128;
+
+/// Regular expression with the JavaScript syntax, the flags are `g` (global), `i` (ignore case),
+/// `m` (multiline) and `s` (the dot matches the new lines).
+///
+/// ## Example
+/// ```
+/// use sflynlang::utils::Regex;
+///
+/// let regex = Regex::new("(\\d+)-(?<name>\\w+)", "").unwrap();
+/// let text: Vec<char> = "id: 42-sflyn".chars().collect();
+///
+/// assert_eq!(regex.find_at(&text, 0).unwrap()[1], Some((4, 6)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regex {
+  source: String,
+  flags: String,
+  program: Vec<Instruction>,
+  registers: usize,
+  groups: usize,
+  names: Vec<(String, usize)>,  // Group name + Capture index
+}
+
+impl Regex {
+  pub fn new(source: &str, flags: &str) -> Result<Regex, String> {
+    let error = |message: String| format!("invalid regular expression `/{}/{}`: {}.", source, flags, message);
+
+    // Check the flags.
+    for (index, flag) in flags.chars().enumerate() {
+      if !"gims".contains(flag) {
+        return Err(error(format!("`{}` is not a valid flag", flag)));
+      } else if flags.chars().take(index).any(|other| other == flag) {
+        return Err(error(format!("the `{}` flag is repeated", flag)));
+      }
+    }
+
+    let mut parser = PatternParser {
+      chars: source.chars().collect(),
+      position: 0,
+      groups: 0,
+      depth: 0,
+      names: Vec::new(),
+      backreferences: Vec::new(),
+    };
+
+    let node = parser.parse_alternation().map_err(error)?;
+
+    // Check if the pattern has an unmatched parentheses.
+    if parser.peek().is_some() {
+      return Err(error(String::from("unmatched `)`")));
+    }
+
+    // Check if the backreferences point to a group.
+    if let Some(index) = parser.backreferences.iter().find(|index| **index > parser.groups) {
+      return Err(error(format!("the `\\{}` group does not exist", index)));
+    }
+
+    let mut program: Vec<Instruction> = Vec::new();
+    let mut registers: usize = 0;
+
+    compile(&node, &mut program, &mut registers).map_err(error)?;
+    program.push(Instruction::Match);
+
+    Ok(Regex {
+      source: source.to_string(),
+      flags: flags.to_string(),
+      program,
+      registers,
+      groups: parser.groups,
+      names: parser.names,
+    })
+  }
+
+  pub fn get_source(&self) -> String {
+    self.source.clone()
+  }
+
+  pub fn get_flags(&self) -> String {
+    self.flags.clone()
+  }
+
+  pub fn get_groups(&self) -> usize {
+    self.groups
+  }
+
+  pub fn get_names(&self) -> Vec<(String, usize)> {
+    self.names.clone()
+  }
+
+  pub fn is_global(&self) -> bool {
+    self.flags.contains('g')
+  }
+
+  /// Find the first match that starts at a position or after it.
+  pub fn find_at(&self, text: &[char], start: usize) -> Option<Captures> {
+    let matcher = Matcher {
+      text,
+      ignore_case: self.flags.contains('i'),
+      multiline: self.flags.contains('m'),
+      dot_all: self.flags.contains('s'),
+    };
+
+    for position in start..=text.len() {
+      let mut captures: Captures = vec![None; self.groups + 1];
+      let mut registers: Vec<usize> = vec![0; self.registers];
+
+      if let Some(end) = matcher.run(&self.program, position, &mut captures, &mut registers) {
+        captures[0] = Some((position, end));
+
+        return Some(captures);
+      }
+    }
+
+    None
+  }
+}
+
+struct PatternParser {
+  chars: Vec<char>,
+  position: usize,
+  groups: usize,
+  depth: usize,
+  names: Vec<(String, usize)>,
+  backreferences: Vec<usize>,
+}
+
+impl PatternParser {
+  fn peek(&self) -> Option<char> {
+    self.chars.get(self.position).cloned()
+  }
+
+  fn next(&mut self) -> Option<char> {
+    let current = self.peek();
+
+    self.position += 1;
+
+    current
+  }
+
+  fn parse_alternation(&mut self) -> Result<Node, String> {
+    let mut alternatives: Vec<Node> = vec![self.parse_concat()?];
+
+    while self.peek() == Some('|') {
+      self.next();
+      alternatives.push(self.parse_concat()?);
+    }
+
+    if alternatives.len() == 1 {
+      return Ok(alternatives.remove(0));
+    }
+
+    Ok(Node::Alternation(alternatives))
+  }
+
+  fn parse_concat(&mut self) -> Result<Node, String> {
+    let mut nodes: Vec<Node> = Vec::new();
+
+    while let Some(current) = self.peek() {
+      if current == '|' || current == ')' {
+        break;
+      }
+
+      let atom = self.parse_atom()?;
+
+      nodes.push(self.parse_quantifier(atom)?);
+    }
+
+    Ok(Node::Concat(nodes))
+  }
+
+  fn parse_atom(&mut self) -> Result<Node, String> {
+    match self.next() {
+      Some('^') => Ok(Node::Start),
+      Some('$') => Ok(Node::End),
+      Some('.') => Ok(Node::Any),
+      Some('(') => self.parse_group(),
+      Some('[') => self.parse_class(),
+      Some('\\') => self.parse_escape(),
+      Some('*' | '+' | '?') => Err(String::from("nothing to repeat")),
+      Some('{') if self.read_bounds().is_some() => Err(String::from("nothing to repeat")),
+      Some(current) => Ok(Node::Char(current)),
+      None => Err(String::from("unexpected end of the pattern")),
+    }
+  }
+
+  fn parse_group(&mut self) -> Result<Node, String> {
+    // Check if the group is nested too deep.
+    if self.depth >= MAX_DEPTH {
+      return Err(format!("the groups are nested more than {} levels", MAX_DEPTH));
+    }
+
+    let mut index: Option<usize> = None;
+    let mut look_ahead: Option<bool> = None;
+
+    if self.peek() == Some('?') {
+      self.next();
+
+      match self.next() {
+        Some(':') => {},
+        Some('=') => look_ahead = Some(false),
+        Some('!') => look_ahead = Some(true),
+        Some('<') => {
+          let mut name = String::new();
+
+          loop {
+            match self.next() {
+              Some('>') => break,
+              Some(current) if current.is_alphanumeric() || current == '_' => name.push(current),
+              _ => {
+                return Err(String::from("invalid group name"));
+              },
+            }
+          }
+
+          if name.is_empty() || self.names.iter().any(|(other, _)| *other == name) {
+            return Err(format!("invalid group name `{}`", name));
+          }
+
+          self.groups += 1;
+          self.names.push((name, self.groups));
+          index = Some(self.groups);
+        },
+        _ => {
+          return Err(String::from("invalid group"));
+        },
+      }
+    } else {
+      self.groups += 1;
+      index = Some(self.groups);
+    }
+
+    self.depth += 1;
+
+    let node = self.parse_alternation()?;
+
+    self.depth -= 1;
+
+    if self.next() != Some(')') {
+      return Err(String::from("missing `)`"));
+    }
+
+    match look_ahead {
+      Some(negated) => Ok(Node::LookAhead(Box::new(node), negated)),
+      None => Ok(Node::Group(Box::new(node), index)),
+    }
+  }
+
+  fn parse_class(&mut self) -> Result<Node, String> {
+    let mut items: Vec<ClassItem> = Vec::new();
+    let negated = self.peek() == Some('^');
+
+    if negated {
+      self.next();
+    }
+
+    loop {
+      let start = match self.next() {
+        Some(']') => break,
+        Some('\\') => match self.parse_class_escape()? {
+          Ok(character) => character,
+          Err(item) => {
+            items.push(item);
+            continue;
+          },
+        },
+        Some(current) => current,
+        None => {
+          return Err(String::from("missing `]`"));
+        },
+      };
+
+      // Check if is a range (`a-z`), the `-` before `]` is a character.
+      if self.peek() == Some('-') && self.chars.get(self.position + 1).is_some_and(|next| *next != ']') {
+        self.next();
+
+        let end = match self.next() {
+          Some('\\') => match self.parse_class_escape()? {
+            Ok(character) => character,
+            Err(_) => {
+              return Err(String::from("invalid character class range"));
+            },
+          },
+          Some(current) => current,
+          None => {
+            return Err(String::from("missing `]`"));
+          },
+        };
+
+        if end < start {
+          return Err(format!("`{}-{}` range out of order", start, end));
+        }
+
+        items.push(ClassItem::Range(start, end));
+      } else {
+        items.push(ClassItem::Range(start, start));
+      }
+    }
+
+    Ok(Node::Class(items, negated))
+  }
+
+  /// Parse an escape of a character class, it is a character or a class item (`\d`).
+  fn parse_class_escape(&mut self) -> Result<Result<char, ClassItem>, String> {
+    match self.peek() {
+      Some('b') => {
+        self.next();
+
+        Ok(Ok('\u{8}'))
+      },
+      _ => match self.parse_escape()? {
+        Node::Char(character) => Ok(Ok(character)),
+        Node::Class(mut items, false) if items.len() == 1 => Ok(Err(items.remove(0))),
+        _ => Err(String::from("invalid escape in a character class")),
+      },
+    }
+  }
+
+  fn parse_escape(&mut self) -> Result<Node, String> {
+    let current = match self.next() {
+      Some(current) => current,
+      None => {
+        return Err(String::from("`\\` at the end of the pattern"));
+      },
+    };
+
+    let node = match current {
+      'd' => Node::Class(vec![ClassItem::Digit(false)], false),
+      'D' => Node::Class(vec![ClassItem::Digit(true)], false),
+      'w' => Node::Class(vec![ClassItem::Word(false)], false),
+      'W' => Node::Class(vec![ClassItem::Word(true)], false),
+      's' => Node::Class(vec![ClassItem::Space(false)], false),
+      'S' => Node::Class(vec![ClassItem::Space(true)], false),
+      'b' => Node::WordBoundary(true),
+      'B' => Node::WordBoundary(false),
+      'n' => Node::Char('\n'),
+      'r' => Node::Char('\r'),
+      't' => Node::Char('\t'),
+      'f' => Node::Char('\u{c}'),
+      'v' => Node::Char('\u{b}'),
+      '0' => Node::Char('\0'),
+      '1'..='9' => {
+        let mut index = current.to_digit(10).unwrap() as usize;
+
+        while let Some(digit) = self.peek().and_then(|next| next.to_digit(10)) {
+          self.next();
+          index = index * 10 + digit as usize;
+        }
+
+        self.backreferences.push(index);
+
+        Node::Backreference(index)
+      },
+      'x' => Node::Char(self.read_hex(2)?),
+      'u' => Node::Char(self.read_hex(4)?),
+      current if current.is_ascii_alphanumeric() => {
+        return Err(format!("`\\{}` is not a valid escape", current));
+      },
+      current => Node::Char(current),
+    };
+
+    Ok(node)
+  }
+
+  fn read_hex(&mut self, digits: usize) -> Result<char, String> {
+    let mut code: u32 = 0;
+
+    for _ in 0..digits {
+      match self.next().and_then(|current| current.to_digit(16)) {
+        Some(digit) => code = code * 16 + digit,
+        None => {
+          return Err(String::from("invalid hexadecimal escape"));
+        },
+      }
+    }
+
+    char::from_u32(code).ok_or_else(|| String::from("invalid hexadecimal escape"))
+  }
+
+  /// Read the bounds of a `{n}`, `{n,}` or `{n,m}` quantifier after the `{`, the position does not
+  /// change when it is not a quantifier.
+  fn read_bounds(&mut self) -> Option<(usize, Option<usize>)> {
+    let start = self.position;
+    let mut content = String::new();
+
+    loop {
+      match self.next() {
+        Some('}') => break,
+        Some(current) if current.is_ascii_digit() || current == ',' => content.push(current),
+        _ => {
+          self.position = start;
+
+          return None;
+        },
+      }
+    }
+
+    let bounds = match content.split_once(',') {
+      Some((min, "")) => min.parse().ok().map(|min| (min, None)),
+      Some((min, max)) => min.parse().ok().zip(max.parse().ok()).map(|(min, max)| (min, Some(max))),
+      None => content.parse().ok().map(|count| (count, Some(count))),
+    };
+
+    if bounds.is_none() {
+      self.position = start;
+    }
+
+    bounds
+  }
+
+  fn parse_quantifier(&mut self, atom: Node) -> Result<Node, String> {
+    let (min, max) = match self.peek() {
+      Some('*') => {
+        self.next();
+
+        (0, None)
+      },
+      Some('+') => {
+        self.next();
+
+        (1, None)
+      },
+      Some('?') => {
+        self.next();
+
+        (0, Some(1))
+      },
+      Some('{') => {
+        self.next();
+
+        match self.read_bounds() {
+          Some(bounds) => bounds,
+          None => {
+            // The `{` is a character.
+            self.position -= 1;
+
+            return Ok(atom);
+          },
+        }
+      },
+      _ => {
+        return Ok(atom);
+      },
+    };
+
+    if let Node::Start | Node::End | Node::WordBoundary(_) | Node::LookAhead(_, _) = atom {
+      return Err(String::from("nothing to repeat"));
+    } else if max.is_some_and(|max| max < min) {
+      return Err(String::from("numbers out of order in the quantifier"));
+    }
+
+    // Check if the quantifier is lazy.
+    let greedy = self.peek() != Some('?');
+
+    if !greedy {
+      self.next();
+    }
+
+    Ok(Node::Repeat(Box::new(atom), min, max, greedy))
+  }
+}
+
+/// Get a new register of the matcher.
+fn new_register(registers: &mut usize) -> usize {
+  *registers += 1;
+  *registers - 1
+}
+
+/// Compile a node to the instructions of the matcher.
+fn compile(node: &Node, program: &mut Vec<Instruction>, registers: &mut usize) -> Result<(), String> {
+  // Check if the pattern is too large.
+  if program.len() > MAX_INSTRUCTIONS {
+    return Err(String::from("the pattern is too large"));
+  }
+
+  match node {
+    Node::Char(_) | Node::Any | Node::Class(_, _) => program.push(Instruction::Char(node.clone())),
+    Node::Start | Node::End | Node::WordBoundary(_) => program.push(Instruction::Assert(node.clone())),
+    Node::Group(node, None) => compile(node, program, registers)?,
+    Node::Group(node, Some(index)) => {
+      let register = new_register(registers);
+
+      program.push(Instruction::Mark(register));
+      compile(node, program, registers)?;
+      program.push(Instruction::Capture(*index, register));
+    },
+    Node::Concat(nodes) => {
+      for node in nodes.iter() {
+        compile(node, program, registers)?;
+      }
+    },
+    Node::Alternation(alternatives) => {
+      let mut jumps: Vec<usize> = Vec::new();
+
+      for (index, alternative) in alternatives.iter().enumerate() {
+        // The last alternative does not have a split.
+        if index + 1 == alternatives.len() {
+          compile(alternative, program, registers)?;
+          break;
+        }
+
+        let split = program.len();
+
+        program.push(Instruction::Split(0, 0));
+        compile(alternative, program, registers)?;
+
+        jumps.push(program.len());
+        program.push(Instruction::Jump(0));
+
+        program[split] = Instruction::Split(split + 1, program.len());
+      }
+
+      for jump in jumps.into_iter() {
+        program[jump] = Instruction::Jump(program.len());
+      }
+    },
+    Node::Repeat(node, min, max, greedy) => {
+      // Check if the node is one character, the repetitions are counted in one instruction.
+      if let Node::Char(_) | Node::Any | Node::Class(_, _) = node.as_ref() {
+        program.push(Instruction::RepeatChar(node.as_ref().clone(), *min, *max, *greedy));
+        return Ok(());
+      }
+
+      for _ in 0..*min {
+        compile(node, program, registers)?;
+      }
+
+      let split_of = |body: usize, exit: usize| {
+        if *greedy { Instruction::Split(body, exit) } else { Instruction::Split(exit, body) }
+      };
+
+      match max {
+        None => {
+          let split = program.len();
+          let register = new_register(registers);
+
+          // The empty repetitions stop the loop.
+          program.push(Instruction::Split(0, 0));
+          program.push(Instruction::Mark(register));
+          compile(node, program, registers)?;
+          program.push(Instruction::Progress(register));
+          program.push(Instruction::Jump(split));
+
+          program[split] = split_of(split + 1, program.len());
+        },
+        Some(max) => {
+          let mut splits: Vec<usize> = Vec::new();
+
+          for _ in *min..*max {
+            let register = new_register(registers);
+
+            splits.push(program.len());
+            program.push(Instruction::Split(0, 0));
+            program.push(Instruction::Mark(register));
+            compile(node, program, registers)?;
+            program.push(Instruction::Progress(register));
+          }
+
+          for split in splits.into_iter() {
+            program[split] = split_of(split + 1, program.len());
+          }
+        },
+      }
+    },
+    Node::Backreference(index) => program.push(Instruction::Backreference(*index)),
+    Node::LookAhead(node, negated) => {
+      let mut look_program: Vec<Instruction> = Vec::new();
+
+      compile(node, &mut look_program, registers)?;
+      look_program.push(Instruction::Match);
+
+      program.push(Instruction::LookAhead(look_program, *negated));
+    },
+  }
+
+  Ok(())
+}
+
+/// Entry of the backtrack stack: a position to retry or a change to undo.
+enum Backtrack {
+  Branch(usize, usize),                     // Instruction + Position
+  Positions(usize, usize, usize),           // Instruction + Position + Last position of a `RepeatChar`
+  Register(usize, usize),                   // Register + Previous value
+  Capture(usize, Option<(usize, usize)>),   // Capture index + Previous value
+}
+
+struct Matcher<'a> {
+  text: &'a [char],
+  ignore_case: bool,
+  multiline: bool,
+  dot_all: bool,
+}
+
+fn is_word(character: char) -> bool {
+  character.is_ascii_alphanumeric() || character == '_'
+}
+
+fn is_line_terminator(character: char) -> bool {
+  matches!(character, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
+
+impl Matcher<'_> {
+  fn equal_chars(&self, one: char, two: char) -> bool {
+    one == two || (self.ignore_case && one.to_lowercase().eq(two.to_lowercase()))
+  }
+
+  fn in_class(&self, items: &[ClassItem], character: char) -> bool {
+    items.iter().any(|item| match item {
+      ClassItem::Range(start, end) => {
+        let in_range = |character: char| *start <= character && character <= *end;
+
+        in_range(character) || (
+          self.ignore_case &&
+          (character.to_lowercase().any(in_range) || character.to_uppercase().any(in_range))
+        )
+      },
+      ClassItem::Digit(negated) => character.is_ascii_digit() != *negated,
+      ClassItem::Word(negated) => is_word(character) != *negated,
+      ClassItem::Space(negated) => (character.is_whitespace() || character == '\u{feff}') != *negated,
+    })
+  }
+
+  /// Check if a node of one character matches a character.
+  fn match_char(&self, node: &Node, character: char) -> bool {
+    match node {
+      Node::Char(expected) => self.equal_chars(*expected, character),
+      Node::Any => self.dot_all || !is_line_terminator(character),
+      Node::Class(items, negated) => self.in_class(items, character) != *negated,
+      _ => false,
+    }
+  }
+
+  /// Check if an assertion node is true at a position.
+  fn match_assertion(&self, node: &Node, position: usize) -> bool {
+    match node {
+      Node::Start => position == 0 || (self.multiline && is_line_terminator(self.text[position - 1])),
+      Node::End => position == self.text.len() || (self.multiline && is_line_terminator(self.text[position])),
+      Node::WordBoundary(expected) => {
+        let before = position > 0 && is_word(self.text[position - 1]);
+        let after = position < self.text.len() && is_word(self.text[position]);
+
+        (before != after) == *expected
+      },
+      _ => false,
+    }
+  }
+
+  /// Run a program from a position and get the end of the first match, the backtracking uses a
+  /// stack instead of recursion so the long inputs do not overflow the call stack.
+  fn run(
+    &self,
+    program: &[Instruction],
+    position: usize,
+    captures: &mut Captures,
+    registers: &mut [usize],
+  ) -> Option<usize> {
+    let mut stack: Vec<Backtrack> = Vec::new();
+    let mut counter: usize = 0;
+    let mut position = position;
+
+    loop {
+      let matched = match &program[counter] {
+        Instruction::Match => {
+          return Some(position);
+        },
+        Instruction::Char(node) => {
+          let matched = position < self.text.len() && self.match_char(node, self.text[position]);
+
+          if matched {
+            position += 1;
+            counter += 1;
+          }
+
+          matched
+        },
+        Instruction::RepeatChar(node, min, max, greedy) => {
+          let start = position;
+          let mut end = position;
+
+          while max.is_none_or(|max| end - start < max) &&
+            end < self.text.len() &&
+            self.match_char(node, self.text[end]) {
+            end += 1;
+          }
+
+          let matched = end - start >= *min;
+
+          if matched {
+            let first = start + min;
+
+            // Try the rest of the positions from the longest (greedy) or the shortest (lazy) one.
+            if *greedy {
+              if end > first {
+                stack.push(Backtrack::Positions(counter + 1, end - 1, first));
+              }
+
+              position = end;
+            } else {
+              if end > first {
+                stack.push(Backtrack::Positions(counter + 1, first + 1, end));
+              }
+
+              position = first;
+            }
+
+            counter += 1;
+          }
+
+          matched
+        },
+        Instruction::Assert(node) => {
+          let matched = self.match_assertion(node, position);
+
+          if matched {
+            counter += 1;
+          }
+
+          matched
+        },
+        Instruction::Split(preferred, alternative) => {
+          stack.push(Backtrack::Branch(*alternative, position));
+          counter = *preferred;
+
+          true
+        },
+        Instruction::Jump(target) => {
+          counter = *target;
+
+          true
+        },
+        Instruction::Mark(register) => {
+          stack.push(Backtrack::Register(*register, registers[*register]));
+          registers[*register] = position;
+          counter += 1;
+
+          true
+        },
+        Instruction::Progress(register) => {
+          let matched = registers[*register] != position;
+
+          if matched {
+            counter += 1;
+          }
+
+          matched
+        },
+        Instruction::Capture(index, register) => {
+          stack.push(Backtrack::Capture(*index, captures[*index]));
+          captures[*index] = Some((registers[*register], position));
+          counter += 1;
+
+          true
+        },
+        Instruction::Backreference(index) => {
+          // The groups without a match are empty.
+          let (start, end) = captures[*index].unwrap_or((0, 0));
+          let length = end - start;
+          let matched = position + length <= self.text.len() &&
+            (0..length).all(|offset| self.equal_chars(self.text[start + offset], self.text[position + offset]));
+
+          if matched {
+            position += length;
+            counter += 1;
+          }
+
+          matched
+        },
+        Instruction::LookAhead(look_program, negated) => {
+          let mut look_captures = captures.clone();
+          let mut look_registers = registers.to_vec();
+          let look_matched = self.run(look_program, position, &mut look_captures, &mut look_registers).is_some();
+          let matched = look_matched != *negated;
+
+          // The captures of a positive look ahead are kept.
+          if matched && !*negated {
+            for (index, capture) in look_captures.into_iter().enumerate() {
+              if capture != captures[index] {
+                stack.push(Backtrack::Capture(index, captures[index]));
+                captures[index] = capture;
+              }
+            }
+          }
+
+          if matched {
+            counter += 1;
+          }
+
+          matched
+        },
+      };
+
+      if matched {
+        continue;
+      }
+
+      // Undo the changes until the last position to retry.
+      loop {
+        match stack.pop() {
+          None => {
+            return None;
+          },
+          Some(Backtrack::Branch(target, retry)) => {
+            counter = target;
+            position = retry;
+            break;
+          },
+          Some(Backtrack::Positions(target, retry, last)) => {
+            if retry > last {
+              stack.push(Backtrack::Positions(target, retry - 1, last));
+            } else if retry < last {
+              stack.push(Backtrack::Positions(target, retry + 1, last));
+            }
+
+            counter = target;
+            position = retry;
+            break;
+          },
+          Some(Backtrack::Register(register, previous)) => registers[register] = previous,
+          Some(Backtrack::Capture(index, previous)) => captures[index] = previous,
+        }
+      }
+    }
+  }
+}
+
+/// Get the captures of the first match as texts, used by the tests.
+#[cfg(test)]
+fn find_texts(pattern: &str, flags: &str, text: &str) -> Option<Vec<Option<String>>> {
+  let text: Vec<char> = text.chars().collect();
+  let captures = Regex::new(pattern, flags).unwrap().find_at(&text, 0)?;
+
+  Some(captures.iter().map(|capture| capture.map(|(start, end)| text[start..end].iter().collect())).collect())
+}
+
+#[cfg(test)]
+fn find_text(pattern: &str, flags: &str, text: &str) -> Option<String> {
+  find_texts(pattern, flags, text).and_then(|captures| captures[0].clone())
+}
+
+#[test]
+fn regex_quantifiers() {
+  assert_eq!(find_text("a+", "", "baaac"), Some(String::from("aaa")));
+  assert_eq!(find_text("ba*c", "", "bc"), Some(String::from("bc")));
+  assert_eq!(find_text("a{2,3}", "", "aaaa"), Some(String::from("aaa")));
+  assert_eq!(find_text("a{2}", "", "a"), None);
+  assert_eq!(find_text("(?:ab){2,}", "", "abababx"), Some(String::from("ababab")));
+  assert_eq!(find_text("colou?r", "", "color"), Some(String::from("color")));
+  assert_eq!(find_text("x{,2}", "", "x{,2}"), Some(String::from("x{,2}")));
+}
+
+#[test]
+fn regex_lazy_quantifiers() {
+  assert_eq!(find_text("a+?", "", "aaa"), Some(String::from("a")));
+  assert_eq!(find_text("<.*?>", "", "<a><b>"), Some(String::from("<a>")));
+  assert_eq!(find_text("(?:ab)*?c", "", "ababc"), Some(String::from("ababc")));
+  assert_eq!(find_text("a{2,4}?", "", "aaaa"), Some(String::from("aa")));
+}
+
+#[test]
+fn regex_groups_and_backreferences() {
+  let captures = find_texts("(\\d+)-(?<name>\\w+)", "", "id: 42-sflyn").unwrap();
+
+  assert_eq!(captures[1], Some(String::from("42")));
+  assert_eq!(captures[2], Some(String::from("sflyn")));
+  assert_eq!(find_text("(\\w+) \\1", "", "say hello hello"), Some(String::from("hello hello")));
+  assert_eq!(find_text("(a)|(b)\\2", "", "bb"), Some(String::from("bb")));
+  assert_eq!(find_texts("(a)|(b)", "", "b").unwrap()[1], None);
+  assert_eq!(find_text("(A)\\1", "i", "aA"), Some(String::from("aA")));
+}
+
+#[test]
+fn regex_look_ahead() {
+  assert_eq!(find_text("\\w+(?=!)", "", "hi there!"), Some(String::from("there")));
+  assert_eq!(find_text("a(?!b)", "", "abac").map(|text| text.len()), Some(1));
+  assert_eq!(find_texts("(?=(\\d+))\\d", "", "123").unwrap()[1], Some(String::from("123")));
+  assert_eq!(find_text("a(?!b)", "", "ab"), None);
+}
+
+#[test]
+fn regex_empty_loops() {
+  assert_eq!(find_text("(a*)*b", "", "aab"), Some(String::from("aab")));
+  assert_eq!(find_text("(a?)+$", "", "aa"), Some(String::from("aa")));
+  assert_eq!(find_text("(?:)*x", "", "x"), Some(String::from("x")));
+  assert_eq!(find_text("(|a)*b", "", "aab"), Some(String::from("aab")));
+}
+
+#[test]
+fn regex_anchors_and_flags() {
+  assert_eq!(find_text("^b", "", "a\nb"), None);
+  assert_eq!(find_text("^b", "m", "a\nb"), Some(String::from("b")));
+  assert_eq!(find_text("a.b", "", "a\nb"), None);
+  assert_eq!(find_text("a.b", "s", "a\nb"), Some(String::from("a\nb")));
+  assert_eq!(find_text("\\bcat\\b", "", "concat cat"), Some(String::from("cat")));
+  assert_eq!(find_text("[^a-c]+", "i", "ABcdE"), Some(String::from("dE")));
+  assert!(Regex::new("a", "gg").is_err());
+  assert!(Regex::new("(a", "").is_err());
+  assert!(Regex::new("\\2(a)", "").is_err());
+}
+
+#[test]
+fn regex_long_inputs() {
+  let text: String = "ab".repeat(2_000);
+
+  assert_eq!(find_text("(ab)*c", "", &text), None);
+  assert_eq!(find_text("(?:a|b)*$", "", &text).map(|text| text.len()), Some(4_000));
+  assert!(Regex::new("(?:ab){1000000}", "").is_err());
+}
+
+#[test]
+fn regex_nested_groups() {
+  let nested = |depth: usize| format!("{}a{}", "(".repeat(depth), ")".repeat(depth));
+
+  assert_eq!(find_text(&nested(MAX_DEPTH), "", "a"), Some(String::from("a")));
+  assert!(Regex::new(&nested(MAX_DEPTH + 1), "").is_err());
+  assert!(Regex::new(&nested(10_000), "").is_err());
+  assert!(Regex::new(&"(?=".repeat(10_000), "").is_err());
+}
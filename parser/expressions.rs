@@ -11,6 +11,7 @@ mod infix;
 mod null;
 mod number;
 mod prefix;
+mod regexp;
 mod string;
 mod suffix;
 mod types;
@@ -28,6 +29,7 @@ pub use infix::*;
 pub use null::*;
 pub use number::*;
 pub use prefix::*;
+pub use regexp::*;
 pub use string::*;
 pub use suffix::*;
 pub use types::*;
@@ -73,6 +75,11 @@ pub fn parse_expression<'a>(
     expression = Ok(StringE::new_box_from_token(current_token.clone()));
   }
 
+  // Parse regular expressions.
+  if current_token.token.is_regexp() {
+    expression = Ok(RegExp::new_box_from_token(current_token.clone()));
+  }
+
   // Parse numbers.
   if current_token.token.is_number() {
     expression = Number::parse(parser);
@@ -93,9 +100,11 @@ pub fn parse_expression<'a>(
   }
 
   // Parse anonymous functions.
-  if current_token.token.expect_keyword(&Keywords::FUNCTION) || (
+  if current_token.token.expect_keyword(&Keywords::FUNCTION) ||
+    current_token.token.expect_keyword(&Keywords::NATIVE) || (
     current_token.token.expect_sign(&Signs::LEFTPARENTHESES) && (
       parser.get_next_token().token.is_identifier() ||
+      parser.get_next_token().token.expect_sign(&Signs::DOTDOTDOT) ||
       parser.get_next_token().token.expect_sign(&Signs::RIGHTPARENTHESES)
     )
   ) {
@@ -30,7 +30,7 @@ impl Expression for AnonymousFunction {
     Self {
       token: Token::new_empty(),
       arguments: Vec::new(),
-      data_type: Token::from_value("any", 0, 0),
+      data_type: Token::new_empty(),
       body: Block::new_box(),
     }
   }
@@ -54,11 +54,17 @@ impl Expression for AnonymousFunction {
       arguments.push(argument.string());
     }
 
-    let function = format!(
-      "({}): {}",
-      arguments.join(", "),
-      self.get_type().value,
-    );
+    let mut function = format!("({})", arguments.join(", "));
+
+    // Check if the return data type is not inferred.
+    if !self.get_type().value.is_empty() {
+      function.push_str(format!(": {}", self.get_type().value).as_str());
+    }
+
+    // Check if the function is native.
+    if self.is_native() {
+      return format!("{} {}", self.get_token().value, function);
+    }
 
     let body = self.get_body().string();
 
@@ -83,6 +89,10 @@ impl AnonymousFunction {
     self.body.clone()
   }
 
+  pub fn is_native(&self) -> bool {
+    self.token.token.expect_keyword(&Keywords::NATIVE)
+  }
+
   pub fn parse<'a>(
     parser: &'a mut Parser,
     standard_library: bool,
@@ -131,10 +141,23 @@ impl AnonymousFunction {
         },
       }
 
+      // Check if the function is native.
+      if function.is_native() {
+        return Ok(Box::new(Expressions::ANONYMOUSFUNCTION(function)));
+      }
+
       // Get the next token.
       parser.next_token();
     }
 
+    // Check if the function is native.
+    if function.is_native() {
+      return Err(Error::from_token(
+        String::from("native functions must declare a return type."),
+        parser.get_current_token(),
+      ));
+    }
+
     // Check if the function token is a left parentheses.
     if function.token.token.expect_sign(&Signs::LEFTPARENTHESES) {
       // Check if the next token is an assign arrow sign.
@@ -20,7 +20,8 @@ use super::{
 pub struct Argument {
   token: Token,
   data_type: Token,
-  value: Option<Box<Expressions>>
+  value: Option<Box<Expressions>>,
+  rest: bool,  // Collects the remaining call arguments in an array (`...values: any[]`)
 }
 
 impl Expression for Argument {
@@ -29,6 +30,7 @@ impl Expression for Argument {
       token: Token::new_empty(),
       data_type: Token::new_empty(),
       value: None,
+      rest: false,
     }
   }
 
@@ -37,6 +39,7 @@ impl Expression for Argument {
       token,
       data_type: Token::new_empty(),
       value: None,
+      rest: false,
     }
   }
 
@@ -46,7 +49,8 @@ impl Expression for Argument {
 
   fn string(&self) -> String {
     let argument = format!(
-      "{}: {}",
+      "{}{}: {}",
+      if self.is_rest() { "..." } else { "" },
       self.get_token().value,
       self.get_type().value,
     );
@@ -68,7 +72,11 @@ impl Argument {
   }
 
   pub fn new_box_full(token: Token, data_type: Token, value: Option<Box<Expressions>>) -> Box<Expressions> {
-    Box::new(Expressions::ARGUMENT(Argument { token, data_type, value }))
+    Box::new(Expressions::ARGUMENT(Argument { token, data_type, value, rest: false }))
+  }
+
+  pub fn new_box_rest(token: Token, data_type: Token) -> Box<Expressions> {
+    Box::new(Expressions::ARGUMENT(Argument { token, data_type, value: None, rest: true }))
   }
 
   pub fn get_type(&self) -> Token {
@@ -79,6 +87,10 @@ impl Argument {
     self.value.clone()
   }
 
+  pub fn is_rest(&self) -> bool {
+    self.rest
+  }
+
   pub fn parse<'a>(
     parser: &'a mut Parser,
     standard_library: bool,
@@ -94,6 +106,17 @@ impl Argument {
 
     let mut has_default = false;
     while !parser.current_token_is(Signs::new(Signs::RIGHTPARENTHESES)) {
+      // Check if the previous argument is a rest argument.
+      if arguments.last().and_then(|argument| argument.get_argument()).is_some_and(|argument| argument.is_rest()) {
+        return Err(Error::from_token(
+          String::from("the rest argument must be the last argument."),
+          parser.get_next_token(),
+        ));
+      }
+
+      // Check if the next token is a rest sign.
+      let rest = parser.expect_token(Signs::new(Signs::DOTDOTDOT));
+
       // Check if the next token is an identifier.
       if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
         return Err(Error::from_token(
@@ -104,6 +127,8 @@ impl Argument {
 
       let mut argument: Argument = Expression::from_token(parser.get_current_token());
 
+      argument.rest = rest;
+
       // Check if the next token is a colon.
       if !parser.expect_token(Signs::new(Signs::COLON)) {
         return Err(Error::from_token(
@@ -130,7 +155,12 @@ impl Argument {
       }
 
       // Check if the next token is an assign sign.
-      if parser.expect_token(Signs::new(Signs::ASSIGN)) {
+      if parser.next_token_is(Signs::new(Signs::ASSIGN)) && argument.is_rest() {
+        return Err(Error::from_token(
+          String::from("the rest argument can not have a default value."),
+          parser.get_next_token(),
+        ));
+      } else if parser.expect_token(Signs::new(Signs::ASSIGN)) {
         has_default = true;
 
         // Get the next token.
@@ -145,7 +175,7 @@ impl Argument {
             return Err(error);
           },
         }
-      } else if has_default {
+      } else if has_default && !argument.is_rest() {
         return Err(Error::from_token(
           String::from("the argument must has a default value."),
           parser.get_next_token(),
@@ -142,19 +142,19 @@ impl ArrayIndex {
   pub fn parse_index<'a>(
     index: Box<Expressions>,
   ) -> Result<(), Error> {
-    // Check if the expression has a dot.
-    if index.clone().string().contains('.') {
+    // Check if the number has a dot.
+    if index.get_number().is_some() && index.clone().string().contains('.') {
       return Err(Error::from_token(
         String::from("the index value can not contains a dot."),
         index.clone().token(),
       ));
     }
 
-    // Get the prefix expression.
+    // Check if the negative number has a dot, the negative indexes start from the end.
     if let Some(prefix) = index.clone().get_prefix() {
-      if prefix.get_right().get_number().unwrap().get_value() != 1.0 {
+      if prefix.get_right().get_number().is_some() && prefix.get_right().string().contains('.') {
         return Err(Error::from_token(
-          String::from("the index can not be other than '-1' or a positive number."),
+          String::from("the index value can not contains a dot."),
           index.clone().token(),
         ));
       }
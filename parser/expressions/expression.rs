@@ -31,6 +31,7 @@ pub enum Expressions {
   NULL(Null),
   NUMBER(Number),
   PREFIX(Prefix),
+  REGEXP(RegExp),
   STRING(StringE),
   SUFFIX(Suffix),
 }
@@ -126,6 +127,13 @@ impl Expressions {
     }
   }
 
+  pub fn get_regexp(&self) -> Option<RegExp> {
+    match self {
+      Expressions::REGEXP(regexp) => Some(regexp.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_string(&self) -> Option<StringE> {
     match self {
       Expressions::STRING(string) => Some(string.clone()),
@@ -155,6 +163,7 @@ impl Expressions {
       Expressions::NULL(null) => null.get_token(),
       Expressions::NUMBER(number) => number.get_token(),
       Expressions::PREFIX(prefix) => prefix.get_token(),
+      Expressions::REGEXP(regexp) => regexp.get_token(),
       Expressions::STRING(string) => string.get_token(),
       Expressions::SUFFIX(suffix) => suffix.get_token(),
     }
@@ -175,6 +184,7 @@ impl Expressions {
       Expressions::NULL(null) => null.string(),
       Expressions::NUMBER(number) => number.string(),
       Expressions::PREFIX(prefix) => prefix.string(),
+      Expressions::REGEXP(regexp) => regexp.string(),
       Expressions::STRING(string) => string.string(),
       Expressions::SUFFIX(suffix) => suffix.string(),
     }
@@ -9,8 +9,6 @@ use crate::{
   },
 };
 
-use std::collections::HashMap as HashMapSTD;
-
 use super::{
   Expression,
   Expressions,
@@ -20,14 +18,14 @@ use super::{
 #[derive(Debug, Clone, PartialEq)]
 pub struct HashMap {
   token: Token,
-  items: HashMapSTD<String, Box<Expressions>>,
+  items: Vec<(String, Box<Expressions>)>,   // Items in insertion order
 }
 
 impl Expression for HashMap {
   fn new() -> Self {
     Self {
       token: Token::new_empty(),
-      items: HashMapSTD::new(),
+      items: Vec::new(),
     }
   }
 
@@ -55,7 +53,7 @@ impl Expression for HashMap {
 }
 
 impl HashMap {
-  pub fn get_items(&self) -> HashMapSTD<String, Box<Expressions>> {
+  pub fn get_items(&self) -> Vec<(String, Box<Expressions>)> {
     self.items.clone()
   }
 
@@ -89,7 +87,7 @@ impl HashMap {
       }
 
       // Check if the key already exists in the HashMap.
-      if hashmap.items.clone().contains_key(&key.clone()) {
+      if hashmap.items.iter().any(|(item_key, _)| item_key == &key) {
         return Err(Error::from_token(
           String::from("the hashmap key is already in use."),
           parser.get_current_token(),
@@ -110,7 +108,7 @@ impl HashMap {
       // Parse expression.
       match parse_expression(parser, Precedence::LOWEST, standard_library, with_this) {
         Ok(expression) => {
-          hashmap.items.insert(key, expression);
+          hashmap.items.push((key, expression));
         },
         Err(error) => {
           return Err(error);
@@ -153,10 +153,16 @@ impl Infix {
     // Get the next token.
     parser.next_token();
 
-    if infix.is_type() {
+    if infix.is_type() || infix.is_alias() {
       // Parse the right type.
       match parse_type(parser) {
         Ok(right_type) => {
+          // Check if the alias is an identifier.
+          if infix.is_alias() && right_type.token.is_identifier() {
+            // Set the right expression.
+            infix.right = Some(Identifier::new_box_from_token(right_type.clone()));
+          }
+
           // Set the right type.
           infix.right_type = Some(right_type);
         },
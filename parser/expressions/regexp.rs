@@ -0,0 +1,63 @@
+use crate::tokens::Token;
+
+use super::{
+  Expression,
+  Expressions,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegExp {
+  token: Token,
+  pattern: String,
+  flags: String,
+}
+
+impl Expression for RegExp {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      pattern: String::new(),
+      flags: String::new(),
+    }
+  }
+
+  fn from_token(token: Token) -> Self {
+    // Split the `/pattern/flags` value in the last slash.
+    let (pattern, flags) = match token.value.rfind('/') {
+      Some(index) if index > 0 => (token.value[1..index].to_string(), token.value[index + 1..].to_string()),
+      _ => (String::new(), String::new()),
+    };
+
+    Self {
+      token,
+      pattern,
+      flags,
+    }
+  }
+
+  fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  fn string(&self) -> String {
+    format!("/{}/{}", self.get_pattern(), self.get_flags())
+  }
+}
+
+impl RegExp {
+  pub fn new_box() -> Box<Expressions> {
+    Box::new(Expressions::REGEXP(Expression::new()))
+  }
+
+  pub fn new_box_from_token(token: Token) -> Box<Expressions> {
+    Box::new(Expressions::REGEXP(Expression::from_token(token)))
+  }
+
+  pub fn get_pattern(&self) -> String {
+    self.pattern.clone()
+  }
+
+  pub fn get_flags(&self) -> String {
+    self.flags.clone()
+  }
+}
@@ -2,10 +2,87 @@ use crate::Parser;
 use crate::tokens::*;
 
 pub fn parse_type<'a>(parser: &'a mut Parser) -> Result<Token, ()> {
+  let token: Token = parse_single_type(parser)?;
+
+  // Parse unions.
+  if parser.next_token_is(Signs::new(Signs::BITOR)) {
+    return Union::parse(parser, token);
+  }
+
+  Ok(token)
+}
+
+/// Check if the current parentheses group a type (`(string | null)[]`) instead of starting the
+/// arguments of a function type (`(value: string) => void`).
+fn is_grouped_type(parser: &mut Parser) -> bool {
+  if !parser.current_token_is(Signs::new(Signs::LEFTPARENTHESES)) ||
+    parser.next_token_is(Signs::new(Signs::DOTDOTDOT)) ||
+    parser.next_token_is(Signs::new(Signs::RIGHTPARENTHESES)) {
+    return false;
+  }
+
+  if !parser.next_token_is(Box::new(Tokens::IDENTIFIER)) {
+    return true;
+  }
+
+  // The function arguments have a colon after the name.
+  let token = parser.lexer.peek_next_token();
+
+  token.token != Signs::new(Signs::COLON) && token.token != Signs::new(Signs::QUESTION)
+}
+
+fn parse_grouped_type(parser: &mut Parser) -> Result<Token, ()> {
+  let left_parentheses = parser.get_current_token();
+
+  // Get the next token.
+  parser.next_token();
+
+  let data_type: Token = parse_type(parser)?;
+
+  if !parser.expect_token(Signs::new(Signs::RIGHTPARENTHESES)) {
+    return Err(());
+  }
+
+  Ok(Token::new(
+    data_type.token,
+    format!("({})", data_type.value),
+    left_parentheses.line,
+    left_parentheses.position,
+  ))
+}
+
+fn parse_single_type(parser: &mut Parser) -> Result<Token, ()> {
   let token: Token =
-    if parser.get_current_token().token.get_type().is_some() ||
+    if is_grouped_type(parser) {
+      match parse_grouped_type(parser) {
+        Ok(token) => token,
+        Err(_) => Token::new_empty(),
+      }
+    } else if parser.current_token_is(Box::new(Tokens::STRING)) ||
+      parser.current_token_is(Box::new(Tokens::NUMBER)) {
+      match Literal::parse(parser) {
+        Ok(token) => token,
+        Err(_) => Token::new_empty(),
+      }
+    } else if parser.get_current_token().token.is_identifier() &&
+      parser.next_token_is(Signs::new(Signs::LESSTHAN)) {
+      match Generic::parse(parser, parser.get_current_token()) {
+        Ok(token) => token,
+        Err(_) => Token::new_empty(),
+      }
+    } else if parser.get_current_token().token.get_type().is_some() ||
       parser.get_current_token().token.is_identifier() {
       parser.get_current_token()
+    } else if parser.current_token_is(Signs::new(Signs::LEFTBRACE)) {
+      match HashMap::parse(parser) {
+        Ok(token) => token,
+        Err(_) => Token::new_empty(),
+      }
+    } else if parser.current_token_is(Signs::new(Signs::LEFTBRACKET)) {
+      match Tuple::parse(parser) {
+        Ok(token) => token,
+        Err(_) => Token::new_empty(),
+      }
     } else {
       match Function::parse(parser) {
         Ok(token) => token,
@@ -15,6 +15,8 @@ pub struct Lexer {
   pub current_character: u8,
 
   pub next_position: usize,
+
+  pub previous_token: Box<Tokens>,
 }
 
 impl Lexer {
@@ -28,6 +30,8 @@ impl Lexer {
       current_character: 0,
 
       next_position: 0,
+
+      previous_token: Box::new(Tokens::ILLEGAL),
     };
 
     lexer.read_next_character();
@@ -151,8 +155,91 @@ impl Lexer {
     }
   }
 
+  /// Read a regular expression (`/pattern/flags`), the `/` inside a character class (`[/]`) does
+  /// not end the pattern.
+  fn read_regexp(&mut self) -> Token {
+    let start_position = self.current_position;
+    let start_line_position = self.current_line_position;
+    let mut in_class = false;
+
+    // Read the first slash.
+    self.read_next_character();
+
+    loop {
+      match self.current_character {
+        // The regular expression is not closed in the line.
+        0 | b'\n' => {
+          return Token::new(
+            Box::new(Tokens::ILLEGAL),
+            self.file.content[start_position..self.current_position].to_string(),
+            self.current_line,
+            start_line_position,
+          );
+        },
+        b'\\' => {
+          self.read_next_character();
+        },
+        b'[' => {
+          in_class = true;
+        },
+        b']' => {
+          in_class = false;
+        },
+        b'/' if !in_class => {
+          break;
+        },
+        _ => {},
+      }
+
+      self.read_next_character();
+    }
+
+    // Read the last slash and the flags.
+    self.read_next_character();
+
+    while self.current_character.is_ascii_alphabetic() {
+      self.read_next_character();
+    }
+
+    Token::new(
+      Box::new(Tokens::REGEXP),
+      self.file.content[start_position..self.current_position].to_string(),
+      self.current_line,
+      start_line_position,
+    )
+  }
+
   /// Read and get the next token.
   pub fn read_next_token(&mut self) -> Token {
+    let token = self.read_token();
+
+    self.previous_token = token.token.clone();
+
+    token
+  }
+
+  /// Get the next token without moving the lexer.
+  pub fn peek_next_token(&mut self) -> Token {
+    let current_line = self.current_line;
+    let current_line_position = self.current_line_position;
+    let current_position = self.current_position;
+    let current_character = self.current_character;
+    let next_position = self.next_position;
+    let previous_token = self.previous_token.clone();
+
+    let token = self.read_token();
+
+    self.current_line = current_line;
+    self.current_line_position = current_line_position;
+    self.current_position = current_position;
+    self.current_character = current_character;
+    self.next_position = next_position;
+    self.previous_token = previous_token;
+
+    token
+  }
+
+  fn read_token(&mut self) -> Token {
     self.skip_whitespace();
     self.skip_comments();
 
@@ -161,12 +248,17 @@ impl Lexer {
       return Token::new(Box::new(Tokens::EOF), String::new(), self.current_line, self.current_line_position);
     }
 
+    // Check if the slash starts a regular expression, after a value it is a division.
+    if self.current_character == b'/' && !self.previous_token.is_value_end() {
+      return self.read_regexp();
+    }
+
     let start_position = self.current_line_position;
     let current_character_str = utils::character_to_str(self.current_character);
     let mut current_token = Token::from_value(current_character_str, self.current_line, start_position);
 
-    // Check if the current token is a string, keyword, identifier or number.
-    if current_token.token.clone().is_illegal() {
+    // Check if the current token is a string, keyword, identifier or number (the digits are literal types).
+    if current_token.token.clone().is_illegal() || current_token.token.get_type().is_some() {
       if current_character_str == "\"" || current_character_str == "'" {
         // Read strings.
         current_token = Token::new(Box::new(Tokens::STRING), self.read_string(self.current_character), self.current_line, start_position);
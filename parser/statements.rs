@@ -9,6 +9,7 @@ mod import;
 mod interface;
 mod return_s;
 mod statement;
+mod type_alias;
 mod variable;
 
 pub use block::*;
@@ -22,6 +23,7 @@ pub use import::*;
 pub use interface::*;
 pub use return_s::*;
 pub use statement::*;
+pub use type_alias::*;
 pub use variable::*;
 
 use super::{
@@ -77,6 +79,11 @@ pub fn parse_statement<'a>(
     return Return::parse(parser, standard_library, with_this);
   }
 
+  // Type alias
+  if TypeAlias::is_type_alias(parser) {
+    return TypeAlias::parse(parser);
+  }
+
   // Variable
   if parser.current_token_is(Keywords::new(Keywords::LET)) ||
     parser.current_token_is(Keywords::new(Keywords::CONST)) {
@@ -28,7 +28,7 @@ impl Statement for Function {
       token: Token::new_empty(),
       name: Token::new_empty(),
       arguments: Vec::new(),
-      data_type: Token::from_value("void", 0, 0),
+      data_type: Token::new_empty(),
       body: Block::new_box(),
     }
   }
@@ -52,11 +52,18 @@ impl Statement for Function {
       arguments.push(argument.string());
     }
 
+    let mut data_type = String::new();
+
+    // Check if the return data type is not inferred.
+    if !self.get_type().value.is_empty() {
+      data_type = format!(": {}", self.get_type().value);
+    }
+
     format!(
-      "function {}({}): {} {}",
+      "function {}({}){} {}",
       self.get_name().value,
       arguments.join(", "),
-      self.get_type().value,
+      data_type,
       self.get_body().string(),
     )
   }
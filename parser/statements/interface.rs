@@ -3,6 +3,8 @@ use crate::{
   parse_type,
   Parser,
   tokens::{
+    HashMap,
+    Keywords,
     Signs,
     Token,
     Tokens,
@@ -18,6 +20,7 @@ use super::{
 pub struct InterfaceMethod {
   token: Token,
   data_type: Token,
+  optional: bool,
 }
 
 impl InterfaceMethod {
@@ -29,10 +32,15 @@ impl InterfaceMethod {
     self.data_type.clone()
   }
 
+  pub fn is_optional(&self) -> bool {
+    self.optional
+  }
+
   pub fn string(&self) -> String {
     format!(
-      "{}: {}",
+      "{}{}: {}",
       self.get_token().value,
+      if self.is_optional() { "?" } else { "" },
       self.get_type().value,
     )
   }
@@ -42,7 +50,9 @@ impl InterfaceMethod {
 pub struct Interface {
   token: Token,
   name: Token,
+  extends: Vec<Token>,
   methods: Vec<InterfaceMethod>,
+  index: Option<(Token, Token)>,
 }
 
 impl Statement for Interface {
@@ -50,8 +60,10 @@ impl Statement for Interface {
     Self {
       token: Token::new_empty(),
       name: Token::new_empty(),
-      
+      extends: Vec::new(),
+
       methods: Vec::new(),
+      index: None,
     }
   }
 
@@ -70,14 +82,25 @@ impl Statement for Interface {
   fn string(&self) -> String {
     let mut methods: Vec<String> = Vec::new();
 
+    if let Some((key_type, value_type)) = self.get_index() {
+      methods.push(format!("[key: {}]: {}", key_type.value, value_type.value));
+    }
+
     for method in self.get_methods().iter() {
       methods.push(method.string());
     }
 
+    let mut extends: Vec<String> = Vec::new();
+
+    for parent in self.get_extends().iter() {
+      extends.push(parent.value.clone());
+    }
+
     format!(
-      "{} {} {{\n {} \n}}",
+      "{} {}{} {{\n {} \n}}",
       self.get_token().value,
       self.get_name().value,
+      if extends.is_empty() { String::new() } else { format!(" extends {}", extends.join(", ")) },
       methods.join(";\n"),
     )
   }
@@ -88,10 +111,18 @@ impl Interface {
     self.name.clone()
   }
 
+  pub fn get_extends(&self) -> Vec<Token> {
+    self.extends.clone()
+  }
+
   pub fn get_methods(&self) -> Vec<InterfaceMethod> {
     self.methods.clone()
   }
 
+  pub fn get_index(&self) -> Option<(Token, Token)> {
+    self.index.clone()
+  }
+
   pub fn parse<'a>(
     parser: &'a mut Parser,
     _standard_library: bool,
@@ -110,6 +141,27 @@ impl Interface {
     // Set the current token as the interface name.
     interface.name = parser.get_current_token();
 
+    // Check if the next token is `extends`.
+    if parser.expect_token(Keywords::new(Keywords::EXTENDS)) {
+      loop {
+        // Check if the next token is an identifier.
+        if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+          return Err(Error::from_token(
+            format!("`{}` is not a valid interface name.", parser.get_next_token().value),
+            parser.get_next_token(),
+          ));
+        }
+
+        // Add the parent interface.
+        interface.extends.push(parser.get_current_token());
+
+        // Check if the next token is a comma.
+        if !parser.expect_token(Signs::new(Signs::COMMA)) {
+          break;
+        }
+      }
+    }
+
     // Check if the next token is a left brace.
     if !parser.expect_token(Signs::new(Signs::LEFTBRACE)) {
       return Err(Error::from_token(
@@ -118,7 +170,46 @@ impl Interface {
       ));
     }
 
+    // Check if the interface is empty.
+    if parser.next_token_is(Signs::new(Signs::RIGHTBRACE)) {
+      // Get the next token.
+      parser.next_token();
+    }
+
     while !parser.current_token_is(Signs::new(Signs::RIGHTBRACE)) {
+      // Check if the next token is an index signature.
+      if parser.next_token_is(Signs::new(Signs::LEFTBRACKET)) {
+        // Get the next token.
+        parser.next_token();
+
+        match HashMap::parse_index(parser) {
+          Ok(index) => {
+            interface.index = Some(index);
+          },
+          Err(_) => {
+            return Err(Error::from_token(
+              format!("`{}` is not a valid index signature.", parser.get_current_token().value),
+              parser.get_current_token(),
+            ));
+          },
+        }
+
+        // Check if the next token is a comma or semicolon.
+        if parser.next_token_is(Signs::new(Signs::COMMA)) ||
+          parser.next_token_is(Signs::new(Signs::SEMICOLON)) {
+          // Get the next token.
+          parser.next_token();
+        }
+
+        // Check if the next token is a right brace.
+        if parser.next_token_is(Signs::new(Signs::RIGHTBRACE)) {
+          // Get the next token.
+          parser.next_token();
+        }
+
+        continue;
+      }
+
       // Check if the next token is an identifier.
       if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
         return Err(Error::from_token(
@@ -129,6 +220,9 @@ impl Interface {
 
       let key = parser.get_current_token();
 
+      // Check if the next token is a question sign.
+      let optional = parser.expect_token(Signs::new(Signs::QUESTION));
+
       // Check if the next token is a colon.
       if !parser.expect_token(Signs::new(Signs::COLON)) {
         return Err(Error::from_token(
@@ -147,6 +241,7 @@ impl Interface {
           interface.methods.push(InterfaceMethod {
             token: key,
             data_type,
+            optional,
           });
         },
         Err(_) => {
@@ -164,8 +259,11 @@ impl Interface {
         parser.next_token();
       }
 
-      // Get the next token.
-      parser.next_token();
+      // Check if the next token is a right brace.
+      if parser.next_token_is(Signs::new(Signs::RIGHTBRACE)) {
+        // Get the next token.
+        parser.next_token();
+      }
     }
 
     Ok(Box::new(Statements::INTERFACE(interface)))
@@ -28,6 +28,7 @@ pub enum Statements {
   IMPORT(Import),
   INTERFACE(Interface),
   RETURN(Return),
+  TYPEALIAS(TypeAlias),
   VARIABLE(Variable),
 }
 
@@ -102,6 +103,13 @@ impl Statements {
     }
   }
 
+  pub fn get_type_alias(&self) -> Option<TypeAlias> {
+    match self {
+      Statements::TYPEALIAS(type_alias) => Some(type_alias.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_variable(&self) -> Option<Variable> {
     match self {
       Statements::VARIABLE(variable) => Some(variable.clone()),
@@ -121,6 +129,7 @@ impl Statements {
       Statements::IMPORT(import) => import.get_token(),
       Statements::INTERFACE(interface) => interface.get_token(),
       Statements::RETURN(return_s) => return_s.get_token(),
+      Statements::TYPEALIAS(type_alias) => type_alias.get_token(),
       Statements::VARIABLE(variable) => variable.get_token(),
     }
   }
@@ -137,6 +146,7 @@ impl Statements {
       Statements::IMPORT(import) => import.string(),
       Statements::INTERFACE(interface) => interface.string(),
       Statements::RETURN(return_s) => return_s.string(),
+      Statements::TYPEALIAS(type_alias) => type_alias.string(),
       Statements::VARIABLE(variable) => variable.string(),
     }
   }
@@ -0,0 +1,113 @@
+use crate::{
+  Error,
+  parse_type,
+  Parser,
+  tokens::{
+    Signs,
+    Token,
+    Tokens,
+  },
+};
+
+use super::{
+  Statement,
+  Statements,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeAlias {
+  token: Token,
+  name: Token,
+  data_type: Token,
+}
+
+impl Statement for TypeAlias {
+  fn new() -> Self {
+    Self {
+      token: Token::new_empty(),
+      name: Token::new_empty(),
+      data_type: Token::new_empty(),
+    }
+  }
+
+  fn from_token(token: Token) -> Self {
+    let mut type_alias: Self = Statement::new();
+
+    type_alias.token = token;
+
+    type_alias
+  }
+
+  fn get_token(&self) -> Token {
+    self.token.clone()
+  }
+
+  fn string(&self) -> String {
+    format!(
+      "{} {} = {};",
+      self.get_token().value,
+      self.get_name().value,
+      self.get_type().value,
+    )
+  }
+}
+
+impl TypeAlias {
+  pub fn get_name(&self) -> Token {
+    self.name.clone()
+  }
+
+  pub fn get_type(&self) -> Token {
+    self.data_type.clone()
+  }
+
+  /// Check if the current token starts a type alias (`type Name = ...`), `type` is not a keyword
+  /// so it can be used as a variable name.
+  pub fn is_type_alias(parser: &mut Parser) -> bool {
+    parser.current_token_is(Box::new(Tokens::IDENTIFIER)) &&
+      parser.get_current_token().value == "type" &&
+      parser.next_token_is(Box::new(Tokens::IDENTIFIER))
+  }
+
+  pub fn parse(parser: &mut Parser) -> Result<Box<Statements>, Error> {
+    let mut type_alias: TypeAlias = Statement::from_token(parser.get_current_token());
+
+    // Get the next token.
+    parser.next_token();
+
+    // Set the type alias name.
+    type_alias.name = parser.get_current_token();
+
+    // Check if the next token is an assign.
+    if !parser.expect_token(Signs::new(Signs::ASSIGN)) {
+      return Err(Error::from_token(
+        format!("expect `=`, got `{}` instead.", parser.get_next_token().value),
+        parser.get_next_token(),
+      ));
+    }
+
+    // Get the next token.
+    parser.next_token();
+
+    // Parse the data type.
+    match parse_type(parser) {
+      Ok(data_type) => {
+        type_alias.data_type = data_type;
+      },
+      Err(_) => {
+        return Err(Error::from_token(
+          format!("`{}` is not a valid data type.", parser.get_current_token().value),
+          parser.get_current_token(),
+        ));
+      },
+    }
+
+    // Check if the next token is a semicolon.
+    if parser.next_token_is(Signs::new(Signs::SEMICOLON)) {
+      // Get the next token.
+      parser.next_token();
+    }
+
+    Ok(Box::new(Statements::TYPEALIAS(type_alias)))
+  }
+}
@@ -17,6 +17,7 @@ use super::{
 pub struct Variable {
   token: Token,
   name: Token,
+  names: Vec<Token>,
   data_type: Token,
   value: Option<Box<Expressions>>,
 }
@@ -26,7 +27,8 @@ impl Statement for Variable {
     Self {
       token: Token::new_empty(),
       name: Token::new_empty(),
-      data_type: Token::from_value("any", 0, 0),
+      names: Vec::new(),
+      data_type: Token::new_empty(),
       value: None,
     }
   }
@@ -56,11 +58,23 @@ impl Statement for Variable {
       }
     }
 
+    let mut name = self.get_name().value;
+
+    if self.is_destructuring() {
+      let names: Vec<String> = self.get_names().iter().map(|name| name.value.clone()).collect();
+
+      name = format!("[{}]", names.join(", "));
+    }
+
     format!(
-      "{} {}: {} = {};",
+      "{} {}{} = {};",
       self.get_token().value,
-      self.get_name().value,
-      self.get_type().value,
+      name,
+      if self.get_type().value.is_empty() {
+        String::new()
+      } else {
+        format!(": {}", self.get_type().value)
+      },
       value,
     )
   }
@@ -75,6 +89,14 @@ impl Variable {
     self.name.clone()
   }
 
+  pub fn get_names(&self) -> Vec<Token> {
+    self.names.clone()
+  }
+
+  pub fn is_destructuring(&self) -> bool {
+    !self.names.is_empty()
+  }
+
   pub fn get_type(&self) -> Token {
     self.data_type.clone()
   }
@@ -90,8 +112,34 @@ impl Variable {
   ) -> Result<Box<Statements>, Error> {
     let mut variable: Variable = Statement::from_token(parser.get_current_token());
 
+    // Check if the next token is a left bracket (Destructuring).
+    if parser.expect_token(Signs::new(Signs::LEFTBRACKET)) {
+      // Set the left bracket as the variable name.
+      variable.name = parser.get_current_token();
+
+      while !parser.current_token_is(Signs::new(Signs::RIGHTBRACKET)) {
+        // Check if the next token is an identifier.
+        if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+          return Err(Error::from_token(
+            format!("`{}` is not a valid variable name.", parser.get_next_token().value),
+            parser.get_next_token(),
+          ));
+        }
+
+        variable.names.push(parser.get_current_token());
+
+        // Check if the next token is a comma or a right bracket.
+        if !parser.expect_token(Signs::new(Signs::COMMA)) &&
+          !parser.expect_token(Signs::new(Signs::RIGHTBRACKET)) {
+          return Err(Error::from_token(
+            format!("expect `,` or `]`, got `{}` instead.", parser.get_next_token().value),
+            parser.get_next_token(),
+          ));
+        }
+      }
+    }
     // Check if the next token is a valid identifier.
-    if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
+    else if !parser.expect_token(Box::new(Tokens::IDENTIFIER)) {
       let mut message = format!("`{}` is not a valid variable name.", parser.get_next_token().value);
 
       if parser.next_token_is(Signs::new(Signs::COLON)) {
@@ -105,7 +153,9 @@ impl Variable {
     }
 
     // Set the variable name.
-    variable.name = parser.get_current_token();
+    if !variable.is_destructuring() {
+      variable.name = parser.get_current_token();
+    }
 
     // Check if the next token is an assign sign.
     if parser.next_token_is(Signs::new(Signs::ASSIGN)) {
@@ -15,6 +15,7 @@ pub enum Tokens {
   STRING,
   IDENTIFIER,
   NUMBER,
+  REGEXP,
 
   KEYWORD(Keywords),
   SIGN(Signs),
@@ -56,6 +57,25 @@ impl Tokens {
     }
   }
 
+  /// Check if the token is a regular expression token (`/pattern/flags`).
+  pub fn is_regexp(&self) -> bool {
+    matches!(self, Tokens::REGEXP)
+  }
+
+  /// Check if the token ends a value, a `/` after it is a division and not a regular expression.
+  pub fn is_value_end(&self) -> bool {
+    match self {
+      Tokens::STRING | Tokens::IDENTIFIER | Tokens::NUMBER | Tokens::REGEXP | Tokens::TYPE(_) => true,
+      Tokens::KEYWORD(keyword) => keyword == &Keywords::TRUE || keyword == &Keywords::FALSE || keyword == &Keywords::THIS,
+      Tokens::SIGN(sign) => sign == &Signs::RIGHTPARENTHESES ||
+        sign == &Signs::RIGHTBRACKET ||
+        sign == &Signs::RIGHTBRACE ||
+        sign == &Signs::PLUSPLUS ||
+        sign == &Signs::MINUSMINUS,
+      _ => false,
+    }
+  }
+
   /// Get the keyword token.
   pub fn get_keyword(&self) -> Option<Keywords> {
     match self {
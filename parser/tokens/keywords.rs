@@ -10,6 +10,7 @@ pub enum Keywords {
   // Function
   FUNCTION,
   RETURN,
+  NATIVE,
 
   // Boolean
   TRUE,
@@ -47,6 +48,7 @@ pub enum Keywords {
   // Others
   DEFAULT,
   INTERFACE,
+  EXTENDS,
   ENUM,
 }
 
@@ -65,6 +67,7 @@ impl Keywords {
       // Function
       "func" => Ok(Keywords::FUNCTION),
       "return" => Ok(Keywords::RETURN),
+      "native" => Ok(Keywords::NATIVE),
 
       // Boolean
       "true" => Ok(Keywords::TRUE),
@@ -102,6 +105,7 @@ impl Keywords {
       // Others
       "default" => Ok(Keywords::DEFAULT),
       "interface" => Ok(Keywords::INTERFACE),
+      "extends" => Ok(Keywords::EXTENDS),
       "enum" => Ok(Keywords::ENUM),
 
       // Default
@@ -52,6 +52,7 @@ pub enum Signs {
 
   // Others
   NOT,
+  QUESTION,
   ARROW,
   AT,
   BITOR,
@@ -115,6 +116,7 @@ impl Signs {
 
       // Others
       "!" => Ok(Signs::NOT),
+      "?" => Ok(Signs::QUESTION),
       "->" => Ok(Signs::ARROW),
       "@" => Ok(Signs::AT),
       "|" => Ok(Signs::BITOR),
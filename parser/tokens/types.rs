@@ -1,10 +1,23 @@
 mod array;
 mod function;
+mod generic;
 mod hashmap;
+mod literal;
+mod tuple;
+mod union;
 
 pub use array::Array;
 pub use function::Function;
+pub use generic::Generic;
 pub use hashmap::HashMap;
+pub use literal::Literal;
+pub use tuple::Tuple;
+pub use union::Union;
+
+use super::{
+  Token,
+  Tokens,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Types {
@@ -13,10 +26,18 @@ pub enum Types {
   NUMBER,
   BOOLEAN,
   VOID,
+  ANY,
+  UNKNOWN,
+  REGEXP,
+  BYTES,
 
   ARRAY(Array),
   FUNCTION(Function),
+  GENERIC(Generic),
   HASHMAP(HashMap),
+  LITERAL(Literal),
+  TUPLE(Tuple),
+  UNION(Union),
 }
 
 impl Types {
@@ -34,6 +55,13 @@ impl Types {
     }
   }
 
+  pub fn get_generic(&self) -> Option<Generic> {
+    match self {
+      Types::GENERIC(generic) => Some(generic.clone()),
+      _ => None,
+    }
+  }
+
   pub fn get_hashmap(&self) -> Option<HashMap> {
     match self {
       Types::HASHMAP(hashmap) => Some(hashmap.clone()),
@@ -41,12 +69,46 @@ impl Types {
     }
   }
 
+  pub fn get_literal(&self) -> Option<Literal> {
+    match self {
+      Types::LITERAL(literal) => Some(literal.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_tuple(&self) -> Option<Tuple> {
+    match self {
+      Types::TUPLE(tuple) => Some(tuple.clone()),
+      _ => None,
+    }
+  }
+
+  pub fn get_union(&self) -> Option<Union> {
+    match self {
+      Types::UNION(union) => Some(union.clone()),
+      _ => None,
+    }
+  }
+
   pub fn from_value(value: &str) -> Result<Types, ()> {
+    // Parse grouped types (`(string | null)`).
+    if let Some(inner) = value.strip_prefix('(').and_then(|value| value.strip_suffix(')')) {
+      // Check if the first parentheses are closed at the end (`(a) => (b)` is a function).
+      if is_balanced(inner) {
+        return Types::from_value(inner);
+      }
+    }
+
     // Parse functions.
     if let Ok(function) = Function::from_value(value) {
       return Ok(Types::FUNCTION(function));
     }
 
+    // Parse unions.
+    if let Ok(union) = Union::from_value(value) {
+      return Ok(Types::UNION(union));
+    }
+
     // Parse array.
     if let Ok(array) = Array::from_value(value) {
       return Ok(Types::ARRAY(array));
@@ -57,15 +119,128 @@ impl Types {
       return Ok(Types::HASHMAP(hashmap));
     }
 
+    // Parse tuples.
+    if let Ok(tuple) = Tuple::from_value(value) {
+      return Ok(Types::TUPLE(tuple));
+    }
+
+    // Parse generics.
+    if let Ok(generic) = Generic::from_value(value) {
+      return Ok(Types::GENERIC(generic));
+    }
+
+    // Parse literals.
+    if let Ok(literal) = Literal::from_value(value) {
+      return Ok(Types::LITERAL(literal));
+    }
+
     match value {
       "null" => Ok(Types::NULL),
       "string" => Ok(Types::STRING),
       "number" => Ok(Types::NUMBER),
       "boolean" => Ok(Types::BOOLEAN),
       "void" => Ok(Types::VOID),
+      "any" => Ok(Types::ANY),
+      "unknown" => Ok(Types::UNKNOWN),
+      "regexp" => Ok(Types::REGEXP),
+      "bytes" => Ok(Types::BYTES),
 
       // Default
       _ => Err(()),
     }
   }
 }
+
+/// Get the token of an item of a type value, the names of the interfaces, the type aliases and
+/// the type parameters are identifiers.
+pub fn item_from_value(value: &str) -> Option<Token> {
+  let token = Token::from_value(value, 0, 0);
+
+  if token.token.get_type().is_some() {
+    return Some(token);
+  }
+
+  let mut characters = value.chars();
+
+  if token.token.is_illegal() &&
+    characters.next().is_some_and(|character| character.is_alphabetic() || character == '_') &&
+    characters.all(|character| character.is_alphanumeric() || character == '_') {
+    return Some(Token::new(Box::new(Tokens::IDENTIFIER), value.to_string(), 0, 0));
+  }
+
+  None
+}
+
+/// Split a type value by a separator ignoring the separators inside of
+/// parentheses, brackets, braces and strings.
+///
+/// ## Example
+/// ```
+/// use sflyn_parser::tokens::split_type_value;
+///
+/// let items = split_type_value("a: (x: number, y: number) => void, b: string", ",");
+/// // Returns: ["a: (x: number, y: number) => void", " b: string"]
+/// ```
+/// Check if the parentheses of a type value are balanced, the literals are skipped.
+fn is_balanced(value: &str) -> bool {
+  let mut depth: usize = 0;
+  let mut quote: Option<char> = None;
+
+  for character in value.chars() {
+    if let Some(quote_character) = quote {
+      if character == quote_character {
+        quote = None;
+      }
+    } else if character == '\'' || character == '"' {
+      quote = Some(character);
+    } else if character == '(' {
+      depth += 1;
+    } else if character == ')' {
+      if depth == 0 {
+        return false;
+      }
+
+      depth -= 1;
+    }
+  }
+
+  depth == 0
+}
+
+pub fn split_type_value(value: &str, separator: &str) -> Vec<String> {
+  let mut parts: Vec<String> = Vec::new();
+  let mut depth: i32 = 0;
+  let mut quote: Option<char> = None;
+  let mut start: usize = 0;
+  let mut index: usize = 0;
+
+  while index < value.len() {
+    let character = value[index..].chars().next().unwrap();
+
+    if let Some(quote_character) = quote {
+      if character == quote_character {
+        quote = None;
+      }
+    } else if character == '\'' || character == '"' {
+      quote = Some(character);
+    } else if character == '(' || character == '[' || character == '{' || character == '<' {
+      depth += 1;
+    } else if character == ')' || character == ']' || character == '}' ||
+      (character == '>' && !value[..index].ends_with('=')) {
+      depth -= 1;
+    } else if depth == 0 && value[index..].starts_with(separator) {
+      parts.push(value[start..index].to_string());
+
+      index += separator.len();
+      start = index;
+
+      continue;
+    }
+
+    index += character.len_utf8();
+  }
+
+  parts.push(value[start..].to_string());
+
+  parts
+}
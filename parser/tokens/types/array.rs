@@ -62,3 +62,15 @@ fn array_from_value_1() {
   assert_eq!(array_2.is_ok(), true);
   assert_eq!(array_2.unwrap(), array);
 }
+
+#[test]
+fn array_from_value_with_grouped_type() {
+  let array = Array::from_value("(string | null)[]");
+
+  assert!(array.is_ok());
+
+  let data_type = array.unwrap().get_type().token.get_type();
+
+  assert_eq!(data_type.and_then(|data_type| data_type.get_union()).map(|union| union.get_items().len()), Some(2));
+  assert!(Types::from_value("(value: number) => (string | null)").is_ok_and(|data_type| data_type.get_function().is_some()));
+}
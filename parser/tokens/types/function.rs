@@ -2,18 +2,18 @@ use crate::{
   parse_type,
   Parser,
   tokens::{
+    item_from_value,
     Signs,
+    split_type_value,
     Token,
     Tokens,
     Types,
   },
 };
 
-use std::collections::HashMap;
-
 #[derive(Debug, Clone, PartialEq)]
 pub struct Function {
-  arguments: HashMap<String, Token>,
+  arguments: Vec<(String, Token)>,
   data_type: Token,
   value: String,
 }
@@ -21,57 +21,62 @@ pub struct Function {
 impl Function {
   pub fn new(data_type: Token, value: String) -> Function {
     Function {
-      arguments: HashMap::new(),
+      arguments: Vec::new(),
       data_type,
       value,
     }
   }
 
   pub fn from_value(value: &str) -> Result<Function, ()> {
-    let parts: Vec<String> = value.split("=>").map(|x| x.to_string()).collect();
+    let parts: Vec<String> = split_type_value(value, "=>");
 
     if !value.starts_with("(") || parts.len() < 2 {
       return Err(());
     }
 
-    let mut function = Function::new(
-      Token::from_value(parts.last().unwrap().trim(), 0, 0),
-      value.to_string(),
-    );
+    // The return data type can be an interface or a type alias.
+    let return_type = parts[1..].join("=>");
+    let return_type = item_from_value(return_type.trim())
+      .unwrap_or_else(|| Token::from_value(return_type.trim(), 0, 0));
+
+    let mut function = Function::new(return_type, value.to_string());
+
+    let arguments = parts[0].trim();
+
+    if !arguments.ends_with(")") {
+      return Err(());
+    }
 
-    let arguments = parts[0..parts.len() - 1].join("=>");
-    let arguments = arguments.trim();
+    let arguments = arguments[1..arguments.len() - 1].trim();
 
-    if arguments != "()" {
+    if !arguments.is_empty() {
       // Parse function arguments.
-      for argument in arguments[1..].split(",") {
-        let argument: Vec<&str> = argument.split(":").collect();
+      for argument in split_type_value(arguments, ",") {
+        let argument: Vec<String> = split_type_value(&argument, ":");
 
         if argument.len() < 2 {
           return Err(());
         }
 
-        let data_type = argument[1..].join(":");
-        let mut data_type = data_type.trim();
-
-        if !data_type.starts_with("(") && data_type.ends_with(")") {
-          data_type = &data_type[..data_type.len() - 1];
-        }
-
-        function.arguments.insert(
+        function.arguments.push((
           argument[0].trim().to_string(),
-          Token::from_value(data_type, 0, 0),
-        );
+          Token::from_value(argument[1..].join(":").trim(), 0, 0),
+        ));
       }
     }
 
     Ok(function)
   }
 
-  pub fn get_arguments(&self) -> HashMap<String, Token> {
+  pub fn get_arguments(&self) -> Vec<(String, Token)> {
     self.arguments.clone()
   }
 
+  /// Check if the last argument collects the remaining arguments (`...values: any[]`).
+  pub fn has_rest(&self) -> bool {
+    self.arguments.last().is_some_and(|(key, _)| key.starts_with("..."))
+  }
+
   pub fn get_type(&self) -> Token {
     self.data_type.clone()
   }
@@ -83,6 +88,7 @@ impl Function {
   pub fn parse<'a>(parser: &'a mut Parser) -> Result<Token, i32> {
     if !parser.current_token_is(Signs::new(Signs::LEFTPARENTHESES)) || (
       !parser.next_token_is(Box::new(Tokens::IDENTIFIER)) &&
+      !parser.next_token_is(Signs::new(Signs::DOTDOTDOT)) &&
       !parser.next_token_is(Signs::new(Signs::RIGHTPARENTHESES))
     ) {
       return Err(0);
@@ -96,12 +102,27 @@ impl Function {
     let mut function = Function::new(Token::new_empty(), String::from("("));
 
     while !parser.current_token_is(Signs::new(Signs::RIGHTPARENTHESES)) {
+      // Check if the previous argument is a rest argument (`...values: any[]`).
+      if function.arguments.last().is_some_and(|(key, _)| key.starts_with("...")) {
+        return Err(1);
+      }
+
+      let mut key = String::new();
+
+      // Check if the current token is a rest sign.
+      if parser.current_token_is(Signs::new(Signs::DOTDOTDOT)) {
+        key.push_str("...");
+
+        // Get the next token.
+        parser.next_token();
+      }
+
       // Check if the next token is an identifier.
       if !parser.current_token_is(Box::new(Tokens::IDENTIFIER)) {
         return Err(1);
       }
 
-      let key = parser.get_current_token().value;
+      key.push_str(parser.get_current_token().value.as_str());
 
       if function.arguments.len() > 0 {
         function.value.push_str(" ");
@@ -122,7 +143,7 @@ impl Function {
       // Parse type.
       match parse_type(parser) {
         Ok(data_type) => {
-          function.arguments.insert(key, data_type.clone());
+          function.arguments.push((key, data_type.clone()));
           function.value.push_str(data_type.value.as_str());
         },
         Err(_) => {
@@ -183,10 +204,49 @@ fn function_from_value() {
     String::from("(name: string) => string"),
   );
 
-  function.arguments.insert(String::from("name"), Token::from_value("string", 0, 0));
+  function.arguments.push((String::from("name"), Token::from_value("string", 0, 0)));
 
   let function_2 = Function::from_value("(name: string) => string");
 
   assert_eq!(function_2.is_ok(), true);
   assert_eq!(function_2.unwrap(), function);
 }
+
+#[test]
+fn function_from_value_keeps_arguments_order() {
+  let function = Function::from_value("(b: number, a: (x: string) => void) => void");
+
+  assert!(function.is_ok());
+
+  let arguments = function.unwrap().get_arguments();
+
+  assert_eq!(arguments.len(), 2);
+  assert_eq!(arguments[0].0, "b");
+  assert_eq!(arguments[1].0, "a");
+  assert_eq!(arguments[1].1.value, "(x: string) => void");
+}
+
+#[test]
+fn function_from_value_with_rest_argument() {
+  let function = Function::from_value("(template: string, ...values: any[]) => string");
+
+  assert!(function.is_ok());
+
+  let function = function.unwrap();
+
+  assert!(function.has_rest());
+  assert_eq!(function.get_arguments()[1].0, "...values");
+  assert_eq!(function.get_arguments()[1].1.value, "any[]");
+}
+
+#[test]
+fn function_from_value_with_interface_return() {
+  let function = Function::from_value("(offset: number) => DateTime");
+
+  assert!(function.is_ok());
+
+  let return_type = function.unwrap().get_type();
+
+  assert!(return_type.token.is_identifier());
+  assert_eq!(return_type.value, "DateTime");
+}
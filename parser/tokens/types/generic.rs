@@ -0,0 +1,116 @@
+use crate::{
+  parse_type,
+  Parser,
+  tokens::{
+    Signs,
+    item_from_value,
+    split_type_value,
+    Token,
+    Tokens,
+    Types,
+  },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generic {
+  name: String,
+  arguments: Vec<Token>,
+  value: String,
+}
+
+impl Generic {
+  pub fn new(name: String, arguments: Vec<Token>, value: String) -> Generic {
+    Generic { name, arguments, value }
+  }
+
+  pub fn from_value(value: &str) -> Result<Generic, ()> {
+    let start = match value.find('<') {
+      Some(start) => start,
+      None => {
+        return Err(());
+      },
+    };
+
+    let name = value[..start].trim();
+
+    if name.is_empty() || !value.ends_with(">") ||
+      !name.chars().all(|character| character.is_alphanumeric() || character == '_') {
+      return Err(());
+    }
+
+    let mut generic = Generic::new(name.to_string(), Vec::new(), value.to_string());
+
+    for argument in split_type_value(&value[start + 1..value.len() - 1], ",") {
+      let token = match item_from_value(argument.trim()) {
+        Some(token) => token,
+        None => {
+          return Err(());
+        },
+      };
+
+      generic.arguments.push(token);
+    }
+
+    Ok(generic)
+  }
+
+  pub fn get_name(&self) -> String {
+    self.name.clone()
+  }
+
+  pub fn get_arguments(&self) -> Vec<Token> {
+    self.arguments.clone()
+  }
+
+  pub fn get_value(&self) -> String {
+    self.value.clone()
+  }
+
+  pub fn parse(parser: &mut Parser, token: Token) -> Result<Token, ()> {
+    // Check if the next token is a less than sign.
+    if !parser.expect_token(Signs::new(Signs::LESSTHAN)) {
+      return Err(());
+    }
+
+    let mut generic = Generic::new(token.value.clone(), Vec::new(), String::new());
+
+    while !parser.current_token_is(Signs::new(Signs::GREATERTHAN)) {
+      // Get the next token.
+      parser.next_token();
+
+      let data_type: Token = parse_type(parser)?;
+
+      generic.arguments.push(data_type);
+
+      // Check if the next token is a comma.
+      if !parser.expect_token(Signs::new(Signs::COMMA)) {
+        // Check if the next token is a greater than sign.
+        if !parser.expect_token(Signs::new(Signs::GREATERTHAN)) {
+          return Err(());
+        }
+      }
+    }
+
+    let values: Vec<String> = generic.arguments.iter().map(|argument| argument.value.clone()).collect();
+
+    generic.value = format!("{}<{}>", generic.name, values.join(", "));
+
+    Ok(Token::new(
+      Box::new(Tokens::TYPE(Types::GENERIC(generic.clone()))),
+      generic.value,
+      token.line,
+      token.position,
+    ))
+  }
+}
+
+#[test]
+fn generic_from_value() {
+  let generic = Generic::from_value("Map<string, [number, boolean]>");
+
+  assert!(generic.is_ok());
+  assert_eq!(generic.clone().unwrap().get_name(), "Map");
+  assert_eq!(generic.clone().unwrap().get_arguments().len(), 2);
+  assert_eq!(generic.unwrap().get_arguments()[1].value, "[number, boolean]");
+  assert!(Generic::from_value("Set<number>[]").is_err());
+}
@@ -3,6 +3,7 @@ use crate::{
   Parser,
   tokens::{
     Signs,
+    split_type_value,
     Token,
     Tokens,
     Types,
@@ -14,6 +15,8 @@ use std::collections::HashMap as HashMapSTD;
 #[derive(Debug, Clone, PartialEq)]
 pub struct HashMap {
   items: HashMapSTD<String, Token>,
+  optionals: Vec<String>,
+  index: Option<(Token, Token)>,
   value: String,
 }
 
@@ -21,29 +24,54 @@ impl HashMap {
   pub fn new(value: String) -> HashMap {
     HashMap {
       items: HashMapSTD::new(),
+      optionals: Vec::new(),
+      index: None,
       value,
     }
   }
 
   pub fn from_value(value: &str) -> Result<HashMap, ()> {
-    if !value.starts_with("{") && !value.ends_with("}") {
+    if !value.starts_with("{") || !value.ends_with("}") {
       return Err(());
     }
 
     let mut hashmap = HashMap::new(value.to_string());
-    let new_value: &str = &value[1..value.len() - 1];
+    let new_value: &str = value[1..value.len() - 1].trim();
 
-    for item in new_value.split(",") {
-      let item: Vec<&str> = item.split(":").collect();
+    if new_value.is_empty() {
+      return Ok(hashmap);
+    }
+
+    for item in split_type_value(new_value, ",") {
+      let item: Vec<String> = split_type_value(&item, ":");
 
       if item.len() < 2 {
         return Err(());
       }
 
-      hashmap.items.insert(
-        item[0].trim().to_string(),
-        Token::from_value(item[1..].join(":").trim(), 0, 0),
-      );
+      let key = item[0].trim();
+      let data_type = Token::from_value(item[1..].join(":").trim(), 0, 0);
+
+      // Check if the item is an index signature.
+      if key.starts_with('[') && key.ends_with(']') {
+        let index: Vec<String> = split_type_value(&key[1..key.len() - 1], ":");
+
+        if index.len() != 2 {
+          return Err(());
+        }
+
+        hashmap.index = Some((Token::from_value(index[1].trim(), 0, 0), data_type));
+        continue;
+      }
+
+      // Check if the item is optional.
+      if let Some(key) = key.strip_suffix('?') {
+        hashmap.optionals.push(key.to_string());
+        hashmap.items.insert(key.to_string(), data_type);
+        continue;
+      }
+
+      hashmap.items.insert(key.to_string(), data_type);
     }
 
     Ok(hashmap)
@@ -53,6 +81,18 @@ impl HashMap {
     self.items.clone()
   }
 
+  pub fn get_optionals(&self) -> Vec<String> {
+    self.optionals.clone()
+  }
+
+  pub fn is_optional(&self, key: &str) -> bool {
+    self.optionals.contains(&key.to_string())
+  }
+
+  pub fn get_index(&self) -> Option<(Token, Token)> {
+    self.index.clone()
+  }
+
   pub fn get_value(&self) -> String {
     self.value.clone()
   }
@@ -70,6 +110,35 @@ impl HashMap {
     let mut hashmap = HashMap::new(String::from("{"));
 
     while !parser.current_token_is(Signs::new(Signs::RIGHTBRACE)) {
+      // Check if the current token is an index signature.
+      if parser.current_token_is(Signs::new(Signs::LEFTBRACKET)) {
+        let (key_type, value_type) = match HashMap::parse_index(parser) {
+          Ok(index) => index,
+          Err(_) => {
+            return Err(1);
+          },
+        };
+
+        hashmap.value.push_str(
+          format!(" [key: {}]: {}", key_type.value, value_type.value).as_str(),
+        );
+
+        hashmap.index = Some((key_type, value_type));
+
+        // Check if the next token is a comma.
+        if parser.next_token_is(Signs::new(Signs::COMMA)) {
+          // Get the next token.
+          parser.next_token();
+
+          hashmap.value.push(',');
+        }
+
+        // Get the next token.
+        parser.next_token();
+
+        continue;
+      }
+
       // Check if the next token is an identifier.
       if !parser.current_token_is(Box::new(Tokens::IDENTIFIER)) {
         return Err(1);
@@ -80,6 +149,12 @@ impl HashMap {
       hashmap.value.push_str(" ");
       hashmap.value.push_str(key.as_str());
 
+      // Check if the next token is a question sign.
+      if parser.expect_token(Signs::new(Signs::QUESTION)) {
+        hashmap.optionals.push(key.clone());
+        hashmap.value.push('?');
+      }
+
       // Check if the next token is a colon.
       if !parser.expect_token(Signs::new(Signs::COLON)) {
         return Err(1);
@@ -122,6 +197,32 @@ impl HashMap {
       current_token.position,
     ))
   }
+
+  /// Parse an index signature like `[key: string]: number` and get the key and value types.
+  pub fn parse_index(parser: &mut Parser) -> Result<(Token, Token), ()> {
+    if !parser.current_token_is(Signs::new(Signs::LEFTBRACKET)) ||
+      !parser.expect_token(Box::new(Tokens::IDENTIFIER)) ||
+      !parser.expect_token(Signs::new(Signs::COLON)) {
+      return Err(());
+    }
+
+    // Get the next token.
+    parser.next_token();
+
+    let key_type: Token = parse_type(parser)?;
+
+    if !parser.expect_token(Signs::new(Signs::RIGHTBRACKET)) ||
+      !parser.expect_token(Signs::new(Signs::COLON)) {
+      return Err(());
+    }
+
+    // Get the next token.
+    parser.next_token();
+
+    let value_type: Token = parse_type(parser)?;
+
+    Ok((key_type, value_type))
+  }
 }
 
 #[test]
@@ -136,3 +237,28 @@ fn hashmap_from_value() {
   assert_eq!(hashmap_2.is_ok(), true);
   assert_eq!(hashmap_2.unwrap(), hashmap);
 }
+
+#[test]
+fn hashmap_from_value_with_function() {
+  let hashmap = HashMap::from_value("{ name: string, setName: (name: string, force: boolean) => void }");
+
+  assert!(hashmap.is_ok());
+
+  let items = hashmap.unwrap().get_items();
+
+  assert_eq!(items.len(), 2);
+  assert_eq!(items.get("setName").unwrap().value, "(name: string, force: boolean) => void");
+}
+
+#[test]
+fn hashmap_from_value_with_optionals_and_index() {
+  let hashmap = HashMap::from_value("{ label?: string, [key: string]: number }");
+
+  assert!(hashmap.is_ok());
+
+  let hashmap = hashmap.unwrap();
+
+  assert!(hashmap.is_optional("label"));
+  assert_eq!(hashmap.get_items().len(), 1);
+  assert_eq!(hashmap.get_index().unwrap().1, Token::from_value("number", 0, 0));
+}
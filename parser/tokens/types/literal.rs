@@ -0,0 +1,93 @@
+use crate::{
+  Parser,
+  tokens::{
+    Token,
+    Tokens,
+    Types,
+  },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Literal {
+  data_type: Token,
+  value: String,
+}
+
+impl Literal {
+  pub fn new(data_type: Token, value: String) -> Literal {
+    Literal { data_type, value }
+  }
+
+  pub fn from_value(value: &str) -> Result<Literal, ()> {
+    // Parse string literals.
+    if value.len() >= 2 && (
+      (value.starts_with('\'') && value.ends_with('\'')) ||
+      (value.starts_with('"') && value.ends_with('"'))
+    ) {
+      return Ok(Literal::new(Token::from_value("string", 0, 0), value.to_string()));
+    }
+
+    // Parse number literals.
+    let digits = value.strip_prefix('-').unwrap_or(value);
+
+    if digits.starts_with(|character: char| character.is_ascii_digit()) && value.parse::<f64>().is_ok() {
+      return Ok(Literal::new(Token::from_value("number", 0, 0), value.to_string()));
+    }
+
+    Err(())
+  }
+
+  /// Get the base data type (`string` or `number`).
+  pub fn get_type(&self) -> Token {
+    self.data_type.clone()
+  }
+
+  pub fn get_value(&self) -> String {
+    self.value.clone()
+  }
+
+  /// Get the literal content without the quotes of the strings.
+  pub fn get_content(&self) -> String {
+    if self.data_type.token.get_type() == Some(Types::STRING) {
+      return self.value[1..self.value.len() - 1].to_string();
+    }
+
+    self.value.clone()
+  }
+
+  /// Check if two literals have the same content, `'read'` is equal to `"read"` and `1` to `1.0`.
+  pub fn is_equal(&self, other: &Literal) -> bool {
+    if self.data_type.value != other.data_type.value {
+      return false;
+    }
+
+    match (self.value.parse::<f64>(), other.value.parse::<f64>()) {
+      (Ok(one), Ok(two)) => one == two,
+      _ => self.get_content() == other.get_content(),
+    }
+  }
+
+  pub fn parse(parser: &mut Parser) -> Result<Token, ()> {
+    let current_token = parser.get_current_token();
+    let literal = Literal::from_value(&current_token.value)?;
+
+    Ok(Token::new(
+      Box::new(Tokens::TYPE(Types::LITERAL(literal))),
+      current_token.value,
+      current_token.line,
+      current_token.position,
+    ))
+  }
+}
+
+#[test]
+fn literal_from_value() {
+  let literal = Literal::new(Token::from_value("string", 0, 0), String::from("'read'"));
+  let literal_2 = Literal::from_value("'read'");
+
+  assert!(literal_2.is_ok());
+  assert_eq!(literal_2.clone().unwrap(), literal);
+  assert!(literal.is_equal(&Literal::from_value("\"read\"").unwrap()));
+  assert!(Literal::from_value("1.0").unwrap().is_equal(&Literal::from_value("1").unwrap()));
+  assert!(Literal::from_value("inf").is_err());
+}
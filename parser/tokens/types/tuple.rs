@@ -0,0 +1,103 @@
+use crate::{
+  parse_type,
+  Parser,
+  tokens::{
+    Signs,
+    item_from_value,
+    split_type_value,
+    Token,
+    Tokens,
+    Types,
+  },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tuple {
+  items: Vec<Token>,
+  value: String,
+}
+
+impl Tuple {
+  pub fn new(items: Vec<Token>, value: String) -> Tuple {
+    Tuple { items, value }
+  }
+
+  pub fn from_value(value: &str) -> Result<Tuple, ()> {
+    if !value.starts_with("[") || !value.ends_with("]") || value.ends_with("[]") {
+      return Err(());
+    }
+
+    let mut tuple = Tuple::new(Vec::new(), value.to_string());
+
+    for item in split_type_value(&value[1..value.len() - 1], ",") {
+      let token = match item_from_value(item.trim()) {
+        Some(token) => token,
+        None => {
+          return Err(());
+        },
+      };
+
+      tuple.items.push(token);
+    }
+
+    Ok(tuple)
+  }
+
+  pub fn get_items(&self) -> Vec<Token> {
+    self.items.clone()
+  }
+
+  pub fn get_value(&self) -> String {
+    self.value.clone()
+  }
+
+  pub fn parse(parser: &mut Parser) -> Result<Token, ()> {
+    if !parser.current_token_is(Signs::new(Signs::LEFTBRACKET)) {
+      return Err(());
+    }
+
+    let current_token = parser.get_current_token();
+    let mut values: Vec<String> = Vec::new();
+    let mut tuple = Tuple::new(Vec::new(), String::new());
+
+    while !parser.current_token_is(Signs::new(Signs::RIGHTBRACKET)) {
+      // Get the next token.
+      parser.next_token();
+
+      let data_type: Token = parse_type(parser)?;
+
+      values.push(data_type.value.clone());
+      tuple.items.push(data_type);
+
+      // Check if the next token is a comma.
+      if !parser.expect_token(Signs::new(Signs::COMMA)) {
+        // Check if the next token is a right bracket.
+        if !parser.expect_token(Signs::new(Signs::RIGHTBRACKET)) {
+          return Err(());
+        }
+      }
+    }
+
+    tuple.value = format!("[{}]", values.join(", "));
+
+    Ok(Token::new(
+      Box::new(Tokens::TYPE(Types::TUPLE(tuple.clone()))),
+      tuple.value,
+      current_token.line,
+      current_token.position,
+    ))
+  }
+}
+
+#[test]
+fn tuple_from_value() {
+  let tuple = Tuple::new(
+    vec![Token::from_value("string", 0, 0), Token::from_value("number", 0, 0)],
+    String::from("[string, number]"),
+  );
+
+  let tuple_2 = Tuple::from_value("[string, number]");
+
+  assert!(tuple_2.is_ok());
+  assert_eq!(tuple_2.unwrap(), tuple);
+}
@@ -0,0 +1,100 @@
+use crate::{
+  parse_type,
+  Parser,
+  tokens::{
+    Signs,
+    split_type_value,
+    Token,
+    Tokens,
+    Types,
+  },
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Union {
+  items: Vec<Token>,
+  value: String,
+}
+
+impl Union {
+  pub fn new(items: Vec<Token>, value: String) -> Union {
+    Union { items, value }
+  }
+
+  pub fn from_value(value: &str) -> Result<Union, ()> {
+    let parts: Vec<String> = split_type_value(value, "|");
+
+    if parts.len() < 2 {
+      return Err(());
+    }
+
+    let mut union = Union::new(Vec::new(), value.to_string());
+
+    for part in parts.iter() {
+      let token = Token::from_value(part.trim(), 0, 0);
+
+      if token.token.get_type().is_none() && !token.token.is_identifier() {
+        return Err(());
+      }
+
+      union.items.push(token);
+    }
+
+    Ok(union)
+  }
+
+  pub fn get_items(&self) -> Vec<Token> {
+    self.items.clone()
+  }
+
+  pub fn get_value(&self) -> String {
+    self.value.clone()
+  }
+
+  pub fn parse(parser: &mut Parser, token: Token) -> Result<Token, ()> {
+    let mut union = Union::new(vec![token.clone()], String::new());
+
+    while parser.expect_token(Signs::new(Signs::BITOR)) {
+      // Get the next token.
+      parser.next_token();
+
+      let data_type: Token = parse_type(parser)?;
+
+      // Flat the nested unions.
+      match data_type.token.get_type().and_then(|data_type| data_type.get_union()) {
+        Some(nested) => union.items.append(&mut nested.get_items()),
+        None => union.items.push(data_type),
+      }
+    }
+
+    let values: Vec<String> = union.items.iter().map(|item| item.value.clone()).collect();
+
+    union.value = values.join(" | ");
+
+    Ok(Token::new(
+      Box::new(Tokens::TYPE(Types::UNION(union.clone()))),
+      union.value,
+      token.line,
+      token.position,
+    ))
+  }
+}
+
+#[test]
+fn union_from_value() {
+  let union = Union::from_value("'read' | 'write'");
+
+  assert!(union.is_ok());
+  assert_eq!(union.clone().unwrap().get_items().len(), 2);
+  assert_eq!(union.unwrap().get_items()[1].value, "'write'");
+  assert!(Union::from_value("(value: number) => void").is_err());
+}
+
+#[test]
+fn union_from_value_with_bytes() {
+  let union = Union::from_value("bytes | null");
+
+  assert!(union.is_ok());
+  assert_eq!(union.clone().unwrap().get_items()[0].token.get_type(), Some(Types::BYTES));
+  assert_eq!(Types::from_value("bytes"), Ok(Types::BYTES));
+}